@@ -0,0 +1,479 @@
+use std::ops::{Add, Mul, Sub};
+
+use ndarray::{concatenate, Array, ArrayBase, Axis, Data, Dimension, RemoveAxis, Slice};
+
+use crate::{apply_along_axis, normalize_axis, NumrustError};
+
+/// The sample spacing used by [`gradient`], [`trapezoid`] and [`simpson`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Spacing<'a> {
+    /// A constant distance between consecutive samples.
+    Uniform(f64),
+    /// The coordinates of the samples, one per element along the axis.
+    Coordinates(&'a [f64]),
+}
+
+impl Spacing<'_> {
+    /// Returns the distances between the `n` consecutive samples.
+    fn steps(&self, n: usize) -> Result<Vec<f64>, NumrustError> {
+        match *self {
+            Spacing::Uniform(h) => Ok(vec![h; n.saturating_sub(1)]),
+            Spacing::Coordinates(x) if x.len() == n => {
+                Ok(x.windows(2).map(|w| w[1] - w[0]).collect())
+            }
+            Spacing::Coordinates(x) => Err(NumrustError::ValueError(format!(
+                "when 1d, distances must match the length of the corresponding dimension ({} != {})",
+                x.len(),
+                n
+            ))),
+        }
+    }
+}
+
+/// Returns the cumulative sum of the elements of `a` along `axis`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::calculus::cumsum;
+///
+/// let a = array![[1, 2, 3], [4, 5, 6]];
+/// assert_eq!(cumsum(&a, 0).unwrap(), array![[1, 2, 3], [5, 7, 9]]);
+/// assert_eq!(cumsum(&a, -1).unwrap(), array![[1, 3, 6], [4, 9, 15]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn cumsum<A, S, D>(a: &ArrayBase<S, D>, axis: isize) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone + Add<Output = A>,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    apply_along_axis(a, axis, a.to_owned(), |values| {
+        Ok(accumulate(values, A::add))
+    })
+}
+
+/// Returns the cumulative product of the elements of `a` along `axis`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::calculus::cumprod;
+///
+/// assert_eq!(cumprod(&array![1.0, 2.0, 3.0, 4.0], 0).unwrap(), array![1.0, 2.0, 6.0, 24.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn cumprod<A, S, D>(a: &ArrayBase<S, D>, axis: isize) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone + Mul<Output = A>,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    apply_along_axis(a, axis, a.to_owned(), |values| {
+        Ok(accumulate(values, A::mul))
+    })
+}
+
+/// Returns the cumulative sum of the elements of `a` along `axis`, treating NaNs as zero.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::calculus::nancumsum;
+///
+/// let a = array![1.0, f64::NAN, 2.0];
+/// assert_eq!(nancumsum(&a, 0).unwrap(), array![1.0, 1.0, 3.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn nancumsum<A, S, D>(a: &ArrayBase<S, D>, axis: isize) -> Result<Array<f64, D>, NumrustError>
+where
+    A: Into<f64> + Copy,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let values = a.mapv(|x| {
+        let x: f64 = x.into();
+        if x.is_nan() {
+            0.0
+        } else {
+            x
+        }
+    });
+    cumsum(&values, axis)
+}
+
+fn accumulate<A: Clone>(values: &[A], op: impl Fn(A, A) -> A) -> Vec<A> {
+    let mut result: Vec<A> = Vec::with_capacity(values.len());
+    for value in values {
+        let next = match result.last() {
+            Some(total) => op(total.clone(), value.clone()),
+            None => value.clone(),
+        };
+        result.push(next);
+    }
+    result
+}
+
+/// Computes the `n`-th discrete difference of `a` along `axis`.
+///
+/// # Arguments
+///
+/// * `a` - The input array.
+/// * `n` - The number of times values are differenced. `0` returns a copy of `a`.
+/// * `axis` - The axis along which to difference. Negative values count from the last axis.
+/// * `prepend` - Values joined to the start of `a` along `axis` before differencing. Its shape
+///   must match that of `a` except along `axis`.
+/// * `append` - Values joined to the end of `a` along `axis` before differencing.
+///
+/// # Returns
+///
+/// An array whose length along `axis` is `n` less than that of the (extended) input, or zero if
+/// `n` is larger than that length.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::calculus::diff;
+///
+/// let a = array![1, 2, 4, 7, 0];
+/// assert_eq!(diff(&a, 1, -1, None, None).unwrap(), array![1, 2, 3, -7]);
+/// assert_eq!(diff(&a, 2, -1, None, None).unwrap(), array![1, 1, -10]);
+/// assert_eq!(diff(&a, 1, 0, Some(&array![0]), None).unwrap(), array![1, 1, 2, 3, -7]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds or `prepend`/`append` cannot be joined to
+/// `a` along `axis`.
+pub fn diff<A, S, D>(
+    a: &ArrayBase<S, D>,
+    n: usize,
+    axis: isize,
+    prepend: Option<&Array<A, D>>,
+    append: Option<&Array<A, D>>,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone + Sub<Output = A>,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    let axis = Axis(normalize_axis(axis, a.ndim())?);
+    let mut parts = Vec::with_capacity(3);
+    parts.extend(prepend.map(|p| p.view()));
+    parts.push(a.view());
+    parts.extend(append.map(|p| p.view()));
+    let mut result = concatenate(axis, &parts).map_err(|e| {
+        NumrustError::ValueError(format!(
+            "prepend and append must match the shape of the array except along the axis: {}",
+            e
+        ))
+    })?;
+
+    for _ in 0..n {
+        let len = result.len_of(axis);
+        if len == 0 {
+            break;
+        }
+        let upper = result.slice_axis(axis, Slice::from(1..));
+        let lower = result.slice_axis(axis, Slice::from(..len - 1));
+        result = &upper - &lower;
+    }
+    Ok(result)
+}
+
+/// Computes the gradient of sampled values along `axis` with central differences in the
+/// interior and one-sided differences of order `edge_order` at the boundaries.
+///
+/// Non-uniform spacing uses the second-order accurate scheme of `numpy.gradient`, so the result
+/// is exact for quadratics in the interior (and at the edges when `edge_order` is 2).
+///
+/// # Arguments
+///
+/// * `f` - The sampled values.
+/// * `spacing` - The distance between samples or their coordinates along `axis`.
+/// * `axis` - The axis along which to differentiate. Negative values count from the last axis.
+/// * `edge_order` - `1` or `2`, the accuracy of the differences at the boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::calculus::{gradient, Spacing};
+///
+/// let f = array![1.0, 2.0, 4.0, 7.0, 11.0, 16.0];
+/// let g = gradient(&f, Spacing::Uniform(1.0), 0, 1).unwrap();
+/// assert_eq!(g, array![1.0, 1.5, 2.5, 3.5, 4.5, 5.0]);
+///
+/// let x = [0.0, 1.0, 1.5, 3.5, 4.0, 6.0];
+/// let g = gradient(&f, Spacing::Coordinates(&x), 0, 2).unwrap();
+/// assert!((g[1] - 3.0).abs() < 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `edge_order` is not 1 or 2, if there are fewer than
+/// `edge_order + 1` samples along `axis` or if the coordinates do not match them, and a
+/// `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn gradient<A, S, D>(
+    f: &ArrayBase<S, D>,
+    spacing: Spacing,
+    axis: isize,
+    edge_order: usize,
+) -> Result<Array<f64, D>, NumrustError>
+where
+    A: Into<f64> + Copy,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    if edge_order != 1 && edge_order != 2 {
+        return Err(NumrustError::ValueError(
+            "'edge_order' greater than 2 not supported".to_string(),
+        ));
+    }
+    let values = f.mapv(|x| x.into());
+    let n = values.len_of(Axis(normalize_axis(axis, values.ndim())?));
+    if n < edge_order + 1 {
+        return Err(NumrustError::ValueError(
+            "Shape of array too small to calculate a numerical gradient, at least (edge_order + 1) elements are required."
+                .to_string(),
+        ));
+    }
+    let dx = spacing.steps(n)?;
+    apply_along_axis(&values, axis, Array::zeros(values.raw_dim()), |y| {
+        Ok(gradient_1d(y, &dx, edge_order))
+    })
+}
+
+fn gradient_1d(y: &[f64], dx: &[f64], edge_order: usize) -> Vec<f64> {
+    let n = y.len();
+    let mut out = vec![0.0; n];
+    for i in 1..n - 1 {
+        let (h1, h2) = (dx[i - 1], dx[i]);
+        let a = -h2 / (h1 * (h1 + h2));
+        let b = (h2 - h1) / (h1 * h2);
+        let c = h1 / (h2 * (h1 + h2));
+        out[i] = a * y[i - 1] + b * y[i] + c * y[i + 1];
+    }
+    if edge_order == 1 {
+        out[0] = (y[1] - y[0]) / dx[0];
+        out[n - 1] = (y[n - 1] - y[n - 2]) / dx[n - 2];
+    } else {
+        let (h1, h2) = (dx[0], dx[1]);
+        let a = -(2.0 * h1 + h2) / (h1 * (h1 + h2));
+        let b = (h1 + h2) / (h1 * h2);
+        let c = -h1 / (h2 * (h1 + h2));
+        out[0] = a * y[0] + b * y[1] + c * y[2];
+
+        let (h1, h2) = (dx[n - 3], dx[n - 2]);
+        let a = h2 / (h1 * (h1 + h2));
+        let b = -(h2 + h1) / (h1 * h2);
+        let c = (2.0 * h2 + h1) / (h2 * (h1 + h2));
+        out[n - 1] = a * y[n - 3] + b * y[n - 2] + c * y[n - 1];
+    }
+    out
+}
+
+/// Integrates sampled values along `axis` with the composite trapezoidal rule.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::calculus::{trapezoid, Spacing};
+///
+/// let y = array![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]];
+/// assert_eq!(trapezoid(&y, Spacing::Uniform(1.0), -1).unwrap(), array![2.0, 8.0]);
+/// assert_eq!(trapezoid(&y, Spacing::Coordinates(&[0.0, 2.0]), 0).unwrap(), array![3.0, 5.0, 7.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds or the coordinates do not match the
+/// number of samples along it.
+pub fn trapezoid<A, S, D>(
+    y: &ArrayBase<S, D>,
+    spacing: Spacing,
+    axis: isize,
+) -> Result<Array<f64, D::Smaller>, NumrustError>
+where
+    A: Into<f64> + Copy,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    integrate_along_axis(y, spacing, axis, trapezoid_1d)
+}
+
+/// Integrates sampled values along `axis` with the composite Simpson's rule.
+///
+/// For an even number of samples the last interval is integrated with Cartwright's correction,
+/// as in `scipy.integrate.simpson`, so the result stays exact for quadratics.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::Array1;
+/// use numrust::{linspace, calculus::{simpson, Spacing}};
+///
+/// let x = linspace(0.0, 1.0, 6);
+/// let y = Array1::from_iter(x.iter().map(|x| x * x));
+/// let integral = simpson(&y, Spacing::Coordinates(&x), 0).unwrap();
+/// assert!((integral[()] - 1.0 / 3.0).abs() < 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds or the coordinates do not match the
+/// number of samples along it.
+pub fn simpson<A, S, D>(
+    y: &ArrayBase<S, D>,
+    spacing: Spacing,
+    axis: isize,
+) -> Result<Array<f64, D::Smaller>, NumrustError>
+where
+    A: Into<f64> + Copy,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    integrate_along_axis(y, spacing, axis, simpson_1d)
+}
+
+fn integrate_along_axis<A, S, D>(
+    y: &ArrayBase<S, D>,
+    spacing: Spacing,
+    axis: isize,
+    rule: fn(&[f64], &[f64]) -> f64,
+) -> Result<Array<f64, D::Smaller>, NumrustError>
+where
+    A: Into<f64> + Copy,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    let axis = Axis(normalize_axis(axis, y.ndim())?);
+    let dx = spacing.steps(y.len_of(axis))?;
+    Ok(y.map_axis(axis, |lane| {
+        let values: Vec<f64> = lane.iter().map(|&v| v.into()).collect();
+        rule(&values, &dx)
+    }))
+}
+
+fn trapezoid_1d(y: &[f64], dx: &[f64]) -> f64 {
+    y.windows(2)
+        .zip(dx)
+        .map(|(w, h)| h * (w[0] + w[1]) / 2.0)
+        .sum()
+}
+
+fn simpson_1d(y: &[f64], dx: &[f64]) -> f64 {
+    let n = y.len();
+    if n < 3 {
+        return trapezoid_1d(y, dx);
+    }
+    let even_end = if n.is_multiple_of(2) { n - 1 } else { n };
+    let mut total = 0.0;
+    for i in (0..even_end - 2).step_by(2) {
+        let (h0, h1) = (dx[i], dx[i + 1]);
+        let (hsum, hprod, ratio) = (h0 + h1, h0 * h1, h0 / h1);
+        total += hsum / 6.0
+            * (y[i] * (2.0 - 1.0 / ratio)
+                + y[i + 1] * (hsum * hsum / hprod)
+                + y[i + 2] * (2.0 - ratio));
+    }
+    if n.is_multiple_of(2) {
+        let (h2, h1) = (dx[n - 3], dx[n - 2]);
+        let alpha = (2.0 * h1 * h1 + 3.0 * h1 * h2) / (6.0 * (h2 + h1));
+        let beta = (h1 * h1 + 3.0 * h1 * h2) / (6.0 * h2);
+        let eta = h1 * h1 * h1 / (6.0 * h2 * (h2 + h1));
+        total += alpha * y[n - 1] + beta * y[n - 2] - eta * y[n - 3];
+    }
+    total
+}
+
+#[cfg(test)]
+mod numrust_calculus_tests {
+    use super::*;
+    use crate::linspace;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array1, Array2};
+
+    #[test]
+    fn test_cumulative_ops_along_axes() {
+        let a = array![[1.0, 2.0], [3.0, f64::NAN]];
+        assert_eq!(nancumsum(&a, 0).unwrap(), array![[1.0, 2.0], [4.0, 2.0]]);
+        assert_eq!(
+            cumprod(&array![[1, 2], [3, 4]], 0).unwrap(),
+            array![[1, 2], [3, 8]]
+        );
+        assert!(cumsum(&a, 2).is_err());
+    }
+
+    #[test]
+    fn test_diff_order_and_boundaries() {
+        let a = array![[1, 3, 6, 10], [0, 5, 6, 8]];
+        assert_eq!(
+            diff(&a, 1, 1, None, None).unwrap(),
+            array![[2, 3, 4], [5, 1, 2]]
+        );
+        assert_eq!(diff(&a, 1, 0, None, None).unwrap(), array![[-1, 2, 0, -2]]);
+        assert_eq!(diff(&a, 0, 0, None, None).unwrap(), a);
+        assert_eq!(diff(&a, 5, 1, None, None).unwrap().shape(), &[2, 0]);
+        let appended = diff(&a, 1, 1, None, Some(&array![[10], [8]])).unwrap();
+        assert_eq!(appended, array![[2, 3, 4, 0], [5, 1, 2, 0]]);
+        assert!(diff(&a, 1, 1, Some(&array![[1, 2, 3]]), None).is_err());
+    }
+
+    #[test]
+    fn test_gradient_exact_for_quadratics() {
+        let x = [0.0, 0.3, 1.0, 1.2, 2.5, 3.0, 4.1];
+        let f = Array1::from_iter(x.iter().map(|x| 3.0 * x * x - x + 2.0));
+        let g = gradient(&f, Spacing::Coordinates(&x), 0, 2).unwrap();
+        for (gi, xi) in g.iter().zip(x) {
+            assert_abs_diff_eq!(*gi, 6.0 * xi - 1.0, epsilon = 1e-10);
+        }
+
+        let f = Array2::from_shape_fn((4, 3), |(i, j)| (i * i + j) as f64);
+        let g = gradient(&f, Spacing::Uniform(0.5), 0, 2).unwrap();
+        assert_eq!(g.column(2), array![0.0, 4.0, 8.0, 12.0]);
+        assert!(gradient(&f, Spacing::Uniform(1.0), 0, 3).is_err());
+        assert!(gradient(&array![1.0, 2.0], Spacing::Uniform(1.0), 0, 2).is_err());
+        assert!(gradient(&f, Spacing::Coordinates(&[0.0, 1.0]), 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_integration_on_linspace_grids() {
+        let x = linspace(0.0, 2.0, 101);
+        let y = Array1::from_iter(x.iter().map(|x: &f64| x.powi(3)));
+        assert_abs_diff_eq!(
+            simpson(&y, Spacing::Coordinates(&x), 0).unwrap()[()],
+            4.0,
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(
+            trapezoid(&y, Spacing::Uniform(0.02), 0).unwrap()[()],
+            4.0,
+            epsilon = 1e-3
+        );
+
+        let x = [0.0, 0.5, 1.75, 2.0];
+        let y = Array2::from_shape_fn((2, 4), |(i, j)| (i as f64 + 1.0) * x[j] * x[j]);
+        let integral = simpson(&y, Spacing::Coordinates(&x), -1).unwrap();
+        assert_abs_diff_eq!(integral[0], 8.0 / 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(integral[1], 16.0 / 3.0, epsilon = 1e-12);
+        assert_eq!(
+            simpson(&array![1.0, 3.0], Spacing::Uniform(2.0), 0).unwrap()[()],
+            4.0
+        );
+    }
+}
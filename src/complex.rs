@@ -0,0 +1,387 @@
+//! Complex element support.
+//!
+//! The [`Scalar`] trait lets numrust's routines take real and complex elements alike, and
+//! [`Field`] is the double-precision type they compute in. This module adds the functions that
+//! only make sense for complex data: building complex arrays, taking real and imaginary parts,
+//! conjugates, moduli and phase angles, and the complex-valued `exp`, `log` and `sqrt`.
+
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::Neg;
+
+use ndarray::{Array, ArrayBase, Data, Dimension};
+pub use num_complex::{Complex, Complex32, Complex64};
+use num_traits::Num;
+
+use crate::NumrustError;
+
+/// An element type that numrust can process: the primitive real numbers and complex numbers.
+///
+/// Real values behave like complex values with a zero imaginary part, so functions bounded by
+/// `Scalar` accept both real and complex data. Computations are carried out in the element's
+/// [`Wide`](Scalar::Wide) type, much like `num_traits`' `ComplexFloat` ties a complex type to
+/// its real counterpart.
+pub trait Scalar: Copy {
+    /// The double-precision type the element is computed in: `f64` for real types and
+    /// `Complex64` for complex types.
+    type Wide: Field;
+
+    /// The real part of the value.
+    fn re(self) -> f64;
+
+    /// The imaginary part of the value, zero for real types.
+    fn im(self) -> f64;
+
+    /// The complex conjugate of the value. Real values are returned unchanged.
+    fn conj(self) -> Self;
+
+    /// The absolute value (modulus) of the value.
+    fn abs(self) -> f64;
+
+    /// The value converted to its [`Wide`](Scalar::Wide) type.
+    fn widen(self) -> Self::Wide;
+
+    /// The squared modulus of the value.
+    fn norm_sqr(self) -> f64 {
+        self.re() * self.re() + self.im() * self.im()
+    }
+
+    /// Whether both parts of the value are finite.
+    fn is_finite(self) -> bool {
+        self.re().is_finite() && self.im().is_finite()
+    }
+
+    /// The value as a `Complex64`.
+    fn to_complex(self) -> Complex64 {
+        Complex64::new(self.re(), self.im())
+    }
+}
+
+/// The types that numrust computes in, `f64` and `Complex64`.
+///
+/// Besides field arithmetic, a `Field` can be built from and scaled by real numbers, which is
+/// all the statistics and linear algebra routines need to treat real and complex data alike.
+pub trait Field:
+    Scalar<Wide = Self> + Num + Neg<Output = Self> + Sum + Default + Debug + Send + Sync + 'static
+{
+    /// The value with real part `x` and no imaginary part.
+    fn from_real(x: f64) -> Self;
+
+    /// The value multiplied by the real number `t`.
+    fn scale(self, t: f64) -> Self;
+
+    /// The value divided by the real number `t`.
+    fn unscale(self, t: f64) -> Self;
+
+    /// The principal square root of the value, NaN for negative reals.
+    fn sqrt(self) -> Self;
+}
+
+impl Field for f64 {
+    fn from_real(x: f64) -> Self {
+        x
+    }
+
+    fn scale(self, t: f64) -> Self {
+        self * t
+    }
+
+    fn unscale(self, t: f64) -> Self {
+        self / t
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+}
+
+impl Field for Complex64 {
+    fn from_real(x: f64) -> Self {
+        Complex64::new(x, 0.0)
+    }
+
+    fn scale(self, t: f64) -> Self {
+        Complex64::scale(&self, t)
+    }
+
+    fn unscale(self, t: f64) -> Self {
+        Complex64::unscale(&self, t)
+    }
+
+    fn sqrt(self) -> Self {
+        Complex64::sqrt(self)
+    }
+}
+
+macro_rules! impl_real_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                type Wide = f64;
+
+                fn re(self) -> f64 {
+                    self.into()
+                }
+
+                fn im(self) -> f64 {
+                    0.0
+                }
+
+                fn conj(self) -> Self {
+                    self
+                }
+
+                fn abs(self) -> f64 {
+                    f64::from(self).abs()
+                }
+
+                fn widen(self) -> f64 {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_real_scalar!(f32, f64, i8, i16, i32, u8, u16, u32);
+
+macro_rules! impl_complex_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for Complex<$t> {
+                type Wide = Complex64;
+
+                fn re(self) -> f64 {
+                    self.re.into()
+                }
+
+                fn im(self) -> f64 {
+                    self.im.into()
+                }
+
+                fn conj(self) -> Self {
+                    Complex::conj(&self)
+                }
+
+                fn abs(self) -> f64 {
+                    self.re().hypot(self.im())
+                }
+
+                fn widen(self) -> Complex64 {
+                    self.to_complex()
+                }
+            }
+        )*
+    };
+}
+
+impl_complex_scalar!(f32, f64);
+
+/// Builds a complex array from arrays holding the real and imaginary parts.
+///
+/// # Arguments
+///
+/// * `re` - The real parts.
+/// * `im` - The imaginary parts. Must have the same shape as `re`.
+///
+/// # Returns
+///
+/// An array of `Complex64` values with the shape of `re`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::{complex_array, Complex64};
+///
+/// let z = complex_array(&array![1.0, 2.0], &array![-1.0, 0.5]).unwrap();
+/// assert_eq!(z[0], Complex64::new(1.0, -1.0));
+/// assert_eq!(z[1], Complex64::new(2.0, 0.5));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::CreationError` if `re` and `im` have different shapes.
+pub fn complex_array<A, B, S, T, D>(
+    re: &ArrayBase<S, D>,
+    im: &ArrayBase<T, D>,
+) -> Result<Array<Complex64, D>, NumrustError>
+where
+    A: Into<f64> + Copy,
+    B: Into<f64> + Copy,
+    S: Data<Elem = A>,
+    T: Data<Elem = B>,
+    D: Dimension,
+{
+    if re.shape() != im.shape() {
+        return Err(NumrustError::CreationError(format!(
+            "real part of shape {:?} does not match imaginary part of shape {:?}",
+            re.shape(),
+            im.shape()
+        )));
+    }
+    let mut result = re.map(|&r| Complex64::new(r.into(), 0.0));
+    result.zip_mut_with(im, |z, &i| z.im = i.into());
+    Ok(result)
+}
+
+/// Converts an array of real or complex values into an array of `Complex64`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::{to_complex, Complex64};
+///
+/// let z = to_complex(&array![1, 2]);
+/// assert_eq!(z, array![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)]);
+/// ```
+pub fn to_complex<A: Scalar, S: Data<Elem = A>, D: Dimension>(
+    a: &ArrayBase<S, D>,
+) -> Array<Complex64, D> {
+    a.mapv(Scalar::to_complex)
+}
+
+/// Returns the elementwise complex conjugate of `a`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::{conj, Complex64};
+///
+/// let z = array![Complex64::new(1.0, 2.0)];
+/// assert_eq!(conj(&z)[0], Complex64::new(1.0, -2.0));
+/// ```
+pub fn conj<A: Scalar, S: Data<Elem = A>, D: Dimension>(a: &ArrayBase<S, D>) -> Array<A, D> {
+    a.mapv(Scalar::conj)
+}
+
+/// Returns the elementwise real part of `a`.
+pub fn real<A: Scalar, S: Data<Elem = A>, D: Dimension>(a: &ArrayBase<S, D>) -> Array<f64, D> {
+    a.mapv(Scalar::re)
+}
+
+/// Returns the elementwise imaginary part of `a`.
+pub fn imag<A: Scalar, S: Data<Elem = A>, D: Dimension>(a: &ArrayBase<S, D>) -> Array<f64, D> {
+    a.mapv(Scalar::im)
+}
+
+/// Returns the elementwise absolute value (modulus) of `a`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::{absolute, Complex64};
+///
+/// let z = array![Complex64::new(3.0, 4.0), Complex64::new(-1.0, 0.0)];
+/// assert_eq!(absolute(&z), array![5.0, 1.0]);
+/// ```
+pub fn absolute<A: Scalar, S: Data<Elem = A>, D: Dimension>(a: &ArrayBase<S, D>) -> Array<f64, D> {
+    a.mapv(Scalar::abs)
+}
+
+/// Returns the elementwise argument (phase angle) of `a`, in radians or degrees.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::{angle, Complex64};
+///
+/// let z = array![Complex64::new(0.0, 1.0), Complex64::new(-1.0, 0.0)];
+/// assert_eq!(angle(&z, true), array![90.0, 180.0]);
+/// ```
+pub fn angle<A: Scalar, S: Data<Elem = A>, D: Dimension>(
+    a: &ArrayBase<S, D>,
+    deg: bool,
+) -> Array<f64, D> {
+    a.mapv(|z| {
+        let radians = z.im().atan2(z.re());
+        if deg {
+            radians.to_degrees()
+        } else {
+            radians
+        }
+    })
+}
+
+/// Returns the elementwise complex exponential of `a`.
+pub fn exp<A: Scalar, S: Data<Elem = A>, D: Dimension>(a: &ArrayBase<S, D>) -> Array<Complex64, D> {
+    a.mapv(|z| z.to_complex().exp())
+}
+
+/// Returns the elementwise principal natural logarithm of `a`.
+pub fn log<A: Scalar, S: Data<Elem = A>, D: Dimension>(a: &ArrayBase<S, D>) -> Array<Complex64, D> {
+    a.mapv(|z| z.to_complex().ln())
+}
+
+/// Returns the elementwise principal square root of `a`. Negative real inputs produce purely
+/// imaginary results instead of NaN.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::{sqrt, Complex64};
+///
+/// assert_eq!(sqrt(&array![-4.0])[0], Complex64::new(0.0, 2.0));
+/// ```
+pub fn sqrt<A: Scalar, S: Data<Elem = A>, D: Dimension>(
+    a: &ArrayBase<S, D>,
+) -> Array<Complex64, D> {
+    a.mapv(|z| z.to_complex().sqrt())
+}
+
+#[cfg(test)]
+mod numrust_complex_tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn test_scalar_for_reals_and_complex() {
+        assert_eq!(3i32.re(), 3.0);
+        assert_eq!((-2.5f64).abs(), 2.5);
+        assert_eq!(7u8.conj(), 7u8);
+        let z = Complex64::new(3.0, -4.0);
+        assert_eq!(z.abs(), 5.0);
+        assert_eq!(Scalar::conj(z), Complex64::new(3.0, 4.0));
+        let w = Complex32::new(1.0, 2.0);
+        assert_eq!(w.to_complex(), Complex64::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_complex_array_shape_mismatch() {
+        assert!(complex_array(&array![1.0, 2.0], &array![1.0]).is_err());
+    }
+
+    #[test]
+    fn test_complex_arithmetic() {
+        let a = complex_array(&array![1.0, 2.0], &array![1.0, -1.0]).unwrap();
+        let b = to_complex(&array![2.0, 0.5]);
+        assert_eq!(
+            &a * &b,
+            array![Complex64::new(2.0, 2.0), Complex64::new(1.0, -0.5)]
+        );
+        assert_eq!(
+            &a + &conj(&a),
+            array![Complex64::new(2.0, 0.0), Complex64::new(4.0, 0.0)]
+        );
+        assert_eq!(real(&a), array![1.0, 2.0]);
+        assert_eq!(imag(&a), array![1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_ufuncs() {
+        let z = array![Complex64::new(0.0, std::f64::consts::PI)];
+        let e = exp(&z);
+        assert_abs_diff_eq!(e[0].re, -1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(e[0].im, 0.0, epsilon = 1e-12);
+        let l = log(&array![-1.0]);
+        assert_abs_diff_eq!(l[0].im, std::f64::consts::PI, epsilon = 1e-12);
+        assert_eq!(angle(&array![Complex64::new(1.0, 1.0)], true)[0], 45.0);
+        assert_eq!(absolute(&array![-3, 4]), array![3.0, 4.0]);
+    }
+}
@@ -0,0 +1,835 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, Zip};
+
+use crate::complex::{Complex64, Scalar};
+use crate::{normalize_axis, NumrustError};
+
+/// Radices handled directly by the Cooley–Tukey butterflies. Lengths whose smallest prime
+/// factor exceeds this are transformed with Bluestein's algorithm instead.
+const MAX_RADIX: usize = 13;
+
+/// The number of plans kept by the plan cache before the least recently used one is dropped.
+const PLAN_CACHE_CAPACITY: usize = 64;
+
+/// A precomputed forward transform of a fixed length.
+#[derive(Debug)]
+enum FftPlan {
+    /// Lengths 0 and 1, which the transform leaves unchanged.
+    Identity,
+    /// One decimation-in-time step: `len = radix * sub.len()`.
+    CooleyTukey {
+        len: usize,
+        radix: usize,
+        sub: Arc<FftPlan>,
+        /// `exp(-2πij / len)` for `j` in `0..len`.
+        twiddles: Vec<Complex64>,
+        /// The scratch space the transform needs: `radix` values for the butterflies, reused
+        /// by the sub-transforms.
+        scratch_len: usize,
+    },
+    /// Chirp-z transform of a length with only large prime factors, computed as a circular
+    /// convolution of power-of-two length.
+    Bluestein {
+        len: usize,
+        inner: Arc<FftPlan>,
+        /// `exp(-πik² / len)` for `k` in `0..len`.
+        chirp: Vec<Complex64>,
+        /// The forward transform of the conjugated, wrapped chirp.
+        kernel: Vec<Complex64>,
+        /// The scratch space the transform needs: two convolution buffers followed by the
+        /// scratch space of `inner`.
+        scratch_len: usize,
+    },
+}
+
+/// A least-recently-used cache of plans keyed by length.
+///
+/// Plans hold their sub-plans through `Arc`, so evicting a plan never invalidates one that is
+/// still in use; it only means the next transform of that length builds it again.
+#[derive(Debug)]
+struct PlanCache {
+    capacity: usize,
+    /// Each plan with the tick of its last use.
+    plans: HashMap<usize, (Arc<FftPlan>, u64)>,
+    tick: u64,
+}
+
+impl PlanCache {
+    fn new(capacity: usize) -> PlanCache {
+        PlanCache {
+            capacity,
+            plans: HashMap::new(),
+            tick: 0,
+        }
+    }
+
+    fn get(&mut self, n: usize) -> Option<Arc<FftPlan>> {
+        self.tick += 1;
+        let (plan, last_used) = self.plans.get_mut(&n)?;
+        *last_used = self.tick;
+        Some(Arc::clone(plan))
+    }
+
+    /// Caches `plan` for length `n` unless another thread got there first, and returns the
+    /// cached plan.
+    fn insert(&mut self, n: usize, plan: Arc<FftPlan>) -> Arc<FftPlan> {
+        if let Some(cached) = self.get(n) {
+            return cached;
+        }
+        if self.plans.len() >= self.capacity {
+            let oldest = self
+                .plans
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(&len, _)| len);
+            if let Some(oldest) = oldest {
+                self.plans.remove(&oldest);
+            }
+        }
+        self.plans.insert(n, (Arc::clone(&plan), self.tick));
+        plan
+    }
+}
+
+fn plan_cache() -> &'static Mutex<PlanCache> {
+    static PLANS: OnceLock<Mutex<PlanCache>> = OnceLock::new();
+    PLANS.get_or_init(|| Mutex::new(PlanCache::new(PLAN_CACHE_CAPACITY)))
+}
+
+/// Returns the plan for transforms of length `n`, building and caching it on first use.
+fn plan(n: usize) -> Arc<FftPlan> {
+    if let Some(plan) = plan_cache().lock().unwrap().get(n) {
+        return plan;
+    }
+    // Build without holding the lock, since building recursively requests sub-plans.
+    let built = Arc::new(FftPlan::new(n));
+    plan_cache().lock().unwrap().insert(n, built)
+}
+
+/// Picks the radix of the first Cooley–Tukey step for length `n`: 4 where possible, otherwise
+/// the smallest prime factor of `n`.
+fn choose_radix(n: usize) -> usize {
+    if n.is_multiple_of(4) {
+        return 4;
+    }
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            return p;
+        }
+        p += 1;
+    }
+    n
+}
+
+fn unit_root(numerator: usize, denominator: usize) -> Complex64 {
+    let theta = -2.0 * PI * numerator as f64 / denominator as f64;
+    Complex64::new(theta.cos(), theta.sin())
+}
+
+impl FftPlan {
+    fn new(n: usize) -> FftPlan {
+        if n <= 1 {
+            return FftPlan::Identity;
+        }
+        let radix = choose_radix(n);
+        if radix <= MAX_RADIX {
+            let sub = plan(n / radix);
+            FftPlan::CooleyTukey {
+                len: n,
+                radix,
+                scratch_len: radix.max(sub.scratch_len()),
+                sub,
+                twiddles: (0..n).map(|j| unit_root(j, n)).collect(),
+            }
+        } else {
+            let m = (2 * n - 1).next_power_of_two();
+            let inner = plan(m);
+            // k² is reduced modulo 2n so the angle stays small and accurate.
+            let chirp: Vec<Complex64> = (0..n)
+                .map(|k| {
+                    let theta = -PI * ((k * k) % (2 * n)) as f64 / n as f64;
+                    Complex64::new(theta.cos(), theta.sin())
+                })
+                .collect();
+            let mut wrapped = vec![Complex64::new(0.0, 0.0); m];
+            wrapped[0] = chirp[0].conj();
+            for k in 1..n {
+                wrapped[k] = chirp[k].conj();
+                wrapped[m - k] = chirp[k].conj();
+            }
+            let mut kernel = vec![Complex64::new(0.0, 0.0); m];
+            let mut scratch = vec![Complex64::new(0.0, 0.0); inner.scratch_len()];
+            inner.transform(&wrapped, 1, &mut kernel, &mut scratch);
+            FftPlan::Bluestein {
+                len: n,
+                scratch_len: 2 * m + inner.scratch_len(),
+                inner,
+                chirp,
+                kernel,
+            }
+        }
+    }
+
+    /// The length of the scratch slice that [`transform`](FftPlan::transform) needs.
+    fn scratch_len(&self) -> usize {
+        match self {
+            FftPlan::Identity => 0,
+            FftPlan::CooleyTukey { scratch_len, .. } | FftPlan::Bluestein { scratch_len, .. } => {
+                *scratch_len
+            }
+        }
+    }
+
+    /// Writes the forward transform of `input[0], input[stride], ...` into `output`, using
+    /// `scratch`, at least [`scratch_len`](FftPlan::scratch_len) values long, as working space.
+    fn transform(
+        &self,
+        input: &[Complex64],
+        stride: usize,
+        output: &mut [Complex64],
+        scratch: &mut [Complex64],
+    ) {
+        match self {
+            FftPlan::Identity => {
+                if let Some(first) = output.first_mut() {
+                    *first = input[0];
+                }
+            }
+            FftPlan::CooleyTukey {
+                len,
+                radix,
+                sub,
+                twiddles,
+                ..
+            } => {
+                let (n, p) = (*len, *radix);
+                let m = n / p;
+                for r in 0..p {
+                    sub.transform(
+                        &input[r * stride..],
+                        stride * p,
+                        &mut output[r * m..(r + 1) * m],
+                        scratch,
+                    );
+                }
+                // Combine the p sub-transforms: the inputs of each butterfly sit at r * m + k and
+                // its outputs at q * m + k, so every butterfly can be done in place.
+                let scratch = &mut scratch[..p];
+                for k in 0..m {
+                    for (r, t) in scratch.iter_mut().enumerate() {
+                        *t = output[r * m + k] * twiddles[r * k];
+                    }
+                    for q in 0..p {
+                        let mut sum = scratch[0];
+                        for (r, t) in scratch.iter().enumerate().skip(1) {
+                            sum += t * twiddles[((r * q) % p) * m];
+                        }
+                        output[q * m + k] = sum;
+                    }
+                }
+            }
+            FftPlan::Bluestein {
+                len,
+                inner,
+                chirp,
+                kernel,
+                ..
+            } => {
+                let (n, m) = (*len, kernel.len());
+                let (padded, rest) = scratch.split_at_mut(m);
+                let (spectrum, rest) = rest.split_at_mut(m);
+                for (k, p) in padded.iter_mut().enumerate() {
+                    *p = if k < n {
+                        input[k * stride] * chirp[k]
+                    } else {
+                        Complex64::new(0.0, 0.0)
+                    };
+                }
+                inner.transform(padded, 1, spectrum, rest);
+                // Inverse transform of the product through conj(fft(conj(x))) / m.
+                for (s, k) in spectrum.iter_mut().zip(kernel) {
+                    *s = (*s * k).conj();
+                }
+                inner.transform(spectrum, 1, padded, rest);
+                for k in 0..n {
+                    output[k] = padded[k].conj() / m as f64 * chirp[k];
+                }
+            }
+        }
+    }
+}
+
+/// Resizes `x` to `n` complex values by truncating or zero-padding, as NumPy does for `n`.
+fn resized<T: Scalar>(x: &[T], n: Option<usize>) -> Result<Vec<Complex64>, NumrustError> {
+    let n = n.unwrap_or(x.len());
+    if n < 1 {
+        return Err(NumrustError::ValueError(format!(
+            "Invalid number of FFT data points ({}) specified.",
+            n
+        )));
+    }
+    let mut data: Vec<Complex64> = x.iter().take(n).map(|&v| v.to_complex()).collect();
+    data.resize(n, Complex64::new(0.0, 0.0));
+    Ok(data)
+}
+
+fn forward(data: &[Complex64]) -> Vec<Complex64> {
+    let plan = plan(data.len());
+    let mut output = vec![Complex64::new(0.0, 0.0); data.len()];
+    let mut scratch = vec![Complex64::new(0.0, 0.0); plan.scratch_len()];
+    plan.transform(data, 1, &mut output, &mut scratch);
+    output
+}
+
+fn inverse(data: &[Complex64]) -> Vec<Complex64> {
+    let n = data.len() as f64;
+    let conjugated: Vec<Complex64> = data.iter().map(|z| z.conj()).collect();
+    forward(&conjugated)
+        .into_iter()
+        .map(|z| z.conj() / n)
+        .collect()
+}
+
+/// Computes the one-dimensional discrete Fourier transform.
+///
+/// Lengths are factored into radices of at most 13 and transformed with mixed-radix
+/// Cooley–Tukey; lengths with larger prime factors use Bluestein's algorithm, so every length
+/// runs in `O(n log n)`. Plans for the most recently used lengths are cached, so repeated
+/// transforms of the same length skip the setup work.
+///
+/// # Arguments
+///
+/// * `x` - The real or complex input values.
+/// * `n` - The transform length. `x` is truncated or zero-padded to `n` values. `None` uses the
+///   length of `x`.
+///
+/// # Returns
+///
+/// A `Vec<Complex64>` holding `X[k] = sum_j x[j] * exp(-2πijk / n)`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::fft::fft;
+/// use approx::assert_abs_diff_eq;
+///
+/// let spectrum = fft(&[1.0, 2.0, 3.0, 4.0], None).unwrap();
+/// assert_abs_diff_eq!(spectrum[0].re, 10.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(spectrum[1].re, -2.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(spectrum[1].im, 2.0, epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the transform length is zero.
+pub fn fft<T: Scalar>(x: &[T], n: Option<usize>) -> Result<Vec<Complex64>, NumrustError> {
+    Ok(forward(&resized(x, n)?))
+}
+
+/// Computes the one-dimensional inverse discrete Fourier transform, scaled by `1 / n` so that
+/// `ifft(fft(x)) == x`.
+///
+/// # Arguments
+///
+/// * `x` - The input spectrum.
+/// * `n` - The transform length, with the same meaning as in [`fft`].
+///
+/// # Examples
+///
+/// ```
+/// use numrust::fft::{fft, ifft};
+/// use approx::assert_abs_diff_eq;
+///
+/// let x = [1.0, -2.0, 0.5];
+/// let roundtrip = ifft(&fft(&x, None).unwrap(), None).unwrap();
+/// for (z, v) in roundtrip.iter().zip(x) {
+///     assert_abs_diff_eq!(z.re, v, epsilon = 1e-12);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the transform length is zero.
+pub fn ifft<T: Scalar>(x: &[T], n: Option<usize>) -> Result<Vec<Complex64>, NumrustError> {
+    Ok(inverse(&resized(x, n)?))
+}
+
+/// Computes the discrete Fourier transform of real input, returning only the `n / 2 + 1`
+/// non-negative frequency terms.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::fft::rfft;
+/// use approx::assert_abs_diff_eq;
+///
+/// let spectrum = rfft(&[1.0, 2.0, 3.0, 4.0], None).unwrap();
+/// assert_eq!(spectrum.len(), 3);
+/// assert_abs_diff_eq!(spectrum[2].re, -2.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(spectrum[2].im, 0.0, epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the transform length is zero.
+pub fn rfft<T: Into<f64> + Copy>(
+    x: &[T],
+    n: Option<usize>,
+) -> Result<Vec<Complex64>, NumrustError> {
+    let real: Vec<f64> = x.iter().map(|&v| v.into()).collect();
+    let mut spectrum = fft(&real, n)?;
+    spectrum.truncate(spectrum.len() / 2 + 1);
+    Ok(spectrum)
+}
+
+/// Computes the inverse of [`rfft`].
+///
+/// # Arguments
+///
+/// * `x` - The non-negative frequency terms of a Hermitian-symmetric spectrum.
+/// * `n` - The length of the real output. `None` uses `2 * (x.len() - 1)`; pass the original
+///   length to recover signals of odd length.
+///
+/// # Returns
+///
+/// A `Vec<f64>` of length `n`. As in NumPy, the imaginary parts of the zero-frequency term and,
+/// for even `n`, of the Nyquist term are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::fft::{irfft, rfft};
+/// use approx::assert_abs_diff_eq;
+///
+/// let x = [0.5, 1.0, -3.0, 2.0, 4.0];
+/// let restored = irfft(&rfft(&x, None).unwrap(), Some(x.len())).unwrap();
+/// for (a, b) in restored.iter().zip(x) {
+///     assert_abs_diff_eq!(*a, b, epsilon = 1e-12);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the output length is zero.
+pub fn irfft<T: Scalar>(x: &[T], n: Option<usize>) -> Result<Vec<f64>, NumrustError> {
+    let n = n.unwrap_or(2 * x.len().saturating_sub(1));
+    if n < 1 {
+        return Err(NumrustError::ValueError(format!(
+            "Invalid number of FFT data points ({}) specified.",
+            n
+        )));
+    }
+    let half = resized(x, Some(n / 2 + 1))?;
+    let mut full = vec![Complex64::new(0.0, 0.0); n];
+    full[0] = Complex64::new(half[0].re, 0.0);
+    for k in 1..=(n - 1) / 2 {
+        full[k] = half[k];
+        full[n - k] = half[k].conj();
+    }
+    if n.is_multiple_of(2) {
+        full[n / 2] = Complex64::new(half[n / 2].re, 0.0);
+    }
+    Ok(inverse(&full).into_iter().map(|z| z.re).collect())
+}
+
+/// Applies a one-dimensional transform to every lane of `a` along each of `axes`.
+fn transform_axes<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axes: Option<&[isize]>,
+    transform: fn(&[Complex64]) -> Vec<Complex64>,
+) -> Result<Array<Complex64, D>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let axes: Vec<usize> = match axes {
+        Some(axes) => axes
+            .iter()
+            .map(|&ax| normalize_axis(ax, a.ndim()))
+            .collect::<Result<_, _>>()?,
+        None => (0..a.ndim()).collect(),
+    };
+    let mut result = a.mapv(Scalar::to_complex);
+    for axis in axes {
+        if result.len_of(Axis(axis)) == 0 {
+            return Err(NumrustError::ValueError(
+                "Invalid number of FFT data points (0) specified.".to_string(),
+            ));
+        }
+        for mut lane in result.lanes_mut(Axis(axis)) {
+            let values: Vec<Complex64> = lane.iter().copied().collect();
+            Zip::from(&mut lane)
+                .and(&transform(&values)[..])
+                .for_each(|out, &v| *out = v);
+        }
+    }
+    Ok(result)
+}
+
+/// Computes the N-dimensional discrete Fourier transform over `axes` (all axes when `None`).
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::fft::fftn;
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[1.0, 2.0], [3.0, 4.0]];
+/// let spectrum = fftn(&a, None).unwrap();
+/// assert_abs_diff_eq!(spectrum[[0, 0]].re, 10.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(spectrum[[1, 0]].re, -4.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(spectrum[[1, 1]].re, 0.0, epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if an axis is out of bounds or has length zero.
+pub fn fftn<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axes: Option<&[isize]>,
+) -> Result<Array<Complex64, D>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    transform_axes(a, axes, forward)
+}
+
+/// Computes the N-dimensional inverse discrete Fourier transform over `axes` (all axes when
+/// `None`).
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if an axis is out of bounds or has length zero.
+pub fn ifftn<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axes: Option<&[isize]>,
+) -> Result<Array<Complex64, D>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    transform_axes(a, axes, inverse)
+}
+
+/// Computes the two-dimensional discrete Fourier transform over the last two axes of `a`.
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `a` has fewer than two dimensions or one of the last two axes has
+/// length zero.
+pub fn fft2<A, S, D>(a: &ArrayBase<S, D>) -> Result<Array<Complex64, D>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fftn(a, Some(&[-2, -1]))
+}
+
+/// Computes the two-dimensional inverse discrete Fourier transform over the last two axes of
+/// `a`.
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `a` has fewer than two dimensions or one of the last two axes has
+/// length zero.
+pub fn ifft2<A, S, D>(a: &ArrayBase<S, D>) -> Result<Array<Complex64, D>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    ifftn(a, Some(&[-2, -1]))
+}
+
+/// Returns the sample frequencies of an `n`-point transform with sample spacing `d`, in the
+/// order produced by [`fft`]: `[0, 1, ..., n/2 - 1, -n/2, ..., -1] / (d * n)` for even `n`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::fft::fftfreq;
+///
+/// assert_eq!(
+///     fftfreq(8, 0.1),
+///     vec![0.0, 1.25, 2.5, 3.75, -5.0, -3.75, -2.5, -1.25]
+/// );
+/// ```
+pub fn fftfreq(n: usize, d: f64) -> Vec<f64> {
+    let scale = 1.0 / (n as f64 * d);
+    let positive = n.div_ceil(2);
+    (0..n)
+        .map(|i| {
+            if i < positive {
+                i as f64 * scale
+            } else {
+                -((n - i) as f64) * scale
+            }
+        })
+        .collect()
+}
+
+/// Returns the sample frequencies of the terms produced by [`rfft`] for an `n`-point transform
+/// with sample spacing `d`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::fft::rfftfreq;
+///
+/// assert_eq!(rfftfreq(8, 0.1), vec![0.0, 1.25, 2.5, 3.75, 5.0]);
+/// ```
+pub fn rfftfreq(n: usize, d: f64) -> Vec<f64> {
+    let scale = 1.0 / (n as f64 * d);
+    (0..=n / 2).map(|i| i as f64 * scale).collect()
+}
+
+fn roll_axes<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axes: Option<&[isize]>,
+    shift: fn(usize) -> usize,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let axes: Vec<usize> = match axes {
+        Some(axes) => axes
+            .iter()
+            .map(|&ax| normalize_axis(ax, a.ndim()))
+            .collect::<Result<_, _>>()?,
+        None => (0..a.ndim()).collect(),
+    };
+    let mut result = a.to_owned();
+    for axis in axes {
+        let len = result.len_of(Axis(axis));
+        if len == 0 {
+            continue;
+        }
+        let by = shift(len);
+        for mut lane in result.lanes_mut(Axis(axis)) {
+            let mut values: Vec<A> = lane.iter().cloned().collect();
+            values.rotate_right(by);
+            Zip::from(&mut lane)
+                .and(&values[..])
+                .for_each(|out, v| *out = v.clone());
+        }
+    }
+    Ok(result)
+}
+
+/// Shifts the zero-frequency term to the centre of the spectrum along `axes` (all axes when
+/// `None`).
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::fft::fftshift;
+///
+/// let freqs = array![0, 1, 2, 3, 4, -5, -4, -3, -2, -1];
+/// assert_eq!(
+///     fftshift(&freqs, None).unwrap(),
+///     array![-5, -4, -3, -2, -1, 0, 1, 2, 3, 4]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if an axis is out of bounds.
+pub fn fftshift<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axes: Option<&[isize]>,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    roll_axes(a, axes, |len| len / 2)
+}
+
+/// The inverse of [`fftshift`]. The two differ for odd lengths.
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if an axis is out of bounds.
+pub fn ifftshift<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axes: Option<&[isize]>,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    roll_axes(a, axes, |len| len - len / 2)
+}
+
+#[cfg(test)]
+mod numrust_fft_tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    fn naive_dft(x: &[Complex64]) -> Vec<Complex64> {
+        let n = x.len();
+        (0..n)
+            .map(|k| {
+                x.iter()
+                    .enumerate()
+                    .map(|(j, &v)| v * unit_root((j * k) % n, n))
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn signal(n: usize) -> Vec<Complex64> {
+        (0..n)
+            .map(|i| Complex64::new((i as f64 * 0.37).sin() + 0.1 * i as f64, (i as f64).cos()))
+            .collect()
+    }
+
+    fn assert_close(actual: &[Complex64], expected: &[Complex64], epsilon: f64) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert_abs_diff_eq!(a.re, e.re, epsilon = epsilon);
+            assert_abs_diff_eq!(a.im, e.im, epsilon = epsilon);
+        }
+    }
+
+    #[test]
+    fn test_fft_matches_naive_dft() {
+        for n in (1..=40).chain([49, 64, 97, 121, 169, 2 * 101, 17 * 19, 1009]) {
+            let x = signal(n);
+            assert_close(&fft(&x, None).unwrap(), &naive_dft(&x), 1e-8 * n as f64);
+        }
+    }
+
+    #[test]
+    fn test_ifft_roundtrip() {
+        for n in [1, 7, 12, 30, 53, 128, 210] {
+            let x = signal(n);
+            let restored = ifft(&fft(&x, None).unwrap(), None).unwrap();
+            assert_close(&restored, &x, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_fft_length_argument() {
+        let padded = fft(&[1.0, 1.0], Some(4)).unwrap();
+        assert_close(
+            &padded,
+            &[
+                Complex64::new(2.0, 0.0),
+                Complex64::new(1.0, -1.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(1.0, 1.0),
+            ],
+            1e-12,
+        );
+        let truncated = fft(&[1.0, 2.0, 3.0], Some(1)).unwrap();
+        assert_eq!(truncated, vec![Complex64::new(1.0, 0.0)]);
+        assert!(fft(&[1.0], Some(0)).is_err());
+        let empty: [f64; 0] = [];
+        assert!(fft(&empty, None).is_err());
+    }
+
+    #[test]
+    fn test_plans_are_cached() {
+        let first = plan(360);
+        fft(&signal(360), None).unwrap();
+        assert!(Arc::ptr_eq(&first, &plan(360)));
+        match &*plan(1013) {
+            FftPlan::Bluestein { inner, .. } => assert!(Arc::ptr_eq(inner, &plan(2048))),
+            other => panic!("expected a Bluestein plan, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_plan_cache_evicts_least_recently_used() {
+        let mut cache = PlanCache::new(2);
+        let four = cache.insert(4, Arc::new(FftPlan::new(4)));
+        cache.insert(6, Arc::new(FftPlan::new(6)));
+        assert!(Arc::ptr_eq(&four, &cache.get(4).unwrap()));
+        // Length 6 is now the least recently used and makes room for length 8.
+        cache.insert(8, Arc::new(FftPlan::new(8)));
+        assert_eq!(cache.plans.len(), 2);
+        assert!(cache.get(6).is_none());
+        assert!(cache.get(4).is_some());
+        assert!(cache.get(8).is_some());
+        // A plan that is already cached is returned instead of the new one.
+        let again = cache.insert(4, Arc::new(FftPlan::new(4)));
+        assert!(Arc::ptr_eq(&four, &again));
+    }
+
+    #[test]
+    fn test_rfft_and_irfft() {
+        let x = [0.3, -1.2, 2.5, 4.0, 0.0, 1.5, -0.7];
+        let half = rfft(&x, None).unwrap();
+        let full = fft(&x, None).unwrap();
+        assert_eq!(half.len(), 4);
+        assert_close(&half, &full[..4], 1e-12);
+
+        let restored = irfft(&half, Some(7)).unwrap();
+        for (a, b) in restored.iter().zip(x) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1e-12);
+        }
+
+        let even = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let restored = irfft(&rfft(&even, None).unwrap(), None).unwrap();
+        assert_eq!(restored.len(), 6);
+        for (a, b) in restored.iter().zip(even) {
+            assert_abs_diff_eq!(*a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_fft2_and_fftn() {
+        let a = Array2::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+        let spectrum = fft2(&a).unwrap();
+        let by_hand = fftn(&fftn(&a, Some(&[1])).unwrap(), Some(&[0])).unwrap();
+        for (x, y) in spectrum.iter().zip(by_hand.iter()) {
+            assert_abs_diff_eq!(x.re, y.re, epsilon = 1e-10);
+            assert_abs_diff_eq!(x.im, y.im, epsilon = 1e-10);
+        }
+        assert_abs_diff_eq!(spectrum[[0, 0]].re, 66.0, epsilon = 1e-10);
+
+        let restored = ifft2(&spectrum).unwrap();
+        for (x, y) in restored.iter().zip(a.iter()) {
+            assert_abs_diff_eq!(x.re, *y, epsilon = 1e-10);
+            assert_abs_diff_eq!(x.im, 0.0, epsilon = 1e-10);
+        }
+        assert!(fft2(&array![1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn test_frequencies() {
+        assert_eq!(fftfreq(5, 1.0), vec![0.0, 0.2, 0.4, -0.4, -0.2]);
+        assert_eq!(rfftfreq(5, 1.0), vec![0.0, 0.2, 0.4]);
+    }
+
+    #[test]
+    fn test_fftshift_roundtrip() {
+        let odd = array![0, 1, 2, -2, -1];
+        let shifted = fftshift(&odd, None).unwrap();
+        assert_eq!(shifted, array![-2, -1, 0, 1, 2]);
+        assert_eq!(ifftshift(&shifted, None).unwrap(), odd);
+
+        let grid = array![[0, 1, 2], [3, 4, 5]];
+        assert_eq!(
+            fftshift(&grid, Some(&[1])).unwrap(),
+            array![[2, 0, 1], [5, 3, 4]]
+        );
+    }
+}
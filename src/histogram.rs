@@ -0,0 +1,677 @@
+use std::cmp::Ordering;
+
+use ndarray::{Array2, ArrayBase, ArrayD, Data, Ix2, IxDyn};
+
+use crate::sorting::{insertion_point, nan_last_cmp, Side};
+use crate::{linspace, skew, variance, NumrustError};
+
+/// The sorted unique elements of a slice together with the bookkeeping `numpy.unique` returns
+/// when `return_index`, `return_inverse` and `return_counts` are all set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unique<T> {
+    /// The sorted unique values.
+    pub values: Vec<T>,
+    /// The index of the first occurrence of each unique value in the input.
+    pub indices: Vec<usize>,
+    /// For every input element, the index of its value in `values`.
+    pub inverse: Vec<usize>,
+    /// The number of times each unique value occurs in the input.
+    pub counts: Vec<usize>,
+}
+
+/// Finds the sorted unique elements of a slice.
+///
+/// All NaN values are collapsed into a single NaN, which sorts last.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::histogram::unique;
+///
+/// let u = unique(&[3, 1, 3, 2, 1, 3]);
+/// assert_eq!(u.values, vec![1, 2, 3]);
+/// assert_eq!(u.indices, vec![1, 3, 0]);
+/// assert_eq!(u.inverse, vec![2, 0, 2, 1, 0, 2]);
+/// assert_eq!(u.counts, vec![2, 1, 3]);
+/// ```
+pub fn unique<T: PartialOrd + Clone>(a: &[T]) -> Unique<T> {
+    let mut order: Vec<usize> = (0..a.len()).collect();
+    order.sort_by(|&i, &j| nan_last_cmp(&a[i], &a[j]));
+
+    let mut result = Unique {
+        values: Vec::new(),
+        indices: Vec::new(),
+        inverse: vec![0; a.len()],
+        counts: Vec::new(),
+    };
+    for i in order {
+        let is_new = match result.values.last() {
+            Some(last) => nan_last_cmp(last, &a[i]) != Ordering::Equal,
+            None => true,
+        };
+        if is_new {
+            result.values.push(a[i].clone());
+            result.indices.push(i);
+            result.counts.push(0);
+        }
+        let group = result.values.len() - 1;
+        result.inverse[i] = group;
+        result.counts[group] += 1;
+    }
+    result
+}
+
+/// Counts the occurrences of each non-negative integer value, optionally summing `weights`
+/// instead of counting.
+///
+/// # Arguments
+///
+/// * `x` - The values to count.
+/// * `weights` - Optional weights, one per element of `x`.
+/// * `minlength` - The minimum length of the output.
+///
+/// # Returns
+///
+/// A vector of length `max(x) + 1` (or `minlength` if larger) whose element `i` is the number
+/// of occurrences of `i` in `x`, or the sum of their weights.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::histogram::bincount;
+///
+/// assert_eq!(bincount(&[0, 1, 1, 3], None, 0).unwrap(), vec![1.0, 2.0, 0.0, 1.0]);
+/// assert_eq!(
+///     bincount(&[0, 1, 1, 2], Some(&[0.5, 0.25, 0.25, 1.0]), 5).unwrap(),
+///     vec![0.5, 0.5, 1.0, 0.0, 0.0]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `weights` and `x` have different lengths.
+pub fn bincount(
+    x: &[usize],
+    weights: Option<&[f64]>,
+    minlength: usize,
+) -> Result<Vec<f64>, NumrustError> {
+    if let Some(w) = weights {
+        if w.len() != x.len() {
+            return Err(NumrustError::ValueError(
+                "The weights and list don't have the same length.".to_string(),
+            ));
+        }
+    }
+    let len = x.iter().map(|&v| v + 1).max().unwrap_or(0).max(minlength);
+    let mut counts = vec![0.0; len];
+    for (i, &v) in x.iter().enumerate() {
+        counts[v] += weights.map_or(1.0, |w| w[i]);
+    }
+    Ok(counts)
+}
+
+/// The bin-width estimators supported by [`histogram`], matching NumPy's string options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinEstimator {
+    /// The smaller of the `Fd` and `Sturges` widths, or `Sturges` if `Fd` gives zero.
+    Auto,
+    /// The Freedman–Diaconis estimator, `2 * IQR / n^(1/3)`.
+    Fd,
+    /// Sturges' rule, `ptp / (log2(n) + 1)`.
+    Sturges,
+    /// Scott's normal reference rule, `(24 * sqrt(pi) / n)^(1/3) * std`.
+    Scott,
+    /// Doane's extension of Sturges' rule for skewed data.
+    Doane,
+    /// The Rice rule, `ptp / (2 * n^(1/3))`.
+    Rice,
+    /// The square-root rule, `ptp / sqrt(n)`.
+    Sqrt,
+}
+
+/// How to choose the bins of a histogram.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bins<'a> {
+    /// This many equal-width bins spanning the range.
+    Count(usize),
+    /// Explicit, monotonically increasing bin edges.
+    Edges(&'a [f64]),
+    /// Equal-width bins whose width is chosen by an estimator.
+    Estimator(BinEstimator),
+}
+
+/// The population standard deviation, built on the crate's sample variance.
+fn population_std(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    (variance(values) * (n - 1.0) / n).sqrt()
+}
+
+/// The `q`-th percentile of sorted data, interpolating linearly between neighbours.
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let position = q / 100.0 * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+fn estimate_bin_width(values: &[f64], ptp: f64, estimator: BinEstimator) -> f64 {
+    let n = values.len() as f64;
+    match estimator {
+        BinEstimator::Sqrt => ptp / n.sqrt(),
+        BinEstimator::Sturges => ptp / (n.log2() + 1.0),
+        BinEstimator::Rice => ptp / (2.0 * n.cbrt()),
+        BinEstimator::Scott => {
+            (24.0 * std::f64::consts::PI.sqrt() / n).cbrt() * population_std(values)
+        }
+        BinEstimator::Doane => {
+            if values.len() > 2 && population_std(values) > 0.0 {
+                let sg1 = (6.0 * (n - 2.0) / ((n + 1.0) * (n + 3.0))).sqrt();
+                let g1 = skew(values);
+                ptp / (1.0 + n.log2() + (1.0 + g1.abs() / sg1).log2())
+            } else {
+                0.0
+            }
+        }
+        BinEstimator::Fd => {
+            let mut sorted = values.to_vec();
+            sorted.sort_by(nan_last_cmp);
+            let iqr = percentile(&sorted, 75.0) - percentile(&sorted, 25.0);
+            2.0 * iqr / n.cbrt()
+        }
+        BinEstimator::Auto => {
+            let fd = estimate_bin_width(values, ptp, BinEstimator::Fd);
+            let sturges = estimate_bin_width(values, ptp, BinEstimator::Sturges);
+            if fd > 0.0 {
+                fd.min(sturges)
+            } else {
+                sturges
+            }
+        }
+    }
+}
+
+/// Computes the edges of the bins used by [`histogram`], as `numpy.histogram_bin_edges` does.
+///
+/// # Arguments
+///
+/// * `a` - The input data.
+/// * `bins` - A bin count, explicit edges or a bin-width estimator.
+/// * `range` - The lower and upper edge of the bins. Defaults to the minimum and maximum of `a`.
+///   Values outside the range are ignored by the estimators.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::histogram::{histogram_bin_edges, BinEstimator, Bins};
+///
+/// let data = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+/// let edges = histogram_bin_edges(&data, Bins::Estimator(BinEstimator::Sqrt), None).unwrap();
+/// assert_eq!(edges.len(), 4);
+/// assert_eq!(edges[0], 0.0);
+/// assert_eq!(edges[3], 7.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the range is not finite or is decreasing, if the bin
+/// count is zero, or if explicit edges are not monotonically increasing.
+pub fn histogram_bin_edges<T: Into<f64> + Copy>(
+    a: &[T],
+    bins: Bins,
+    range: Option<(f64, f64)>,
+) -> Result<Vec<f64>, NumrustError> {
+    let values: Vec<f64> = a.iter().map(|&x| x.into()).collect();
+    bin_edges(&values, bins, range)
+}
+
+fn bin_edges(
+    values: &[f64],
+    bins: Bins,
+    range: Option<(f64, f64)>,
+) -> Result<Vec<f64>, NumrustError> {
+    if let Bins::Edges(edges) = bins {
+        if edges.windows(2).any(|w| w[0] > w[1]) || edges.is_empty() {
+            return Err(NumrustError::ValueError(
+                "`bins` must increase monotonically, when an array".to_string(),
+            ));
+        }
+        return Ok(edges.to_vec());
+    }
+
+    let (mut first, mut last) = match range {
+        Some((lo, hi)) => {
+            if lo > hi {
+                return Err(NumrustError::ValueError(
+                    "max must be larger than min in range parameter.".to_string(),
+                ));
+            }
+            (lo, hi)
+        }
+        None if values.is_empty() => (0.0, 1.0),
+        None if values.iter().any(|x| x.is_nan()) => (f64::NAN, f64::NAN),
+        None => values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| {
+                (lo.min(x), hi.max(x))
+            }),
+    };
+    if !first.is_finite() || !last.is_finite() {
+        return Err(NumrustError::ValueError(format!(
+            "autodetected range of [{}, {}] is not finite",
+            first, last
+        )));
+    }
+    if first == last {
+        first -= 0.5;
+        last += 0.5;
+    }
+
+    let count = match bins {
+        Bins::Count(0) => {
+            return Err(NumrustError::ValueError(
+                "`bins` must be positive, when an integer".to_string(),
+            ))
+        }
+        Bins::Count(count) => count,
+        Bins::Estimator(estimator) => {
+            let in_range: Vec<f64> = values
+                .iter()
+                .copied()
+                .filter(|&x| x >= first && x <= last)
+                .collect();
+            if in_range.is_empty() {
+                1
+            } else {
+                let width = estimate_bin_width(&in_range, last - first, estimator);
+                if width > 0.0 {
+                    ((last - first) / width).ceil() as usize
+                } else {
+                    1
+                }
+            }
+        }
+        Bins::Edges(_) => unreachable!(),
+    };
+    let mut edges = linspace(first, last, count + 1);
+    edges[count] = last;
+    Ok(edges)
+}
+
+/// Returns the bin of `x` in `edges`, counting a value on the last edge as part of the last
+/// bin, or `None` if `x` lies outside the edges.
+fn find_bin(edges: &[f64], x: f64) -> Option<usize> {
+    let last = edges.len() - 1;
+    if x.is_nan() || x < edges[0] || x > edges[last] {
+        return None;
+    }
+    if x == edges[last] {
+        return Some(last - 1).filter(|_| last > 0);
+    }
+    Some(insertion_point(edges, &x, Side::Right) - 1)
+}
+
+/// Computes the histogram of a dataset.
+///
+/// # Arguments
+///
+/// * `a` - The input data.
+/// * `bins` - A bin count, explicit edges or a bin-width estimator.
+/// * `range` - The lower and upper edge of the bins. Values outside it are ignored.
+/// * `weights` - Optional weights, one per element of `a`, summed instead of counting.
+/// * `density` - If `true`, normalize the result so that it integrates to one over the range.
+///
+/// # Returns
+///
+/// A tuple of the histogram values and the `len + 1` bin edges. Every bin is half-open except the
+/// last, which includes its right edge.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::histogram::{histogram, Bins};
+///
+/// let (hist, edges) = histogram(&[1, 2, 1], Bins::Edges(&[0.0, 1.0, 2.0, 3.0]), None, None, false).unwrap();
+/// assert_eq!(hist, vec![0.0, 2.0, 1.0]);
+/// assert_eq!(edges, vec![0.0, 1.0, 2.0, 3.0]);
+///
+/// let (density, _) = histogram(&[0.5, 1.5, 1.5, 3.0], Bins::Count(3), Some((0.0, 3.0)), None, true).unwrap();
+/// assert_eq!(density, vec![0.25, 0.5, 0.25]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the bins or range are invalid or if `weights` has a
+/// different length than `a`.
+pub fn histogram<T: Into<f64> + Copy>(
+    a: &[T],
+    bins: Bins,
+    range: Option<(f64, f64)>,
+    weights: Option<&[f64]>,
+    density: bool,
+) -> Result<(Vec<f64>, Vec<f64>), NumrustError> {
+    let values: Vec<f64> = a.iter().map(|&x| x.into()).collect();
+    if let Some(w) = weights {
+        if w.len() != values.len() {
+            return Err(NumrustError::ValueError(
+                "weights should have the same shape as a.".to_string(),
+            ));
+        }
+    }
+    let edges = bin_edges(&values, bins, range)?;
+    let mut hist = vec![0.0; edges.len() - 1];
+    for (i, &x) in values.iter().enumerate() {
+        if let Some(bin) = find_bin(&edges, x) {
+            hist[bin] += weights.map_or(1.0, |w| w[i]);
+        }
+    }
+    if density {
+        let total: f64 = hist.iter().sum();
+        for (h, w) in hist.iter_mut().zip(edges.windows(2)) {
+            *h /= total * (w[1] - w[0]);
+        }
+    }
+    Ok((hist, edges))
+}
+
+/// Computes the multidimensional histogram of a set of points.
+///
+/// # Arguments
+///
+/// * `sample` - An `(N, D)` array of `N` points in `D` dimensions.
+/// * `bins` - Either one bin specification used for every dimension or one per dimension.
+/// * `range` - Optional `(min, max)` per dimension. Points outside it are ignored.
+/// * `weights` - Optional weights, one per point.
+/// * `density` - If `true`, normalize the result so that it integrates to one.
+///
+/// # Returns
+///
+/// A tuple of the `D`-dimensional histogram and the bin edges along each dimension.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::histogram::{histogramdd, Bins};
+///
+/// let points = array![[0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [1.0, 0.0, 1.0]];
+/// let (hist, edges) = histogramdd(&points, &[Bins::Count(2)], None, None, false).unwrap();
+/// assert_eq!(hist.shape(), &[2, 2, 2]);
+/// assert_eq!(hist[[1, 0, 1]], 1.0);
+/// assert_eq!(edges[0], vec![0.0, 0.5, 1.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the number of bin specifications or ranges does not
+/// match the number of dimensions, or if any of the bins, ranges or weights are invalid.
+pub fn histogramdd<A, S>(
+    sample: &ArrayBase<S, Ix2>,
+    bins: &[Bins],
+    range: Option<&[(f64, f64)]>,
+    weights: Option<&[f64]>,
+    density: bool,
+) -> Result<(ArrayD<f64>, Vec<Vec<f64>>), NumrustError>
+where
+    A: Into<f64> + Copy,
+    S: Data<Elem = A>,
+{
+    let (n, dims) = sample.dim();
+    if bins.len() != 1 && bins.len() != dims {
+        return Err(NumrustError::ValueError(
+            "The dimension of bins must be equal to the dimension of the sample x.".to_string(),
+        ));
+    }
+    if range.is_some_and(|r| r.len() != dims) {
+        return Err(NumrustError::ValueError(
+            "range argument must have one entry per dimension".to_string(),
+        ));
+    }
+    if weights.is_some_and(|w| w.len() != n) {
+        return Err(NumrustError::ValueError(
+            "weights should have one entry per sample point.".to_string(),
+        ));
+    }
+
+    let mut edges = Vec::with_capacity(dims);
+    for d in 0..dims {
+        let column: Vec<f64> = sample.column(d).iter().map(|&x| x.into()).collect();
+        let spec = if bins.len() == 1 { bins[0] } else { bins[d] };
+        edges.push(bin_edges(&column, spec, range.map(|r| r[d]))?);
+    }
+
+    let shape: Vec<usize> = edges.iter().map(|e| e.len() - 1).collect();
+    let mut hist = ArrayD::zeros(IxDyn(&shape));
+    let mut index = vec![0; dims];
+    'points: for (i, point) in sample.rows().into_iter().enumerate() {
+        for (d, &x) in point.iter().enumerate() {
+            match find_bin(&edges[d], x.into()) {
+                Some(bin) => index[d] = bin,
+                None => continue 'points,
+            }
+        }
+        hist[IxDyn(&index)] += weights.map_or(1.0, |w| w[i]);
+    }
+
+    if density {
+        let total = hist.sum();
+        for (idx, h) in hist.indexed_iter_mut() {
+            let volume: f64 = (0..dims)
+                .map(|d| edges[d][idx[d] + 1] - edges[d][idx[d]])
+                .product();
+            *h /= total * volume;
+        }
+    }
+    Ok((hist, edges))
+}
+
+/// A two-dimensional histogram with its edges along `x` and along `y`.
+type Histogram2d = (Array2<f64>, Vec<f64>, Vec<f64>);
+
+/// Computes the two-dimensional histogram of paired samples `x` and `y`.
+///
+/// The first axis of the result follows `x` and the second follows `y`.
+///
+/// # Returns
+///
+/// A tuple of the histogram, the edges along `x` and the edges along `y`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::histogram::{histogram2d, Bins};
+///
+/// let x = [0.0, 1.0, 1.0];
+/// let y = [0.0, 0.0, 2.0];
+/// let (hist, x_edges, y_edges) =
+///     histogram2d(&x, &y, [Bins::Count(2), Bins::Count(2)], None, None, false).unwrap();
+/// assert_eq!(hist, ndarray::array![[1.0, 0.0], [1.0, 1.0]]);
+/// assert_eq!(x_edges, vec![0.0, 0.5, 1.0]);
+/// assert_eq!(y_edges, vec![0.0, 1.0, 2.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `x` and `y` have different lengths or if any of the
+/// bins, ranges or weights are invalid.
+pub fn histogram2d<T: Into<f64> + Copy>(
+    x: &[T],
+    y: &[T],
+    bins: [Bins; 2],
+    range: Option<[(f64, f64); 2]>,
+    weights: Option<&[f64]>,
+    density: bool,
+) -> Result<Histogram2d, NumrustError> {
+    if x.len() != y.len() {
+        return Err(NumrustError::ValueError(
+            "x and y must have the same length".to_string(),
+        ));
+    }
+    let sample =
+        Array2::from_shape_fn(
+            (x.len(), 2),
+            |(i, d)| {
+                if d == 0 {
+                    x[i].into()
+                } else {
+                    y[i].into()
+                }
+            },
+        );
+    let (hist, mut edges) = histogramdd(
+        &sample,
+        &bins,
+        range.as_ref().map(|r| &r[..]),
+        weights,
+        density,
+    )?;
+    let hist = hist
+        .into_dimensionality::<Ix2>()
+        .expect("a two-dimensional sample gives a two-dimensional histogram");
+    let y_edges = edges.pop().unwrap_or_default();
+    let x_edges = edges.pop().unwrap_or_default();
+    Ok((hist, x_edges, y_edges))
+}
+
+/// Returns the index of the bin each value of `x` belongs to.
+///
+/// # Arguments
+///
+/// * `x` - The values to bin.
+/// * `bins` - Monotonically increasing or decreasing bin edges.
+/// * `right` - Whether the bins include their right edge instead of their left edge.
+///
+/// # Returns
+///
+/// For increasing bins, the index `i` such that `bins[i - 1] <= x < bins[i]` (or
+/// `bins[i - 1] < x <= bins[i]` if `right` is set). Values below the first edge get `0` and
+/// values beyond the last get `bins.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::histogram::digitize;
+///
+/// let bins = [0.0, 1.0, 2.5, 4.0, 10.0];
+/// let x = [0.2, 6.4, 3.0, 1.6, 10.0];
+/// assert_eq!(digitize(&x, &bins, false).unwrap(), vec![1, 4, 3, 2, 5]);
+/// assert_eq!(digitize(&x, &bins, true).unwrap(), vec![1, 4, 3, 2, 4]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `bins` is not monotonic.
+pub fn digitize<T: Into<f64> + Copy>(
+    x: &[T],
+    bins: &[f64],
+    right: bool,
+) -> Result<Vec<usize>, NumrustError> {
+    let increasing = bins.windows(2).all(|w| w[0] <= w[1]);
+    let decreasing = bins.windows(2).all(|w| w[0] >= w[1]);
+    if !increasing && !decreasing {
+        return Err(NumrustError::ValueError(
+            "bins must be monotonically increasing or decreasing".to_string(),
+        ));
+    }
+    let side = if right { Side::Left } else { Side::Right };
+    if increasing {
+        Ok(x.iter()
+            .map(|&v| insertion_point(bins, &v.into(), side))
+            .collect())
+    } else {
+        let reversed: Vec<f64> = bins.iter().rev().copied().collect();
+        Ok(x.iter()
+            .map(|&v| bins.len() - insertion_point(&reversed, &v.into(), side))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod numrust_histogram_tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_unique_collapses_nans() {
+        let u = unique(&[f64::NAN, 2.0, 1.0, f64::NAN, 2.0]);
+        assert_eq!(u.values.len(), 3);
+        assert_eq!(&u.values[..2], &[1.0, 2.0]);
+        assert!(u.values[2].is_nan());
+        assert_eq!(u.indices, vec![2, 1, 0]);
+        assert_eq!(u.inverse, vec![2, 1, 0, 2, 1]);
+        assert_eq!(u.counts, vec![1, 2, 2]);
+
+        let empty: Unique<i32> = unique(&[]);
+        assert!(empty.values.is_empty() && empty.inverse.is_empty());
+    }
+
+    #[test]
+    fn test_bincount_errors_and_minlength() {
+        assert_eq!(bincount(&[], None, 3).unwrap(), vec![0.0; 3]);
+        assert!(bincount(&[1, 2], Some(&[1.0]), 0).is_err());
+    }
+
+    #[test]
+    fn test_histogram_estimators_match_numpy() {
+        let data: Vec<f64> = (0..20)
+            .map(|i| ((i * 7) % 20) as f64 + (i as f64 / 10.0))
+            .collect();
+        let count = |estimator| {
+            histogram_bin_edges(&data, Bins::Estimator(estimator), None)
+                .unwrap()
+                .len()
+                - 1
+        };
+        // Bin counts given by NumPy's estimator formulas for this data.
+        assert_eq!(count(BinEstimator::Sqrt), 5);
+        assert_eq!(count(BinEstimator::Sturges), 6);
+        assert_eq!(count(BinEstimator::Rice), 6);
+        assert_eq!(count(BinEstimator::Scott), 3);
+        assert_eq!(count(BinEstimator::Fd), 4);
+        assert_eq!(count(BinEstimator::Doane), 6);
+        assert_eq!(count(BinEstimator::Auto), 6);
+    }
+
+    #[test]
+    fn test_histogram_single_value_and_range() {
+        let (hist, edges) = histogram(&[2.0, 2.0], Bins::Count(2), None, None, false).unwrap();
+        assert_eq!(hist, vec![0.0, 2.0]);
+        assert_eq!(edges, vec![1.5, 2.0, 2.5]);
+
+        let (hist, _) = histogram(
+            &[-1.0, 0.0, 0.5, 1.0, 2.0],
+            Bins::Count(2),
+            Some((0.0, 1.0)),
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(hist, vec![1.0, 2.0]);
+
+        let weights = [1.0, 2.0, 3.0];
+        let (hist, _) = histogram(&[0, 1, 2], Bins::Count(3), None, Some(&weights), false).unwrap();
+        assert_eq!(hist, weights.to_vec());
+
+        assert!(histogram(&[1.0, f64::NAN], Bins::Count(2), None, None, false).is_err());
+        assert!(histogram(&[1.0], Bins::Edges(&[1.0, 0.0]), None, None, false).is_err());
+        assert!(histogram(&[1.0], Bins::Count(0), None, None, false).is_err());
+    }
+
+    #[test]
+    fn test_histogramdd_density_integrates_to_one() {
+        let points = Array2::from_shape_fn((50, 2), |(i, d)| ((i * (d + 3)) % 17) as f64);
+        let (hist, edges) =
+            histogramdd(&points, &[Bins::Count(4), Bins::Count(3)], None, None, true).unwrap();
+        let area = (edges[0][1] - edges[0][0]) * (edges[1][1] - edges[1][0]);
+        assert_abs_diff_eq!(hist.sum() * area, 1.0, epsilon = 1e-12);
+        assert!(histogramdd(&points, &[Bins::Count(1); 3], None, None, false).is_err());
+    }
+
+    #[test]
+    fn test_digitize_decreasing() {
+        let bins = [10.0, 4.0, 2.5, 1.0, 0.0];
+        let x = [0.2, 6.4, 3.0, 1.6, 10.0];
+        assert_eq!(digitize(&x, &bins, false).unwrap(), vec![4, 1, 2, 3, 0]);
+        assert_eq!(digitize(&x, &bins, true).unwrap(), vec![4, 1, 2, 3, 1]);
+        assert!(digitize(&x, &[0.0, 2.0, 1.0], false).is_err());
+    }
+}
@@ -0,0 +1,980 @@
+use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
+
+use ndarray::{
+    Array, Array1, Array2, ArrayBase, ArrayViewD, ArrayViewMutD, Axis, Data, DataMut, Dimension,
+    IxDyn, SliceInfo, SliceInfoElem,
+};
+
+use crate::{normalize_axis, NdArray, NumrustError};
+
+/// One entry of a NumPy-style index, as built by the [`npslice!`](crate::npslice) macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceItem {
+    /// A single position, which removes the axis. Negative values count from the end.
+    Index(isize),
+    /// A range of positions with a step. Negative bounds count from the end, out-of-range bounds
+    /// are clipped, and a negative step walks backwards from `start`.
+    Slice {
+        start: Option<isize>,
+        stop: Option<isize>,
+        step: isize,
+    },
+    /// A new axis of length one.
+    NewAxis,
+}
+
+/// Marker for inserting a new axis of length one with [`npslice!`](crate::npslice).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewAxis;
+
+impl SliceItem {
+    /// Sets the step of a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is an index or a new axis, which cannot have a step.
+    pub fn step(self, step: isize) -> SliceItem {
+        match self {
+            SliceItem::Slice { start, stop, .. } => SliceItem::Slice { start, stop, step },
+            _ => panic!("only slices can have a step"),
+        }
+    }
+}
+
+impl From<isize> for SliceItem {
+    fn from(index: isize) -> SliceItem {
+        SliceItem::Index(index)
+    }
+}
+
+impl From<Range<isize>> for SliceItem {
+    fn from(range: Range<isize>) -> SliceItem {
+        SliceItem::Slice {
+            start: Some(range.start),
+            stop: Some(range.end),
+            step: 1,
+        }
+    }
+}
+
+impl From<RangeFrom<isize>> for SliceItem {
+    fn from(range: RangeFrom<isize>) -> SliceItem {
+        SliceItem::Slice {
+            start: Some(range.start),
+            stop: None,
+            step: 1,
+        }
+    }
+}
+
+impl From<RangeTo<isize>> for SliceItem {
+    fn from(range: RangeTo<isize>) -> SliceItem {
+        SliceItem::Slice {
+            start: None,
+            stop: Some(range.end),
+            step: 1,
+        }
+    }
+}
+
+impl From<RangeFull> for SliceItem {
+    fn from(_: RangeFull) -> SliceItem {
+        SliceItem::Slice {
+            start: None,
+            stop: None,
+            step: 1,
+        }
+    }
+}
+
+impl From<NewAxis> for SliceItem {
+    fn from(_: NewAxis) -> SliceItem {
+        SliceItem::NewAxis
+    }
+}
+
+/// Builds a NumPy-style index for [`slice`](crate::indexing::slice) and
+/// [`slice_mut`](crate::indexing::slice_mut).
+///
+/// Each comma-separated entry is an index, a range (optionally followed by `;step`), `..`, or
+/// [`NewAxis`](crate::indexing::NewAxis). Unlike `ndarray::s!`, negative steps follow NumPy:
+/// `8..2;-3` walks backwards from position 8.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{array, Array};
+/// use numrust::indexing::slice;
+/// use numrust::npslice;
+///
+/// let a = Array::from_iter(0..10);
+/// assert_eq!(slice(&a, &npslice![-3..]).unwrap(), array![7, 8, 9].into_dyn());
+/// assert_eq!(slice(&a, &npslice![..;-3]).unwrap(), array![9, 6, 3, 0].into_dyn());
+/// assert_eq!(slice(&a, &npslice![8..2;-3]).unwrap(), array![8, 5].into_dyn());
+/// ```
+#[macro_export]
+macro_rules! npslice {
+    ($($item:expr $(; $step:expr)?),* $(,)?) => {
+        [$($crate::indexing::SliceItem::from($item)$(.step($step))?),*]
+    };
+}
+
+fn resolve_index(index: isize, len: usize, axis: usize) -> Result<usize, NumrustError> {
+    let resolved = if index < 0 {
+        index + len as isize
+    } else {
+        index
+    };
+    if resolved < 0 || resolved >= len as isize {
+        Err(NumrustError::IndexError(format!(
+            "index {} is out of bounds for axis {} with size {}",
+            index, axis, len
+        )))
+    } else {
+        Ok(resolved as usize)
+    }
+}
+
+/// Translates a NumPy index into the equivalent `ndarray` slice description.
+fn slice_info(
+    shape: &[usize],
+    items: &[SliceItem],
+) -> Result<SliceInfo<Vec<SliceInfoElem>, IxDyn, IxDyn>, NumrustError> {
+    let consumed = items
+        .iter()
+        .filter(|item| !matches!(item, SliceItem::NewAxis))
+        .count();
+    if consumed > shape.len() {
+        return Err(NumrustError::IndexError(format!(
+            "too many indices for array: array is {}-dimensional, but {} were indexed",
+            shape.len(),
+            consumed
+        )));
+    }
+
+    let mut elems = Vec::with_capacity(items.len() + shape.len() - consumed);
+    let mut axis = 0;
+    for item in items {
+        match *item {
+            SliceItem::NewAxis => elems.push(SliceInfoElem::NewAxis),
+            SliceItem::Index(index) => {
+                let resolved = resolve_index(index, shape[axis], axis)?;
+                elems.push(SliceInfoElem::Index(resolved as isize));
+                axis += 1;
+            }
+            SliceItem::Slice { start, stop, step } => {
+                let len = shape[axis] as isize;
+                let wrap = |i: isize| if i < 0 { i + len } else { i };
+                let (lo, hi) = match step {
+                    0 => {
+                        return Err(NumrustError::ValueError(
+                            "slice step cannot be zero".to_string(),
+                        ))
+                    }
+                    s if s > 0 => {
+                        let lo = start.map_or(0, |i| wrap(i).clamp(0, len));
+                        let hi = stop.map_or(len, |i| wrap(i).clamp(0, len));
+                        (lo, hi.max(lo))
+                    }
+                    _ => {
+                        // Walking backwards covers start, start + step, ... down to just above stop.
+                        let first = start.map_or(len - 1, |i| wrap(i).clamp(-1, len - 1));
+                        let last = stop.map_or(-1, |i| wrap(i).clamp(-1, len - 1));
+                        let hi = first + 1;
+                        ((last + 1).min(hi), hi)
+                    }
+                };
+                elems.push(SliceInfoElem::Slice {
+                    start: lo,
+                    end: Some(hi),
+                    step,
+                });
+                axis += 1;
+            }
+        }
+    }
+    elems.extend((axis..shape.len()).map(|_| SliceInfoElem::from(..)));
+    Ok(SliceInfo::try_from(elems).expect("one slice entry per axis"))
+}
+
+/// Returns a view of `a` selected by a NumPy-style index built with [`npslice!`](crate::npslice).
+///
+/// Axes that are not indexed are kept whole.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::Array2;
+/// use numrust::indexing::{slice, NewAxis};
+/// use numrust::npslice;
+///
+/// let a = Array2::from_shape_fn((3, 4), |(i, j)| 10 * i + j);
+/// let v = slice(&a, &npslice![-1, ..;-2]).unwrap();
+/// assert_eq!(v.to_owned().into_raw_vec(), vec![23, 21]);
+/// assert_eq!(slice(&a, &npslice![NewAxis, 1..]).unwrap().shape(), &[1, 2, 4]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::IndexError` if there are more indices than axes or an index is out of
+/// bounds, and a `NumrustError::ValueError` if a step is zero.
+pub fn slice<'a, A, S, D>(
+    a: &'a ArrayBase<S, D>,
+    items: &[SliceItem],
+) -> Result<ArrayViewD<'a, A>, NumrustError>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let info = slice_info(a.shape(), items)?;
+    Ok(a.view().into_dyn().slice_move(info))
+}
+
+/// Returns a mutable view of `a` selected by a NumPy-style index, for assigning to a slice.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::slice_mut;
+/// use numrust::npslice;
+///
+/// let mut a = array![0, 1, 2, 3, 4];
+/// slice_mut(&mut a, &npslice![..;-2]).unwrap().fill(9);
+/// assert_eq!(a, array![9, 1, 9, 3, 9]);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`slice`].
+pub fn slice_mut<'a, A, S, D>(
+    a: &'a mut ArrayBase<S, D>,
+    items: &[SliceItem],
+) -> Result<ArrayViewMutD<'a, A>, NumrustError>
+where
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    let info = slice_info(a.shape(), items)?;
+    Ok(a.view_mut().into_dyn().slice_move(info))
+}
+
+/// Resolves one integer index array per axis into multi-indices, checking that the arrays have
+/// equal lengths and that every index is in bounds.
+fn resolve_nd(shape: &[usize], indices: &[&[isize]]) -> Result<Vec<Vec<usize>>, NumrustError> {
+    if indices.len() != shape.len() {
+        return Err(NumrustError::IndexError(format!(
+            "expected {} index arrays, got {}",
+            shape.len(),
+            indices.len()
+        )));
+    }
+    let count = indices.first().map_or(0, |i| i.len());
+    if indices.iter().any(|i| i.len() != count) {
+        return Err(NumrustError::IndexError(
+            "shape mismatch: indexing arrays could not be broadcast together".to_string(),
+        ));
+    }
+    (0..count)
+        .map(|k| {
+            (0..shape.len())
+                .map(|axis| resolve_index(indices[axis][k], shape[axis], axis))
+                .collect()
+        })
+        .collect()
+}
+
+/// Gathers the elements of `a` at the positions given by one integer index array per axis, as
+/// NumPy's `a[rows, cols]` does.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::gather_nd;
+///
+/// let a = array![[1, 2], [3, 4], [5, 6]];
+/// assert_eq!(gather_nd(&a, &[&[0, 1, -1], &[0, 1, 0]]).unwrap(), array![1, 4, 5]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::IndexError` if there is not one index array per axis, the index
+/// arrays have different lengths, or an index is out of bounds.
+pub fn gather_nd<A, S, D>(
+    a: &ArrayBase<S, D>,
+    indices: &[&[isize]],
+) -> Result<Array1<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let view = a.view().into_dyn();
+    Ok(resolve_nd(a.shape(), indices)?
+        .into_iter()
+        .map(|index| view[IxDyn(&index)].clone())
+        .collect())
+}
+
+/// Assigns `values` to the elements of `a` at the positions given by one integer index array per
+/// axis, as NumPy's `a[rows, cols] = values` does. Later positions win when an index repeats.
+///
+/// # Arguments
+///
+/// * `a` - The array to modify.
+/// * `indices` - One index array per axis, all of the same length.
+/// * `values` - Either a single value assigned everywhere or one value per position.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::scatter_nd;
+///
+/// let mut a = array![[0, 0], [0, 0]];
+/// scatter_nd(&mut a, &[&[0, 1], &[1, 0]], &[7, 8]).unwrap();
+/// assert_eq!(a, array![[0, 7], [8, 0]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if the indices are invalid or `values` has the wrong length.
+pub fn scatter_nd<A, S, D>(
+    a: &mut ArrayBase<S, D>,
+    indices: &[&[isize]],
+    values: &[A],
+) -> Result<(), NumrustError>
+where
+    A: Clone,
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    let positions = resolve_nd(a.shape(), indices)?;
+    check_value_count(values.len(), positions.len())?;
+    let mut view = a.view_mut().into_dyn();
+    for (k, index) in positions.into_iter().enumerate() {
+        view[IxDyn(&index)] = values[k % values.len()].clone();
+    }
+    Ok(())
+}
+
+fn check_value_count(values: usize, positions: usize) -> Result<(), NumrustError> {
+    if values == 1 || values == positions {
+        Ok(())
+    } else {
+        Err(NumrustError::ValueError(format!(
+            "cannot assign {} input values to the {} output values",
+            values, positions
+        )))
+    }
+}
+
+/// Returns the elements of `a` where `mask` is `true`, in row-major order, as NumPy's `a[mask]`
+/// does.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::mask_select;
+///
+/// let a = array![[1, -2], [-3, 4]];
+/// let mask = a.mapv(|x| x > 0);
+/// assert_eq!(mask_select(&a, &mask).unwrap(), array![1, 4]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::IndexError` if `mask` and `a` have different shapes.
+pub fn mask_select<A, S, T, D>(
+    a: &ArrayBase<S, D>,
+    mask: &ArrayBase<T, D>,
+) -> Result<Array1<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    T: Data<Elem = bool>,
+    D: Dimension,
+{
+    check_mask_shape(a.shape(), mask.shape())?;
+    Ok(a.iter()
+        .zip(mask.iter())
+        .filter(|(_, &m)| m)
+        .map(|(x, _)| x.clone())
+        .collect())
+}
+
+/// Assigns `values` to the elements of `a` where `mask` is `true`, as NumPy's `a[mask] = values`
+/// does.
+///
+/// # Arguments
+///
+/// * `a` - The array to modify.
+/// * `mask` - A boolean array of the same shape as `a`.
+/// * `values` - Either a single value assigned everywhere or one value per `true` element.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::mask_assign;
+///
+/// let mut a = array![1.0, f64::NAN, 3.0, f64::NAN];
+/// let mask = a.mapv(f64::is_nan);
+/// mask_assign(&mut a, &mask, &[0.0]).unwrap();
+/// assert_eq!(a, array![1.0, 0.0, 3.0, 0.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if the shapes differ or `values` has the wrong length.
+pub fn mask_assign<A, S, T, D>(
+    a: &mut ArrayBase<S, D>,
+    mask: &ArrayBase<T, D>,
+    values: &[A],
+) -> Result<(), NumrustError>
+where
+    A: Clone,
+    S: DataMut<Elem = A>,
+    T: Data<Elem = bool>,
+    D: Dimension,
+{
+    check_mask_shape(a.shape(), mask.shape())?;
+    check_value_count(values.len(), mask.iter().filter(|&&m| m).count())?;
+    let targets = a.iter_mut().zip(mask.iter()).filter(|(_, &m)| m);
+    for (k, (x, _)) in targets.enumerate() {
+        *x = values[k % values.len()].clone();
+    }
+    Ok(())
+}
+
+fn check_mask_shape(shape: &[usize], mask: &[usize]) -> Result<(), NumrustError> {
+    if shape == mask {
+        Ok(())
+    } else {
+        Err(NumrustError::IndexError(format!(
+            "boolean index of shape {:?} does not match indexed array of shape {:?}",
+            mask, shape
+        )))
+    }
+}
+
+/// Chooses elements from `x` where `condition` is `true` and from `y` elsewhere.
+///
+/// `x` and `y` are broadcast to the shape of `condition`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::r#where;
+///
+/// let a = array![[1, -2], [-3, 4]];
+/// let clipped = r#where(&a.mapv(|x| x > 0), &a, &array![[0, 0]]).unwrap();
+/// assert_eq!(clipped, array![[1, 0], [0, 4]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `x` or `y` cannot be broadcast to the shape of
+/// `condition`.
+pub fn r#where<A, T, S1, S2, D>(
+    condition: &ArrayBase<T, D>,
+    x: &ArrayBase<S1, D>,
+    y: &ArrayBase<S2, D>,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone,
+    T: Data<Elem = bool>,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    let broadcast_error = || {
+        NumrustError::ValueError(
+            "operands could not be broadcast together with the condition".to_string(),
+        )
+    };
+    let x = x
+        .broadcast(condition.raw_dim())
+        .ok_or_else(broadcast_error)?;
+    let y = y
+        .broadcast(condition.raw_dim())
+        .ok_or_else(broadcast_error)?;
+    let mut result = x.to_owned();
+    ndarray::Zip::from(&mut result)
+        .and(condition)
+        .and(&y)
+        .for_each(|r, &c, v| {
+            if !c {
+                *r = v.clone();
+            }
+        });
+    Ok(result)
+}
+
+/// Takes elements from `a` at the given positions along `axis`.
+///
+/// # Arguments
+///
+/// * `a` - The source array.
+/// * `indices` - The positions to take. Negative values count from the end.
+/// * `axis` - The axis to take along, or `None` to take from the flattened array.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::take;
+///
+/// let a = array![[4, 3], [5, 7], [6, 8]];
+/// assert_eq!(take(&a, &[0, 1, 4], None).unwrap(), array![4, 3, 6].into_dyn());
+/// assert_eq!(take(&a, &[-1, 0], Some(0)).unwrap(), array![[6, 8], [4, 3]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds or an index is out of range.
+pub fn take<A, S, D>(
+    a: &ArrayBase<S, D>,
+    indices: &[isize],
+    axis: Option<isize>,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let (source, axis) = match axis {
+        Some(ax) => (
+            a.view().into_dyn().to_owned(),
+            normalize_axis(ax, a.ndim())?,
+        ),
+        None => (Array1::from_iter(a.iter().cloned()).into_dyn(), 0),
+    };
+    let len = source.len_of(Axis(axis));
+    let resolved = indices
+        .iter()
+        .map(|&i| resolve_index(i, len, axis))
+        .collect::<Result<Vec<usize>, NumrustError>>()?;
+    Ok(source.select(Axis(axis), &resolved))
+}
+
+/// Converts a position in the row-major flattening of an array of `shape` into a multi-index.
+fn unravel_index(mut flat: usize, shape: &[usize]) -> Vec<usize> {
+    let mut index = vec![0; shape.len()];
+    for (i, &len) in shape.iter().enumerate().rev() {
+        index[i] = flat % len;
+        flat /= len;
+    }
+    index
+}
+
+/// Replaces elements of `a` at positions of its row-major flattening, as `numpy.put` does.
+///
+/// `values` is repeated if it is shorter than `indices`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::put;
+///
+/// let mut a = array![[0, 1], [2, 3]];
+/// put(&mut a, &[0, -1], &[-44]).unwrap();
+/// assert_eq!(a, array![[-44, 1], [2, -44]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if an index is out of range or `values` is empty while `indices` is
+/// not.
+pub fn put<A, S, D>(
+    a: &mut ArrayBase<S, D>,
+    indices: &[isize],
+    values: &[A],
+) -> Result<(), NumrustError>
+where
+    A: Clone,
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    if values.is_empty() && !indices.is_empty() {
+        return Err(NumrustError::ValueError(
+            "cannot put an empty sequence of values".to_string(),
+        ));
+    }
+    let shape = a.shape().to_vec();
+    let size = a.len();
+    let mut view = a.view_mut().into_dyn();
+    for (k, &i) in indices.iter().enumerate() {
+        let flat = resolve_index(i, size, 0)?;
+        view[IxDyn(&unravel_index(flat, &shape))] = values[k % values.len()].clone();
+    }
+    Ok(())
+}
+
+/// Resolves the source positions addressed by `indices` along `axis`, checking that `indices`
+/// matches `shape` on every other axis.
+fn along_axis_positions<T, D>(
+    shape: &[usize],
+    indices: &ArrayBase<T, D>,
+    axis: isize,
+) -> Result<Vec<Vec<usize>>, NumrustError>
+where
+    T: Data<Elem = isize>,
+    D: Dimension,
+{
+    let axis = normalize_axis(axis, shape.len())?;
+    let mismatched = indices.ndim() != shape.len()
+        || (0..shape.len()).any(|d| d != axis && indices.shape()[d] != shape[d]);
+    if mismatched {
+        return Err(NumrustError::ValueError(format!(
+            "indices of shape {:?} do not match the array of shape {:?} outside axis {}",
+            indices.shape(),
+            shape,
+            axis
+        )));
+    }
+    indices
+        .view()
+        .into_dyn()
+        .indexed_iter()
+        .map(|(pattern, &i)| {
+            let mut index = pattern.slice().to_vec();
+            index[axis] = resolve_index(i, shape[axis], axis)?;
+            Ok(index)
+        })
+        .collect()
+}
+
+/// Takes values from `a` by matching one-dimensional index lanes along `axis`, as
+/// `numpy.take_along_axis` does. This pairs naturally with [`argsort`](crate::sorting::argsort).
+///
+/// `indices` must have the shape of `a` except along `axis`; the result has the shape of
+/// `indices`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::take_along_axis;
+/// use numrust::sorting::{argsort, SortKind};
+///
+/// let a = array![[10, 30, 20], [60, 40, 50]];
+/// let order = argsort(&a, 1, SortKind::Stable).unwrap().mapv(|i| i as isize);
+/// assert_eq!(take_along_axis(&a, &order, 1).unwrap(), array![[10, 20, 30], [40, 50, 60]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds, the shapes do not match, or an index is
+/// out of range.
+pub fn take_along_axis<A, S, T, D>(
+    a: &ArrayBase<S, D>,
+    indices: &ArrayBase<T, D>,
+    axis: isize,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    T: Data<Elem = isize>,
+    D: Dimension,
+{
+    let view = a.view().into_dyn();
+    let values: Vec<A> = along_axis_positions(a.shape(), indices, axis)?
+        .into_iter()
+        .map(|index| view[IxDyn(&index)].clone())
+        .collect();
+    Ok(Array::from_shape_vec(indices.raw_dim(), values).expect("one value per index"))
+}
+
+/// Assigns `values` into `a` at matching one-dimensional index lanes along `axis`, the
+/// counterpart of [`take_along_axis`].
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::put_along_axis;
+///
+/// let mut a = array![[10, 30, 20], [60, 40, 50]];
+/// put_along_axis(&mut a, &array![[1], [0]], &array![[0], [0]], 1).unwrap();
+/// assert_eq!(a, array![[10, 0, 20], [0, 40, 50]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if the shapes do not match or an index is out of range.
+pub fn put_along_axis<A, S, T, V, D>(
+    a: &mut ArrayBase<S, D>,
+    indices: &ArrayBase<T, D>,
+    values: &ArrayBase<V, D>,
+    axis: isize,
+) -> Result<(), NumrustError>
+where
+    A: Clone,
+    S: DataMut<Elem = A>,
+    T: Data<Elem = isize>,
+    V: Data<Elem = A>,
+    D: Dimension,
+{
+    if values.shape() != indices.shape() {
+        return Err(NumrustError::ValueError(
+            "values must have the shape of indices".to_string(),
+        ));
+    }
+    let positions = along_axis_positions(a.shape(), indices, axis)?;
+    let mut view = a.view_mut().into_dyn();
+    for (index, value) in positions.into_iter().zip(values.iter()) {
+        view[IxDyn(&index)] = value.clone();
+    }
+    Ok(())
+}
+
+/// Returns the indices of the elements of `a` that are not equal to `A::default()` (non-zero
+/// numbers and `true` booleans), one vector of positions per axis.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::nonzero;
+///
+/// let a = array![[3, 0, 0], [0, 4, 0], [5, 6, 0]];
+/// assert_eq!(nonzero(&a), vec![vec![0, 1, 2, 2], vec![0, 1, 0, 1]]);
+/// ```
+pub fn nonzero<A, S, D>(a: &ArrayBase<S, D>) -> Vec<Vec<usize>>
+where
+    A: Default + PartialEq,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let found = argwhere(a);
+    (0..a.ndim()).map(|d| found.column(d).to_vec()).collect()
+}
+
+/// Returns the multi-indices of the elements of `a` that are not equal to `A::default()`, one
+/// row per element.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::argwhere;
+///
+/// let a = array![[true, false], [false, true]];
+/// assert_eq!(argwhere(&a), array![[0, 0], [1, 1]]);
+/// ```
+pub fn argwhere<A, S, D>(a: &ArrayBase<S, D>) -> Array2<usize>
+where
+    A: Default + PartialEq,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let zero = A::default();
+    let mut flat = Vec::new();
+    let mut count = 0;
+    for (index, x) in a.view().into_dyn().indexed_iter() {
+        if *x != zero {
+            flat.extend_from_slice(index.as_array_view().as_slice().unwrap_or(&[]));
+            count += 1;
+        }
+    }
+    Array2::from_shape_vec((count, a.ndim()), flat).expect("one row per element")
+}
+
+/// Selects the slices of `a` along `axis` whose entry in `condition` is `true`.
+///
+/// `condition` may be shorter than the axis, in which case the remaining slices are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::compress;
+///
+/// let a = array![[1, 2], [3, 4], [5, 6]];
+/// assert_eq!(compress(&[false, true, true], &a, Some(0)).unwrap(), array![[3, 4], [5, 6]].into_dyn());
+/// assert_eq!(compress(&[false, true], &a, None).unwrap(), array![2].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds or `condition` is longer than the axis.
+pub fn compress<A, S, D>(
+    condition: &[bool],
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let selected: Vec<isize> = (0..condition.len())
+        .filter(|&i| condition[i])
+        .map(|i| i as isize)
+        .collect();
+    let len = match axis {
+        Some(ax) => a.len_of(Axis(normalize_axis(ax, a.ndim())?)),
+        None => a.len(),
+    };
+    if condition.len() > len {
+        return Err(NumrustError::IndexError(format!(
+            "condition of length {} is longer than the axis of length {}",
+            condition.len(),
+            len
+        )));
+    }
+    take(a, &selected, axis)
+}
+
+/// Returns the elements of `a` where `condition` is `true`, both read in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::Array2;
+/// use numrust::indexing::extract;
+///
+/// let a = Array2::from_shape_fn((3, 4), |(i, j)| 4 * i + j);
+/// let condition = a.mapv(|x| x % 3 == 0);
+/// assert_eq!(extract(&condition, &a).unwrap().to_vec(), vec![0, 3, 6, 9]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `condition` and `a` have different sizes.
+pub fn extract<A, S, T, D, E>(
+    condition: &ArrayBase<T, E>,
+    a: &ArrayBase<S, D>,
+) -> Result<Array1<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    T: Data<Elem = bool>,
+    D: Dimension,
+    E: Dimension,
+{
+    if condition.len() != a.len() {
+        return Err(NumrustError::ValueError(format!(
+            "condition of size {} does not match array of size {}",
+            condition.len(),
+            a.len()
+        )));
+    }
+    Ok(a.iter()
+        .zip(condition.iter())
+        .filter(|(_, &c)| c)
+        .map(|(x, _)| x.clone())
+        .collect())
+}
+
+#[cfg(test)]
+mod numrust_indexing_tests {
+    use super::*;
+    use ndarray::{array, Array3};
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_npslice_matches_numpy_semantics() {
+        let a = Array1::from_iter(0..10);
+        let items = |items: &[SliceItem]| {
+            slice(&a, items)
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(items(&npslice![1..-1;3]), vec![1, 4, 7]);
+        assert_eq!(items(&npslice![-2..;-1]), vec![8, 7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(
+            items(&npslice![..-12;-1]),
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]
+        );
+        assert_eq!(items(&npslice![20..;-4]), vec![9, 5, 1]);
+        assert_eq!(items(&npslice![5..20]), vec![5, 6, 7, 8, 9]);
+        assert_eq!(items(&npslice![7..3]), Vec::<i32>::new());
+        assert_eq!(items(&npslice![3..7;-1]), Vec::<i32>::new());
+        assert!(slice(&a, &npslice![..;0]).is_err());
+        assert!(matches!(
+            slice(&a, &npslice![10]),
+            Err(NumrustError::IndexError(_))
+        ));
+        assert!(slice(&a, &npslice![0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_slice_multidimensional() {
+        let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+        let v = slice(&a, &npslice![-1, NewAxis, ..;-1, 3]).unwrap();
+        assert_eq!(v, array![[123, 113, 103]].into_dyn());
+        assert_eq!(slice(&a, &npslice![]).unwrap().shape(), &[2, 3, 4]);
+    }
+
+    #[test]
+    fn test_gather_scatter_errors() {
+        let mut a = array![[1, 2], [3, 4]];
+        assert!(gather_nd(&a, &[&[0, 1]]).is_err());
+        assert!(gather_nd(&a, &[&[0, 1], &[0]]).is_err());
+        assert!(gather_nd(&a, &[&[2], &[0]]).is_err());
+        assert!(scatter_nd(&mut a, &[&[0, 1], &[0, 1]], &[1, 2, 3]).is_err());
+        scatter_nd(&mut a, &[&[0, 0], &[0, 0]], &[5, 6]).unwrap();
+        assert_eq!(a[[0, 0]], 6);
+    }
+
+    #[test]
+    fn test_masks() {
+        let mut a = array![[1, 2], [3, 4]];
+        assert!(mask_select(&a, &array![[true, false]]).is_err());
+        let mask = array![[false, true], [true, false]];
+        mask_assign(&mut a, &mask, &[20, 30]).unwrap();
+        assert_eq!(a, array![[1, 20], [30, 4]]);
+        assert!(mask_assign(&mut a, &mask, &[1, 2, 3]).is_err());
+        assert!(r#where(&mask, &a, &array![[0, 0, 0]]).is_err());
+    }
+
+    #[test]
+    fn test_take_put_errors() {
+        let mut a = array![[1, 2], [3, 4]];
+        assert!(matches!(
+            take(&a, &[4], None),
+            Err(NumrustError::IndexError(_))
+        ));
+        assert!(take(&a, &[0], Some(2)).is_err());
+        put(&mut a, &[0, 1, 2], &[7, 8]).unwrap();
+        assert_eq!(a, array![[7, 8], [7, 4]]);
+        assert!(put(&mut a, &[-5], &[0]).is_err());
+        assert!(put(&mut a, &[0], &[]).is_err());
+    }
+
+    #[test]
+    fn test_take_along_axis_shapes() {
+        let a = array![[10, 30, 20], [60, 40, 50]];
+        let max_index = array![[1], [0]];
+        assert_eq!(
+            take_along_axis(&a, &max_index, 1).unwrap(),
+            array![[30], [60]]
+        );
+        assert_eq!(
+            take_along_axis(&a, &array![[1, 0, 1]], 0).unwrap(),
+            array![[60, 30, 50]]
+        );
+        assert!(take_along_axis(&a, &array![[0], [0], [0]], 1).is_err());
+        assert!(take_along_axis(&a, &array![[3], [0]], 1).is_err());
+    }
+
+    #[test]
+    fn test_nonzero_and_compress() {
+        let a = Array3::from_shape_fn((2, 2, 2), |(i, j, k)| (i + j + k == 2) as u8);
+        assert_eq!(
+            nonzero(&a),
+            vec![vec![0, 1, 1], vec![1, 0, 1], vec![1, 1, 0]]
+        );
+        assert_eq!(argwhere(&array![0.0, -0.0, 1.5]), array![[2]]);
+        assert!(compress(&[true; 3], &array![1, 2], None).is_err());
+        assert!(extract(&array![true], &array![1, 2]).is_err());
+    }
+}
@@ -1,40 +1,132 @@
+pub mod calculus;
+pub mod complex;
+pub mod fft;
+pub mod histogram;
+pub mod indexing;
+pub mod linalg;
+pub mod logic;
+pub mod manipulation;
+pub mod memmap;
+pub mod npy;
+pub mod polynomial;
+pub mod printing;
 pub mod random;
+pub mod sorting;
+pub mod text;
 use std::error::Error;
 use std::fmt::{self, Debug};
 
+use ndarray::{Array, ArrayBase, ArrayD, Axis, Data, Dimension};
+use num_traits::{One, Zero};
+
+use crate::complex::{Field, Scalar};
+
+/// The dynamically dimensioned array type returned by routines that change an array's shape.
+pub type NdArray<T> = ArrayD<T>;
+
 #[derive(Debug)]
 pub enum NumrustError {
     CreationError(String),
+    ValueError(String),
+    AxisError(String),
+    IndexError(String),
+    IoError(String),
+    LinAlgError(String),
 }
 
 impl fmt::Display for NumrustError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             NumrustError::CreationError(msg) => write!(f, "CreationError: {}", msg),
+            NumrustError::ValueError(msg) => write!(f, "ValueError: {}", msg),
+            NumrustError::AxisError(msg) => write!(f, "AxisError: {}", msg),
+            NumrustError::IndexError(msg) => write!(f, "IndexError: {}", msg),
+            NumrustError::IoError(msg) => write!(f, "IoError: {}", msg),
+            NumrustError::LinAlgError(msg) => write!(f, "LinAlgError: {}", msg),
         }
     }
 }
 
 impl Error for NumrustError {}
 
+/// Converts a possibly negative `axis` into an index into an array with `ndim` dimensions,
+/// counting from the last axis when `axis` is negative, as NumPy does.
+pub(crate) fn normalize_axis(axis: isize, ndim: usize) -> Result<usize, NumrustError> {
+    let resolved = if axis < 0 { axis + ndim as isize } else { axis };
+    if resolved < 0 || resolved >= ndim as isize {
+        Err(NumrustError::AxisError(format!(
+            "axis {} is out of bounds for array of dimension {}",
+            axis, ndim
+        )))
+    } else {
+        Ok(resolved as usize)
+    }
+}
+
+/// Computes the shape that arrays of shapes `a` and `b` broadcast to, following NumPy's rules.
+pub(crate) fn broadcast_shapes(a: &[usize], b: &[usize]) -> Result<Vec<usize>, NumrustError> {
+    let ndim = a.len().max(b.len());
+    let dim =
+        |shape: &[usize], i: usize| (i + shape.len()).checked_sub(ndim).map_or(1, |j| shape[j]);
+    (0..ndim)
+        .map(|i| match (dim(a, i), dim(b, i)) {
+            (x, y) if x == y || y == 1 => Ok(x),
+            (1, y) => Ok(y),
+            _ => Err(NumrustError::ValueError(format!(
+                "operands could not be broadcast together with shapes {:?} {:?}",
+                a, b
+            ))),
+        })
+        .collect()
+}
+
+/// Applies `f` to every lane of `a` along `axis`, writing each result into the matching lane of
+/// `out`, which must have the shape of `a`.
+pub(crate) fn apply_along_axis<A, B, S, D, F>(
+    a: &ArrayBase<S, D>,
+    axis: isize,
+    mut out: Array<B, D>,
+    mut f: F,
+) -> Result<Array<B, D>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+    F: FnMut(&[A]) -> Result<Vec<B>, NumrustError>,
+{
+    let axis = Axis(normalize_axis(axis, a.ndim())?);
+    for (lane, mut out_lane) in a.lanes(axis).into_iter().zip(out.lanes_mut(axis)) {
+        let values = lane.to_vec();
+        for (o, v) in out_lane.iter_mut().zip(f(&values)?) {
+            *o = v;
+        }
+    }
+    Ok(out)
+}
+
 /// Represents a trait for computing statistical moments of an array.
 pub trait Moment {
+    /// The type of the mean and skewness: `f64` for real data and `Complex64` for complex data.
+    type Output;
+
     /// Computes the mean (average) of the array.
-    fn mean(&self) -> Option<f64>;
+    fn mean(&self) -> Option<Self::Output>;
 
-    /// Computes the variance of the array.
+    /// Computes the variance of the array, which is real even for complex data.
     fn var(&self) -> Option<f64>;
 
     /// Computes the standard deviation of the array.
     fn std(&self) -> Option<f64>;
 
     /// Computes the skewness of the array.
-    fn skew(&self) -> Option<f64>;
+    fn skew(&self) -> Option<Self::Output>;
 }
 
-impl<T: Into<f64> + Copy> Moment for [T] {
-    fn mean(&self) -> Option<f64> {
-        if self.len() == 0 {
+impl<T: Scalar> Moment for [T] {
+    type Output = T::Wide;
+
+    fn mean(&self) -> Option<T::Wide> {
+        if self.is_empty() {
             None
         } else {
             Some(mean(self))
@@ -42,7 +134,7 @@ impl<T: Into<f64> + Copy> Moment for [T] {
     }
 
     fn var(&self) -> Option<f64> {
-        if self.len() == 0 {
+        if self.is_empty() {
             None
         } else {
             Some(variance(self))
@@ -50,15 +142,15 @@ impl<T: Into<f64> + Copy> Moment for [T] {
     }
 
     fn std(&self) -> Option<f64> {
-        if self.len() == 0 {
+        if self.is_empty() {
             None
         } else {
             Some(std_dev(self))
         }
     }
 
-    fn skew(&self) -> Option<f64> {
-        if self.len() == 0 {
+    fn skew(&self) -> Option<T::Wide> {
+        if self.is_empty() {
             None
         } else {
             Some(skew(self))
@@ -98,7 +190,7 @@ impl<T: Into<f64> + Copy> Moment for [T] {
 /// # Panics
 ///
 /// This function assumes that the input `matrix` is a non-empty 2D matrix (with at least one row and one column).
-pub fn transpose<T: Default + Clone + Debug>(matrix: &Vec<Vec<T>>) -> Vec<Vec<T>> {
+pub fn transpose<T: Default + Clone + Debug>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
     let rows = matrix.len();
     let cols = matrix[0].len();
 
@@ -113,21 +205,57 @@ pub fn transpose<T: Default + Clone + Debug>(matrix: &Vec<Vec<T>>) -> Vec<Vec<T>
     result
 }
 
+/// Computes the conjugate transpose (Hermitian transpose) of a 2D matrix represented as a
+/// vector of vectors. For real elements this is the same as [`transpose`].
+///
+/// # Arguments
+///
+/// * `matrix` - A 2D matrix represented as a `Vec<Vec<T>>`.
+///
+/// # Returns
+///
+/// A new `Vec<Vec<T>>` whose `(j, i)` entry is the conjugate of the `(i, j)` entry of `matrix`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::complex::Complex64;
+/// use numrust::conj_transpose;
+///
+/// let m = vec![vec![Complex64::new(1.0, 1.0), Complex64::new(2.0, -3.0)]];
+/// assert_eq!(
+///     conj_transpose(&m),
+///     vec![vec![Complex64::new(1.0, -1.0)], vec![Complex64::new(2.0, 3.0)]]
+/// );
+/// ```
+///
+/// # Panics
+///
+/// This function assumes that the input `matrix` is a non-empty 2D matrix (with at least one row and one column).
+pub fn conj_transpose<T: Scalar + Default + Debug>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
+    transpose(matrix)
+        .into_iter()
+        .map(|row| row.into_iter().map(Scalar::conj).collect())
+        .collect()
+}
+
 /// Generates a linearly spaced vector of `num_points` elements between `start` and `end`, inclusive.
 ///
 /// # Arguments
 ///
-/// * `start` - The starting value of the linear space.
+/// * `start` - The starting value of the linear space, real or complex.
 /// * `end` - The ending value of the linear space.
 /// * `num_points` - The number of points to generate within the linear space.
 ///
 /// # Returns
 ///
-/// Returns a `Vec<f64>` representing the linearly spaced vector containing `num_points` elements.
+/// Returns a `Vec<f64>` for real endpoints, or a `Vec<Complex64>` for complex endpoints, holding
+/// the `num_points` points of the line segment between them.
 ///
 /// # Examples
 ///
 /// ```
+/// use numrust::complex::Complex64;
 /// use numrust::linspace;
 ///
 /// let result = linspace(0.0, 1.0, 5);
@@ -135,32 +263,37 @@ pub fn transpose<T: Default + Clone + Debug>(matrix: &Vec<Vec<T>>) -> Vec<Vec<T>
 ///
 /// let result = linspace(-1.0, 1.0, 3);
 /// assert_eq!(result, vec![-1.0, 0.0, 1.0]);
+///
+/// let result = linspace(Complex64::new(0.0, 0.0), Complex64::new(1.0, 2.0), 3);
+/// assert_eq!(result[1], Complex64::new(0.5, 1.0));
 /// ```
 ///
 /// # Panics
 ///
 /// This function assumes that `num_points` is a positive non-zero value.
-pub fn linspace(start: f64, end: f64, num_points: usize) -> Vec<f64> {
+pub fn linspace<T: Scalar>(start: T, end: T, num_points: usize) -> Vec<T::Wide> {
+    let (start, end) = (start.widen(), end.widen());
     let mut result = Vec::with_capacity(num_points);
-    let step = (end - start) / ((num_points - 1) as f64);
+    let step = (end - start).unscale((num_points - 1) as f64);
     for i in 0..num_points {
-        result.push(start + (step * i as f64));
+        result.push(start + step.scale(i as f64));
     }
 
     result
 }
 
-/// Computes the Pearson correlation coefficient between two arrays of floats.
+/// Computes the Pearson correlation coefficient between two arrays of real or complex values.
 ///
 /// # Arguments
 ///
-/// * `x`: A slice of floats representing the first variable.
-/// * `y`: A slice of floats representing the second variable.
+/// * `x`: A slice of values representing the first variable.
+/// * `y`: A slice of values representing the second variable.
 ///
 /// # Returns
 ///
-/// A 2x2 array of floats containing the correlation matrix, where the (0, 1) and (1, 0)
-/// entries are the correlation coefficients between `x` and `y`.
+/// A 2x2 array containing the correlation matrix, where the (0, 1) and (1, 0) entries are the
+/// correlation coefficients between `x` and `y`. For complex data the matrix is Hermitian, as
+/// with `numpy.corrcoef`.
 ///
 /// # Examples
 ///
@@ -177,30 +310,32 @@ pub fn linspace(start: f64, end: f64, num_points: usize) -> Vec<f64> {
 /// # Panics
 ///
 /// This function will panic if the input arrays `x` and `y` have different lengths.
-pub fn corrcoef(x: &[f64], y: &[f64]) -> [[f64; 2]; 2] {
+pub fn corrcoef<T: Scalar>(x: &[T], y: &[T]) -> [[T::Wide; 2]; 2] {
     if x.len() != y.len() {
         panic!("x and y must have the same length");
     }
     let cov = covariance(x, y)[0][1];
-    let x_std = std_dev(&x);
-    let y_std = std_dev(&y);
-    let corr = cov / (x_std * y_std);
-    [[1.0, corr], [corr, 1.0]]
+    let x_std = std_dev(x);
+    let y_std = std_dev(y);
+    let corr = cov.unscale(x_std * y_std);
+    let one = T::Wide::one();
+    [[one, corr], [corr.conj(), one]]
 }
 
-/// Calculates the covariance matrix for two vectors of float values `x` and `y`.
+/// Calculates the covariance matrix for two vectors of real or complex values `x` and `y`.
 ///
 /// # Arguments
 ///
-/// * `x` - A slice of float values representing the first vector.
-/// * `y` - A slice of float values representing the second vector.
+/// * `x` - A slice of values representing the first vector.
+/// * `y` - A slice of values representing the second vector.
 ///
 /// # Returns
 ///
-/// A 2x2 fixed-size array of float values representing the covariance matrix for `x` and `y`. The first
+/// A 2x2 fixed-size array representing the covariance matrix for `x` and `y`. The first
 /// row and column of the matrix correspond to `x`, while the second row and column correspond to
 /// `y`. The element at position `(i, j)` represents the covariance between the `i`th variable
-/// (either `x` or `y`) and the `j`th variable.
+/// (either `x` or `y`) and the `j`th variable. As in `numpy.cov`, complex deviations of the
+/// second variable are conjugated, so the matrix is Hermitian with a real diagonal.
 ///
 /// # Examples
 ///
@@ -226,42 +361,47 @@ pub fn corrcoef(x: &[f64], y: &[f64]) -> [[f64; 2]; 2] {
 ///
 /// Panics if `x` and `y` have different lengths.
 ///
-pub fn covariance(x: &[f64], y: &[f64]) -> [[f64; 2]; 2] {
+pub fn covariance<T: Scalar>(x: &[T], y: &[T]) -> [[T::Wide; 2]; 2] {
     if x.len() != y.len() {
         panic!("x and y must have the same length");
     }
     let n = x.len() as f64;
     let x_mean = mean(x);
     let y_mean = mean(y);
-    let mut cov = [[0.0; 2]; 2];
-    cov[0][0] = x.iter().map(|&a| (a - x_mean) * (a - x_mean)).sum::<f64>() / (n - 1.0);
-    cov[0][1] = x
-        .iter()
-        .zip(y.iter())
-        .map(|(&a, &b)| (a - x_mean) * (b - y_mean))
-        .sum::<f64>()
-        / (n - 1.0);
-    cov[1][0] = cov[0][1];
-    cov[1][1] = y.iter().map(|&b| (b - y_mean) * (b - y_mean)).sum::<f64>() / (n - 1.0);
+    let co_moment = |u: &[T], u_mean: T::Wide, v: &[T], v_mean: T::Wide| {
+        u.iter()
+            .zip(v)
+            .map(|(&a, &b)| (a.widen() - u_mean) * (b.widen() - v_mean).conj())
+            .sum::<T::Wide>()
+            .unscale(n - 1.0)
+    };
+    let mut cov = [[T::Wide::zero(); 2]; 2];
+    cov[0][0] = co_moment(x, x_mean, x, x_mean);
+    cov[0][1] = co_moment(x, x_mean, y, y_mean);
+    cov[1][0] = cov[0][1].conj();
+    cov[1][1] = co_moment(y, y_mean, y, y_mean);
     cov
 }
 /// Generates a sequence of evenly spaced values within a specified range.
 ///
 /// # Arguments
 ///
-/// * `start` - The starting value of the sequence.
+/// * `start` - The starting value of the sequence, real or complex.
 /// * `stop` - The end value of the sequence (exclusive).
 /// * `step` - The step size between each value in the sequence. A positive value generates
-///            increasing values, while a negative value generates decreasing values.
+///   increasing values, while a negative value generates decreasing values.
 ///
 /// # Returns
 ///
-/// A `Vec<f64>` containing the generated sequence of values.
+/// A `Vec<f64>` for real arguments, or a `Vec<Complex64>` for complex arguments, containing the
+/// values `start + k * step` for `k = 0, 1, ...`, up to the ceiling of `(stop - start) / step`
+/// (of its real part, for complex arguments).
 ///
 /// # Examples
 ///
 /// ```
 /// use numrust::arange;
+/// use numrust::complex::Complex64;
 ///
 /// let sequence = arange(0, 5, 1).unwrap();
 /// assert_eq!(sequence, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
@@ -271,65 +411,64 @@ pub fn covariance(x: &[f64], y: &[f64]) -> [[f64; 2]; 2] {
 ///
 /// let sequence = arange(5, 0, -1).unwrap();
 /// assert_eq!(sequence, vec![5.0, 4.0, 3.0, 2.0, 1.0]);
+///
+/// let start = Complex64::new(0.0, 1.0);
+/// let sequence = arange(start, Complex64::new(3.0, 1.0), Complex64::new(1.0, 0.0)).unwrap();
+/// assert_eq!(sequence[2], Complex64::new(2.0, 1.0));
 /// ```
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if `step` is equal to zero.
-pub fn arange(start: usize, stop: usize, step: isize) -> Result<Vec<f64>, NumrustError> {
-    if step == 0 {
-        Err(NumrustError::CreationError(
+/// Returns a `NumrustError::CreationError` if `step` is equal to zero.
+pub fn arange<T: Scalar>(start: T, stop: T, step: T) -> Result<Vec<T::Wide>, NumrustError> {
+    let (start, stop, step) = (start.widen(), stop.widen(), step.widen());
+    if step.is_zero() {
+        return Err(NumrustError::CreationError(
             "Step size cannot be 0".to_string(),
-        ))
-    } else {
-        let result: Vec<f64> = if step > 0 {
-            (start..stop)
-                .step_by(step as usize)
-                .map(|x| x as f64)
-                .collect()
-        } else {
-            ((stop as isize - step) as usize..=start)
-                .rev()
-                .step_by((-step) as usize)
-                .map(|x| x as f64)
-                .collect()
-        };
-        Ok(result)
+        ));
     }
+    let len = ((stop - start) / step).re().ceil();
+    let len = if len > 0.0 { len as usize } else { 0 };
+    Ok((0..len).map(|k| start + step.scale(k as f64)).collect())
 }
 
-/// Calculates the mean value of a slice of f64 values.
+/// Calculates the mean value of a slice of real or complex values.
 ///
 /// # Arguments
 ///
-/// * `nums` - A slice of f64 values
+/// * `nums` - A slice of values
 ///
 /// # Returns
 ///
-/// A f64 of the computed mean of `nums`
+/// The mean of `nums`, as an `f64` for real values and a `Complex64` for complex values
 ///
 /// # Example
 ///
 /// ```
+/// use numrust::complex::Complex64;
+///
 /// let nums = [1.0, 2.0, 3.0, 4.0, 5.0];
 /// let mean = numrust::mean(&nums);
 /// assert_eq!(mean, 3.0);
+///
+/// let z = [Complex64::new(1.0, 2.0), Complex64::new(3.0, -4.0)];
+/// assert_eq!(numrust::mean(&z), Complex64::new(2.0, -1.0));
 /// ```
 ///
 /// # Panics
 ///
 /// The `mean` function does not panic.
-pub fn mean<T: Into<f64> + Copy>(nums: &[T]) -> f64 {
-    let sum: f64 = nums.iter().map(|&x| x.into()).sum();
-    let mean = sum / (nums.len() as f64);
-    mean
+pub fn mean<T: Scalar>(nums: &[T]) -> T::Wide {
+    let sum: T::Wide = nums.iter().map(|&x| x.widen()).sum();
+    sum.unscale(nums.len() as f64)
 }
 
-/// Calculates the standard deviation of a slice of f64 values.
+/// Calculates the standard deviation of a slice of real or complex values, the square root of
+/// [`variance`].
 ///
 /// # Arguments
 ///
-/// * `nums` - A slice of f64 values
+/// * `nums` - A slice of values
 ///
 /// # Returns
 ///
@@ -346,20 +485,23 @@ pub fn mean<T: Into<f64> + Copy>(nums: &[T]) -> f64 {
 /// # Panics
 ///
 /// The `std_dev` function does not panic.
-pub fn std_dev<T: Into<f64> + Copy>(nums: &[T]) -> f64 {
+pub fn std_dev<T: Scalar>(nums: &[T]) -> f64 {
     let var = variance(nums);
-    if nums.len() == 0 {
+    if nums.is_empty() {
         f64::NAN
     } else {
         var.sqrt()
     }
 }
 
-/// Calculates the sample variance of a slice of f64 values.
+/// Calculates the sample variance of a slice of real or complex values.
+///
+/// As in `numpy.var`, the variance of complex data is the real-valued mean squared modulus of
+/// the deviations, `sum(|z - mean|^2) / (n - 1)`.
 ///
 /// # Arguments
 ///
-/// * `nums` - A slice of f64 values
+/// * `nums` - A slice of values
 ///
 /// # Returns
 ///
@@ -368,31 +510,41 @@ pub fn std_dev<T: Into<f64> + Copy>(nums: &[T]) -> f64 {
 /// # Example
 ///
 /// ```
+/// use numrust::complex::Complex64;
+///
 /// let nums = [1.0, 2.0, 3.0, 4.0, 5.0];
 /// let var = numrust::variance(&nums);
 /// assert_eq!(var, 2.5);
+///
+/// let z = [Complex64::new(1.0, 1.0), Complex64::new(-1.0, -1.0)];
+/// assert_eq!(numrust::variance(&z), 4.0);
 /// ```
 ///
 /// # Panics
 ///
 /// The `variance` function does not panic.
-pub fn variance<T: Into<f64> + Copy>(nums: &[T]) -> f64 {
+pub fn variance<T: Scalar>(nums: &[T]) -> f64 {
     let mean = mean(nums);
-    if nums.len() == 0 {
+    if nums.is_empty() {
         f64::NAN
     } else {
-        nums.iter().map(|&x| (x.into() - mean).powi(2)).sum::<f64>() / ((nums.len() - 1) as f64)
+        nums.iter()
+            .map(|&x| (x.widen() - mean).norm_sqr())
+            .sum::<f64>()
+            / ((nums.len() - 1) as f64)
     }
 }
 /// Calculates the skewness of a slice of numeric values.
 ///
 /// # Arguments
 ///
-/// * `nums` - A reference to a slice of values of any type that can be converted into `f64`.
+/// * `nums` - A reference to a slice of real or complex values.
 ///
 /// # Returns
 ///
-/// The calculated skewness value as an `f64`.
+/// The calculated skewness value as an `f64`, or a `Complex64` for complex values. Complex
+/// deviations are not conjugated, so the result is `m3 / m2^(3/2)` for the complex central
+/// moments `m2` and `m3`, with the principal square root.
 ///
 /// # Examples
 ///
@@ -416,17 +568,20 @@ pub fn variance<T: Into<f64> + Copy>(nums: &[T]) -> f64 {
 /// # Panics
 ///
 /// This function will panic if `nums` is an empty slice.
-pub fn skew<T: Into<f64> + Copy>(nums: &[T]) -> f64 {
+pub fn skew<T: Scalar>(nums: &[T]) -> T::Wide {
     let mean = mean(nums);
-    let variance = nums.iter().map(|&x| (x.into() - mean).powi(2)).sum::<f64>() / nums.len() as f64;
-    let std_dev = variance.sqrt();
-    let skewness = nums
+    let n = nums.len() as f64;
+    let variance = nums
         .iter()
-        .map(|&x| (x.into() - mean) / std_dev)
-        .map(|x| x.powi(3))
-        .sum::<f64>()
-        / nums.len() as f64;
-    skewness
+        .map(|&x| (x.widen() - mean) * (x.widen() - mean))
+        .sum::<T::Wide>()
+        .unscale(n);
+    let std_dev = variance.sqrt();
+    nums.iter()
+        .map(|&x| (x.widen() - mean) / std_dev)
+        .map(|x| x * x * x)
+        .sum::<T::Wide>()
+        .unscale(n)
 }
 
 #[cfg(test)]
@@ -436,6 +591,7 @@ mod numrust_tests {
     use approx::assert_abs_diff_eq;
 
     use super::*;
+    use crate::complex::Complex64;
     #[test]
     fn test_mean_empty() {
         let nums: [f64; 0] = [];
@@ -590,7 +746,7 @@ mod numrust_tests {
 
     #[test]
     fn test_corrcoef_with_zero_variance() {
-        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let x: [f64; 5] = [1.0, 2.0, 3.0, 4.0, 5.0];
         let y = [1.0, 1.0, 1.0, 1.0, 1.0];
         assert!(corrcoef(&x, &y)[0][1].is_nan());
         assert!(corrcoef(&x, &y)[1][0].is_nan());
@@ -627,4 +783,113 @@ mod numrust_tests {
             vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]]
         );
     }
+
+    #[test]
+    fn test_complex_moments() {
+        let z = [
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(0.0, -1.0),
+        ];
+        assert_eq!(mean(&z), Complex64::new(0.0, 0.0));
+        assert_abs_diff_eq!(variance(&z), 4.0 / 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(std_dev(&z), (4.0f64 / 3.0).sqrt(), epsilon = 1e-12);
+        assert_eq!(z.var(), Some(variance(&z)));
+        assert_eq!(z.mean(), Some(Complex64::new(0.0, 0.0)));
+
+        // m3 / m2^(3/2) of the central moments, without conjugation.
+        let z = [
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(0.0, -1.0),
+            Complex64::new(2.0, 0.0),
+        ];
+        let s = skew(&z);
+        assert_abs_diff_eq!(s.re, 1.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(s.im, 0.0, epsilon = 1e-12);
+
+        // Complex data with no imaginary part agrees with the real statistics.
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let zx = x.map(|v| Complex64::new(v, 0.0));
+        assert_eq!(variance(&zx), variance(&x));
+        assert_eq!(mean(&zx), Complex64::new(mean(&x), 0.0));
+
+        let empty: [Complex64; 0] = [];
+        assert!(variance(&empty).is_nan());
+        assert!(mean(&empty).re.is_nan());
+        assert_eq!(empty.mean(), None);
+    }
+
+    #[test]
+    fn test_complex_covariance_and_corrcoef() {
+        let x = [
+            Complex64::new(1.0, 1.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(3.0, -1.0),
+        ];
+        let y = [
+            Complex64::new(0.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(2.0, 2.0),
+        ];
+        let cov = covariance(&x, &y);
+        assert_abs_diff_eq!(cov[0][0].re, 2.0, epsilon = 1e-12);
+        assert_eq!(cov[0][0].im, 0.0);
+        assert_abs_diff_eq!(cov[0][1].re, 0.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(cov[0][1].im, -1.5, epsilon = 1e-12);
+        assert_eq!(cov[1][0], cov[0][1].conj());
+        assert_abs_diff_eq!(cov[1][1].re, 2.0, epsilon = 1e-12);
+
+        let corr = corrcoef(&x, &y);
+        assert_abs_diff_eq!(corr[0][1].re, 0.25, epsilon = 1e-12);
+        assert_abs_diff_eq!(corr[0][1].im, -0.75, epsilon = 1e-12);
+        assert_eq!(corr[1][0], corr[0][1].conj());
+        assert_eq!(corr[0][0], Complex64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_complex_linspace_and_arange() {
+        let a = linspace(Complex64::new(0.0, 1.0), Complex64::new(2.0, -1.0), 3);
+        assert_eq!(
+            a,
+            vec![
+                Complex64::new(0.0, 1.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0, -1.0)
+            ]
+        );
+
+        let step = Complex64::new(0.5, 0.5);
+        let a = arange(Complex64::new(0.0, 0.0), Complex64::new(2.0, 2.0), step).unwrap();
+        assert_eq!(a.len(), 4);
+        assert_eq!(a[3], Complex64::new(1.5, 1.5));
+        assert!(arange(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0)
+        )
+        .is_err());
+
+        assert_eq!(arange(0.5, 2.0, 0.5).unwrap(), vec![0.5, 1.0, 1.5]);
+        assert!(arange(0, 5, -1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_conj_transpose() {
+        let m = vec![
+            vec![Complex64::new(1.0, 2.0), Complex64::new(3.0, 0.0)],
+            vec![Complex64::new(0.0, -1.0), Complex64::new(4.0, 5.0)],
+        ];
+        assert_eq!(
+            conj_transpose(&m),
+            vec![
+                vec![Complex64::new(1.0, -2.0), Complex64::new(0.0, 1.0)],
+                vec![Complex64::new(3.0, 0.0), Complex64::new(4.0, -5.0)],
+            ]
+        );
+        let real = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        assert_eq!(conj_transpose(&real), transpose(&real));
+    }
 }
@@ -0,0 +1,1608 @@
+use std::ops::Range;
+
+use ndarray::{Array1, Array2, ArrayBase, ArrayD, Axis, Data, Dimension, Ix1, Ix2, IxDyn};
+
+use crate::complex::{Complex64, Field, Scalar};
+use crate::{normalize_axis, NumrustError};
+
+/// The factors `(u, s, vt)` of a singular value decomposition.
+type Svd<F> = (Array2<F>, Array1<f64>, Array2<F>);
+
+/// The order of a vector or matrix norm, mirroring the `ord` argument of `numpy.linalg.norm`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormOrd {
+    /// `sum(abs(x)**p)**(1/p)` for vectors. For matrices only `1`, `-1`, `2` and `-2` are valid:
+    /// the maximum/minimum absolute column sum and the largest/smallest singular value.
+    P(f64),
+    /// `max(abs(x))` for vectors, the maximum absolute row sum for matrices.
+    Inf,
+    /// `min(abs(x))` for vectors, the minimum absolute row sum for matrices.
+    NegInf,
+    /// The Frobenius norm. Only valid for matrices.
+    Fro,
+    /// The nuclear norm (sum of singular values). Only valid for matrices.
+    Nuc,
+}
+
+/// Computes a vector or matrix norm of `x`, following the semantics of `numpy.linalg.norm`.
+///
+/// # Arguments
+///
+/// * `x` - The input array.
+/// * `ord` - The order of the norm. `None` is the 2-norm of the flattened array when `axis` is
+///   `None`, the 2-norm for vectors and the Frobenius norm for matrices otherwise.
+/// * `axis` - `None` treats `x` as a vector (1-D) or matrix (2-D). A single axis computes vector
+///   norms along it, two axes compute matrix norms over the 2-D slices they span. Negative
+///   axes count from the end.
+/// * `keepdims` - If `true`, the normed axes are kept in the result with length one.
+///
+/// # Returns
+///
+/// An array holding the norms. Reducing every axis produces a zero-dimensional array.
+///
+/// The 2-norm is accumulated with a running scale factor, so vectors holding values such as
+/// `1e200` or `1e-200` do not overflow or underflow. Matrices are rescaled by their largest
+/// absolute entry before their singular values are computed for the same reason.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::linalg::{norm, NormOrd};
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[1.0, 2.0], [3.0, 4.0]];
+///
+/// let fro = norm(&a, None, None, false).unwrap();
+/// assert_abs_diff_eq!(fro[[]], 30f64.sqrt(), epsilon = 1e-12);
+///
+/// let inf = norm(&a, Some(NormOrd::Inf), None, false).unwrap();
+/// assert_eq!(inf[[]], 7.0);
+///
+/// let columns = norm(&a, Some(NormOrd::P(1.0)), Some(&[0]), false).unwrap();
+/// assert_eq!(columns.into_raw_vec(), vec![4.0, 6.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if an axis is out of bounds or repeated, if `ord` is not valid for
+/// the number of axes being normed, or if a singular value decomposition fails.
+pub fn norm<A, S, D>(
+    x: &ArrayBase<S, D>,
+    ord: Option<NormOrd>,
+    axis: Option<&[isize]>,
+    keepdims: bool,
+) -> Result<ArrayD<f64>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let a: ArrayD<A> = x.to_owned().into_dyn();
+    let ndim = a.ndim();
+
+    let axes: Vec<usize> = match axis {
+        Some(axes) => axes
+            .iter()
+            .map(|&ax| normalize_axis(ax, ndim))
+            .collect::<Result<_, _>>()?,
+        None => {
+            if ord.is_none() {
+                let flat: Vec<f64> = a.iter().map(|&v| v.abs()).collect();
+                let result = ArrayD::from_elem(IxDyn(&[]), nrm2(&flat));
+                return Ok(if keepdims {
+                    result.into_shape(vec![1; ndim]).unwrap()
+                } else {
+                    result
+                });
+            }
+            (0..ndim).collect()
+        }
+    };
+
+    match axes.len() {
+        1 => {
+            let ax = axes[0];
+            let ord = ord.unwrap_or(NormOrd::P(2.0));
+            let mut result = ArrayD::zeros(a.view().remove_axis(Axis(ax)).raw_dim());
+            for (out, lane) in result.iter_mut().zip(a.lanes(Axis(ax))) {
+                let values: Vec<A> = lane.iter().copied().collect();
+                *out = vector_norm(&values, ord)?;
+            }
+            if keepdims {
+                result = result.insert_axis(Axis(ax));
+            }
+            Ok(result)
+        }
+        2 => {
+            let (row, col) = (axes[0], axes[1]);
+            if row == col {
+                return Err(NumrustError::ValueError(
+                    "Duplicate axes given.".to_string(),
+                ));
+            }
+            let ord = ord.unwrap_or(NormOrd::Fro);
+
+            // Move the two normed axes to the end and walk over every 2-D slice.
+            let mut order: Vec<usize> = (0..ndim).filter(|&i| i != row && i != col).collect();
+            let outer_shape: Vec<usize> = order.iter().map(|&i| a.shape()[i]).collect();
+            order.push(row);
+            order.push(col);
+            let (rows, cols) = (a.shape()[row], a.shape()[col]);
+            let batch: usize = outer_shape.iter().product();
+            let permuted = a.permuted_axes(order);
+            let stacked = permuted
+                .as_standard_layout()
+                .into_owned()
+                .into_shape((batch, rows, cols))
+                .unwrap();
+
+            let mut norms = Vec::with_capacity(batch);
+            for matrix in stacked.outer_iter() {
+                norms.push(matrix_norm(&matrix, ord)?);
+            }
+            let mut result = ArrayD::from_shape_vec(outer_shape, norms).unwrap();
+            if keepdims {
+                let (first, second) = if row < col { (row, col) } else { (col, row) };
+                result = result.insert_axis(Axis(first)).insert_axis(Axis(second));
+            }
+            Ok(result)
+        }
+        _ => Err(NumrustError::ValueError(
+            "Improper number of dimensions to norm.".to_string(),
+        )),
+    }
+}
+
+/// Computes the norm of order `ord` of a vector.
+///
+/// # Arguments
+///
+/// * `x` - A slice of values representing the vector.
+/// * `ord` - The order of the norm. `NormOrd::P(0.0)` counts the non-zero entries.
+///
+/// # Returns
+///
+/// The norm of `x` as an `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::linalg::{vector_norm, NormOrd};
+///
+/// let x = [3.0, -4.0];
+/// assert_eq!(vector_norm(&x, NormOrd::P(2.0)).unwrap(), 5.0);
+/// assert_eq!(vector_norm(&x, NormOrd::P(1.0)).unwrap(), 7.0);
+/// assert_eq!(vector_norm(&x, NormOrd::Inf).unwrap(), 4.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `ord` is a matrix-only norm (`Fro` or `Nuc`), or if
+/// `ord` is `Inf`/`NegInf` and `x` is empty.
+pub fn vector_norm<T: Scalar>(x: &[T], ord: NormOrd) -> Result<f64, NumrustError> {
+    let abs: Vec<f64> = x.iter().map(|&v| v.abs()).collect();
+    match ord {
+        NormOrd::Inf | NormOrd::NegInf => {
+            if abs.is_empty() {
+                return Err(NumrustError::ValueError(
+                    "zero-size array to reduction operation which has no identity".to_string(),
+                ));
+            }
+            if abs.iter().any(|v| v.is_nan()) {
+                return Ok(f64::NAN);
+            }
+            Ok(if ord == NormOrd::Inf {
+                abs.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+            } else {
+                abs.iter().copied().fold(f64::INFINITY, f64::min)
+            })
+        }
+        NormOrd::P(0.0) => Ok(abs.iter().filter(|&&v| v != 0.0).count() as f64),
+        NormOrd::P(1.0) => Ok(abs.iter().sum()),
+        NormOrd::P(2.0) => Ok(nrm2(&abs)),
+        NormOrd::P(p) => Ok(scaled_pnorm(&abs, p)),
+        NormOrd::Fro | NormOrd::Nuc => Err(NumrustError::ValueError(format!(
+            "Invalid norm order '{:?}' for vectors",
+            ord
+        ))),
+    }
+}
+
+/// Computes the norm of order `ord` of a single matrix.
+fn matrix_norm<A: Scalar, S: Data<Elem = A>>(
+    m: &ArrayBase<S, Ix2>,
+    ord: NormOrd,
+) -> Result<f64, NumrustError> {
+    let reduce = |sums: Vec<f64>, largest: bool| -> Result<f64, NumrustError> {
+        if sums.is_empty() {
+            return Err(NumrustError::ValueError(
+                "zero-size array to reduction operation which has no identity".to_string(),
+            ));
+        }
+        Ok(if largest {
+            sums.into_iter().fold(f64::NEG_INFINITY, f64::max)
+        } else {
+            sums.into_iter().fold(f64::INFINITY, f64::min)
+        })
+    };
+    let column_sums = || -> Vec<f64> {
+        m.axis_iter(Axis(1))
+            .map(|c| c.iter().map(|v| v.abs()).sum())
+            .collect()
+    };
+    let row_sums = || -> Vec<f64> {
+        m.axis_iter(Axis(0))
+            .map(|r| r.iter().map(|v| v.abs()).sum())
+            .collect()
+    };
+
+    match ord {
+        NormOrd::Fro => Ok(nrm2(&m.iter().map(|v| v.abs()).collect::<Vec<_>>())),
+        NormOrd::Inf => reduce(row_sums(), true),
+        NormOrd::NegInf => reduce(row_sums(), false),
+        NormOrd::P(1.0) => reduce(column_sums(), true),
+        NormOrd::P(-1.0) => reduce(column_sums(), false),
+        NormOrd::P(p) if p == 2.0 || p == -2.0 => {
+            let s = scaled_singular_values(m)?;
+            reduce(s.to_vec(), p > 0.0)
+        }
+        NormOrd::Nuc => Ok(scaled_singular_values(m)?.sum()),
+        NormOrd::P(p) => Err(NumrustError::ValueError(format!(
+            "Invalid norm order '{}' for matrices",
+            p
+        ))),
+    }
+}
+
+/// Computes the singular values of `m` after dividing it by its largest absolute entry, then
+/// scales them back so intermediate sums of squares stay in range.
+fn scaled_singular_values<A: Scalar, S: Data<Elem = A>>(
+    m: &ArrayBase<S, Ix2>,
+) -> Result<Array1<f64>, NumrustError> {
+    let scale = m.iter().fold(0.0f64, |acc, v| acc.max(v.abs()));
+    if scale == 0.0 || !scale.is_finite() {
+        return svdvals(m);
+    }
+    Ok(svdvals(&m.mapv(|v| v.to_complex() / scale))? * scale)
+}
+
+/// The Euclidean norm of `values`, accumulated as `scale * sqrt(ssq)` like LAPACK's `dnrm2` so
+/// that neither very large nor very small entries lose precision.
+fn nrm2(values: &[f64]) -> f64 {
+    let mut scale = 0.0f64;
+    let mut ssq = 1.0f64;
+    let mut infinite = false;
+    for &v in values {
+        if v.is_nan() {
+            return f64::NAN;
+        }
+        if v.is_infinite() {
+            infinite = true;
+            continue;
+        }
+        if v != 0.0 {
+            let a = v.abs();
+            if scale < a {
+                ssq = 1.0 + ssq * (scale / a).powi(2);
+                scale = a;
+            } else {
+                ssq += (a / scale).powi(2);
+            }
+        }
+    }
+    if infinite {
+        f64::INFINITY
+    } else {
+        scale * ssq.sqrt()
+    }
+}
+
+/// `sum(values**p)**(1/p)` for non-negative `values`, computed relative to the largest value.
+fn scaled_pnorm(values: &[f64], p: f64) -> f64 {
+    if values.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    let largest = values.iter().copied().fold(0.0f64, f64::max);
+    if p < 0.0 || largest == 0.0 || largest.is_infinite() {
+        return values.iter().map(|v| v.powf(p)).sum::<f64>().powf(1.0 / p);
+    }
+    largest
+        * values
+            .iter()
+            .map(|v| (v / largest).powf(p))
+            .sum::<f64>()
+            .powf(1.0 / p)
+}
+
+/// Computes the thin singular value decomposition `a = u * diag(s) * vt` of a real or complex
+/// matrix.
+///
+/// # Arguments
+///
+/// * `a` - An `m x n` matrix.
+///
+/// # Returns
+///
+/// A tuple `(u, s, vt)` where `u` is `m x k`, `s` holds the `k = min(m, n)` singular values in
+/// descending order, and `vt` is `k x n`. The columns of `u` and rows of `vt` are orthonormal.
+/// For complex input `u` and `vt` are complex and `vt` is the conjugate transpose of `v`, as
+/// with `numpy.linalg.svd`.
+///
+/// The decomposition uses one-sided Jacobi rotations, which computes small singular values to
+/// high relative accuracy.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{array, Array2};
+/// use numrust::linalg::svd;
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[3.0, 0.0], [0.0, -2.0], [0.0, 0.0]];
+/// let (u, s, vt) = svd(&a).unwrap();
+/// assert_abs_diff_eq!(s[0], 3.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(s[1], 2.0, epsilon = 1e-12);
+///
+/// let rebuilt = u.dot(&Array2::from_diag(&s)).dot(&vt);
+/// for (x, y) in rebuilt.iter().zip(a.iter()) {
+///     assert_abs_diff_eq!(x, y, epsilon = 1e-12);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::LinAlgError` if `a` contains non-finite values or the iteration does
+/// not converge.
+pub fn svd<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Svd<A::Wide>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    if a.iter().any(|v| !v.is_finite()) {
+        return Err(NumrustError::LinAlgError(
+            "SVD did not converge: array must not contain infs or NaNs".to_string(),
+        ));
+    }
+    let a: Array2<A::Wide> = a.mapv(Scalar::widen);
+    if a.nrows() < a.ncols() {
+        let (u, s, vt) = jacobi_svd(conj_t(&a))?;
+        Ok((conj_t(&vt), s, conj_t(&u)))
+    } else {
+        jacobi_svd(a)
+    }
+}
+
+/// Computes the singular values of a real or complex matrix.
+///
+/// # Arguments
+///
+/// * `a` - An `m x n` matrix.
+///
+/// # Returns
+///
+/// The `min(m, n)` singular values of `a` in descending order.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::Complex64;
+/// use numrust::linalg::svdvals;
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[Complex64::new(0.0, 2.0), Complex64::new(0.0, 0.0)],
+///                [Complex64::new(0.0, 0.0), Complex64::new(1.0, 1.0)]];
+/// let s = svdvals(&a).unwrap();
+/// assert_abs_diff_eq!(s[0], 2.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(s[1], 2f64.sqrt(), epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::LinAlgError` if `a` contains non-finite values or the iteration does
+/// not converge.
+pub fn svdvals<A: Scalar, S: Data<Elem = A>>(
+    a: &ArrayBase<S, Ix2>,
+) -> Result<Array1<f64>, NumrustError> {
+    let (_, s, _) = svd(a)?;
+    Ok(s)
+}
+
+/// The conjugate transpose of `m`.
+fn conj_t<F: Field>(m: &Array2<F>) -> Array2<F> {
+    m.t().mapv(Scalar::conj)
+}
+
+/// The inner product `sum(conj(x) * y)` of two columns or rows.
+fn dotc<'a, F: Field>(x: impl IntoIterator<Item = &'a F>, y: impl IntoIterator<Item = &'a F>) -> F {
+    x.into_iter().zip(y).map(|(&a, &b)| a.conj() * b).sum()
+}
+
+/// The Euclidean norm of a real or complex vector, computed with [`nrm2`] over its parts.
+fn vector_nrm2<'a, F: Field>(x: impl IntoIterator<Item = &'a F>) -> f64 {
+    let parts: Vec<f64> = x.into_iter().flat_map(|v| [v.re(), v.im()]).collect();
+    nrm2(&parts)
+}
+
+/// One-sided Jacobi SVD of a matrix with at least as many rows as columns.
+fn jacobi_svd<F: Field>(mut u: Array2<F>) -> Result<Svd<F>, NumrustError> {
+    const MAX_SWEEPS: usize = 100;
+    let (m, n) = u.dim();
+    let mut v = Array2::<F>::eye(n);
+
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let alpha = dotc(u.column(p), u.column(p)).re();
+                let beta = dotc(u.column(q), u.column(q)).re();
+                let gamma = dotc(u.column(p), u.column(q));
+                let g = gamma.abs();
+                if g == 0.0 || g <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                // Rotating against the unit phase of gamma makes the 2x2 problem real.
+                let phase = gamma.unscale(g);
+                let zeta = (beta - alpha) / (2.0 * g);
+                let t = zeta.signum() / (zeta.abs() + 1f64.hypot(zeta));
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = phase.scale(c * t);
+                rotate_columns(&mut u, p, q, c, s);
+                rotate_columns(&mut v, p, q, c, s);
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(NumrustError::LinAlgError(
+            "SVD did not converge".to_string(),
+        ));
+    }
+
+    let mut singular: Vec<(f64, usize)> = (0..n).map(|j| (vector_nrm2(u.column(j)), j)).collect();
+    singular.sort_by(|x, y| y.0.total_cmp(&x.0));
+
+    let mut u_sorted = Array2::<F>::zeros((m, n));
+    let mut vt = Array2::<F>::zeros((n, n));
+    let mut s = Array1::<f64>::zeros(n);
+    for (k, &(sigma, j)) in singular.iter().enumerate() {
+        s[k] = sigma;
+        if sigma > 0.0 {
+            u_sorted
+                .column_mut(k)
+                .assign(&u.column(j).mapv(|x| x.unscale(sigma)));
+        }
+        vt.row_mut(k).assign(&v.column(j).mapv(Scalar::conj));
+    }
+    complete_orthonormal_columns(&mut u_sorted, &s);
+    Ok((u_sorted, s, vt))
+}
+
+/// Applies the plane rotation `(c, s)` to columns `p` and `q` of `m`. For complex `s` the
+/// rotation is the unitary `[[c, s], [-conj(s), c]]`.
+fn rotate_columns<F: Field>(m: &mut Array2<F>, p: usize, q: usize, c: f64, s: F) {
+    for mut row in m.rows_mut() {
+        let (x, y) = (row[p], row[q]);
+        row[p] = x.scale(c) - s.conj() * y;
+        row[q] = s * x + y.scale(c);
+    }
+}
+
+/// Replaces the columns of `u` belonging to zero singular values with unit vectors orthogonal to
+/// every other column.
+fn complete_orthonormal_columns<F: Field>(u: &mut Array2<F>, s: &Array1<f64>) {
+    let m = u.nrows();
+    for k in 0..s.len() {
+        if s[k] > 0.0 {
+            continue;
+        }
+        for e in 0..m {
+            let mut candidate = Array1::<F>::zeros(m);
+            candidate[e] = F::one();
+            for j in 0..u.ncols() {
+                if j == k {
+                    continue;
+                }
+                let proj = dotc(u.column(j), &candidate);
+                for (c, &x) in candidate.iter_mut().zip(u.column(j)) {
+                    *c = *c - proj * x;
+                }
+            }
+            let len = vector_nrm2(&candidate);
+            if len > 0.5 {
+                u.column_mut(k).assign(&candidate.mapv(|x| x.unscale(len)));
+                break;
+            }
+        }
+    }
+}
+
+/// Computes the least-squares solution `x` minimising `||a x - b||` for real or complex `a` and
+/// `b`.
+///
+/// # Arguments
+///
+/// * `a` - An `m x n` coefficient matrix.
+/// * `b` - The `m` right-hand side values.
+/// * `rcond` - Singular values smaller than `rcond` times the largest singular value are treated
+///   as zero. `None` uses `max(m, n) * f64::EPSILON`, as NumPy does.
+///
+/// # Returns
+///
+/// The minimum-norm solution `x` of length `n`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::linalg::lstsq;
+/// use approx::assert_abs_diff_eq;
+///
+/// // Fit y = c0 + c1 * x through three points on the line y = 1 + 2x.
+/// let a = array![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+/// let b = array![1.0, 3.0, 5.0];
+/// let x = lstsq(&a, &b, None).unwrap();
+/// assert_abs_diff_eq!(x[0], 1.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(x[1], 2.0, epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `a` and `b` have incompatible shapes, or a
+/// `NumrustError::LinAlgError` if the singular value decomposition fails.
+pub fn lstsq<A, S, T>(
+    a: &ArrayBase<S, Ix2>,
+    b: &ArrayBase<T, Ix1>,
+    rcond: Option<f64>,
+) -> Result<Array1<A::Wide>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    if b.len() != m {
+        return Err(NumrustError::ValueError(format!(
+            "Incompatible dimensions: a has {} rows but b has {} values",
+            m,
+            b.len()
+        )));
+    }
+    let (u, s, vt) = svd(a)?;
+    let b = b.mapv(Scalar::widen);
+    let cutoff =
+        rcond.unwrap_or(m.max(n) as f64 * f64::EPSILON) * s.first().copied().unwrap_or(0.0);
+    let mut x = Array1::<A::Wide>::zeros(n);
+    for (k, &sigma) in s.iter().enumerate() {
+        if sigma > cutoff {
+            let coef = dotc(u.column(k), &b).unscale(sigma);
+            for (xi, &v) in x.iter_mut().zip(vt.row(k)) {
+                *xi = *xi + coef * v.conj();
+            }
+        }
+    }
+    Ok(x)
+}
+
+/// Computes the eigenvalues of a general real or complex square matrix.
+///
+/// The matrix is balanced and reduced to upper Hessenberg form in its
+/// [`Wide`](crate::complex::Scalar::Wide) type. Real matrices are then iterated with the
+/// Francis double-shift QR algorithm, so complex conjugate eigenvalue pairs are found without
+/// complex arithmetic; complex matrices use single-shift QR steps with Wilkinson shifts.
+///
+/// # Arguments
+///
+/// * `a` - An `n x n` matrix.
+///
+/// # Returns
+///
+/// The `n` eigenvalues of `a`, in no particular order.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::Complex64;
+/// use numrust::linalg::eigvals;
+/// use approx::assert_abs_diff_eq;
+///
+/// // A rotation by 90 degrees has eigenvalues +i and -i.
+/// let a = array![[0.0, -1.0], [1.0, 0.0]];
+/// let mut w = eigvals(&a).unwrap().to_vec();
+/// w.sort_by(|x, y| x.im.total_cmp(&y.im));
+/// assert_abs_diff_eq!(w[0].im, -1.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(w[1].im, 1.0, epsilon = 1e-12);
+///
+/// // A complex triangular matrix has its diagonal as eigenvalues.
+/// let a = array![
+///     [Complex64::new(1.0, 2.0), Complex64::new(3.0, -1.0)],
+///     [Complex64::new(0.0, 0.0), Complex64::new(-2.0, 0.5)]
+/// ];
+/// let mut w = eigvals(&a).unwrap().to_vec();
+/// w.sort_by(|x, y| x.re.total_cmp(&y.re));
+/// assert_abs_diff_eq!(w[0].re, -2.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(w[0].im, 0.5, epsilon = 1e-12);
+/// assert_abs_diff_eq!(w[1].im, 2.0, epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::LinAlgError` if `a` is not square, contains non-finite values, or
+/// the QR iteration does not converge.
+pub fn eigvals<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array1<Complex64>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let mut h = square_finite(a)?;
+    balance(&mut h);
+    reduce_to_hessenberg(&mut h);
+    if h.iter().all(|v| v.im() == 0.0) {
+        hessenberg_eigenvalues(&mut h.mapv(Scalar::re))
+    } else {
+        complex_hessenberg_eigenvalues(&mut h.mapv(Scalar::to_complex))
+    }
+}
+
+/// Checks that `a` is square and finite, returning it as a matrix of its
+/// [`Wide`](Scalar::Wide) type.
+fn square_finite<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<A::Wide>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    if a.nrows() != a.ncols() {
+        return Err(NumrustError::LinAlgError(
+            "Last 2 dimensions of the array must be square".to_string(),
+        ));
+    }
+    if a.iter().any(|v| !v.is_finite()) {
+        return Err(NumrustError::LinAlgError(
+            "Array must not contain infs or NaNs".to_string(),
+        ));
+    }
+    Ok(a.mapv(Scalar::widen))
+}
+
+/// Computes the Cholesky factor of a real symmetric or complex Hermitian positive-definite
+/// matrix.
+///
+/// Only the lower triangle of `a` is read, and the imaginary parts of its diagonal are ignored,
+/// as with `numpy.linalg.cholesky`.
+///
+/// # Arguments
+///
+/// * `a` - An `n x n` Hermitian positive-definite matrix.
+///
+/// # Returns
+///
+/// The lower-triangular matrix `l` with `l.dot(&l.t())` (the conjugate transpose for complex
+/// input) equal to `a`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::complex::Complex64;
+/// use numrust::linalg::cholesky;
+///
+/// let a = array![[4.0, 2.0], [2.0, 5.0]];
+/// let l = cholesky(&a).unwrap();
+/// assert_eq!(l, array![[2.0, 0.0], [1.0, 2.0]]);
+/// assert!(cholesky(&array![[1.0, 2.0], [2.0, 1.0]]).is_err());
+///
+/// let z = |re, im| Complex64::new(re, im);
+/// let a = array![[z(4.0, 0.0), z(0.0, -2.0)], [z(0.0, 2.0), z(5.0, 0.0)]];
+/// let l = cholesky(&a).unwrap();
+/// assert_eq!(l, array![[z(2.0, 0.0), z(0.0, 0.0)], [z(0.0, 1.0), z(2.0, 0.0)]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::LinAlgError` if `a` is not square, contains non-finite values, or is
+/// not positive definite.
+pub fn cholesky<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<A::Wide>, NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let a = square_finite(a)?;
+    let n = a.nrows();
+    let mut l = Array2::<A::Wide>::zeros((n, n));
+    for j in 0..n {
+        let row = l.row(j);
+        let diagonal =
+            a[[j, j]].re() - dotc(row.slice(ndarray::s![..j]), row.slice(ndarray::s![..j])).re();
+        if diagonal <= 0.0 || diagonal.is_nan() {
+            return Err(NumrustError::LinAlgError(
+                "Matrix is not positive definite".to_string(),
+            ));
+        }
+        let pivot = diagonal.sqrt();
+        l[[j, j]] = A::Wide::from_real(pivot);
+        for i in (j + 1)..n {
+            let dot = dotc(
+                l.row(j).slice(ndarray::s![..j]),
+                l.row(i).slice(ndarray::s![..j]),
+            );
+            l[[i, j]] = (a[[i, j]] - dot).unscale(pivot);
+        }
+    }
+    Ok(l)
+}
+
+/// Computes the eigenvalues and eigenvectors of a real symmetric or complex Hermitian matrix.
+///
+/// Only the lower triangle of `a` is read, as with `numpy.linalg.eigh`. The decomposition uses
+/// cyclic Jacobi rotations, which find small eigenvalues to high relative accuracy.
+///
+/// # Arguments
+///
+/// * `a` - An `n x n` Hermitian matrix.
+///
+/// # Returns
+///
+/// A tuple `(w, v)` of the real eigenvalues in ascending order and the matrix whose columns are
+/// the corresponding orthonormal eigenvectors, complex for complex input.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{array, Array2};
+/// use numrust::complex::Complex64;
+/// use numrust::linalg::eigh;
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[2.0, 1.0], [1.0, 2.0]];
+/// let (w, v) = eigh(&a).unwrap();
+/// assert_abs_diff_eq!(w, array![1.0, 3.0], epsilon = 1e-12);
+/// assert_abs_diff_eq!(v.dot(&Array2::from_diag(&w)).dot(&v.t()), a, epsilon = 1e-12);
+///
+/// // The Pauli matrix sigma_y has eigenvalues -1 and 1.
+/// let sigma_y = array![
+///     [Complex64::new(0.0, 0.0), Complex64::new(0.0, -1.0)],
+///     [Complex64::new(0.0, 1.0), Complex64::new(0.0, 0.0)]
+/// ];
+/// let (w, _) = eigh(&sigma_y).unwrap();
+/// assert_abs_diff_eq!(w, array![-1.0, 1.0], epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::LinAlgError` if `a` is not square, contains non-finite values, or
+/// the iteration does not converge.
+pub fn eigh<A, S>(a: &ArrayBase<S, Ix2>) -> Result<(Array1<f64>, Array2<A::Wide>), NumrustError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    const MAX_SWEEPS: usize = 100;
+    let mut a = square_finite(a)?;
+    let n = a.nrows();
+    for i in 0..n {
+        a[[i, i]] = A::Wide::from_real(a[[i, i]].re());
+        for j in (i + 1)..n {
+            a[[i, j]] = a[[j, i]].conj();
+        }
+    }
+    let mut v = Array2::<A::Wide>::eye(n);
+
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let apq = a[[p, q]];
+                let g = apq.abs();
+                let (app, aqq) = (a[[p, p]].re(), a[[q, q]].re());
+                if g == 0.0 || g <= f64::EPSILON * (app * aqq).abs().sqrt() {
+                    continue;
+                }
+                rotated = true;
+                // Rotating against the unit phase of a[p, q] makes the 2x2 problem real.
+                let phase = apq.unscale(g);
+                let theta = (aqq - app) / (2.0 * g);
+                let t = theta.signum() / (theta.abs() + 1f64.hypot(theta));
+                let t = if theta == 0.0 { 1.0 } else { t };
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = phase.scale(c * t);
+                // Apply the rotation J^H A J to rows and columns p and q.
+                rotate_columns(&mut a, p, q, c, s);
+                for k in 0..n {
+                    let (apk, aqk) = (a[[p, k]], a[[q, k]]);
+                    a[[p, k]] = apk.scale(c) - s * aqk;
+                    a[[q, k]] = s.conj() * apk + aqk.scale(c);
+                }
+                rotate_columns(&mut v, p, q, c, s);
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(NumrustError::LinAlgError(
+            "Eigenvalues did not converge".to_string(),
+        ));
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[[i, i]].re().total_cmp(&a[[j, j]].re()));
+    let w = order.iter().map(|&i| a[[i, i]].re()).collect();
+    let v = v.select(Axis(1), &order);
+    Ok((w, v))
+}
+
+/// Scales rows and columns of `a` by powers of two, as the balancing step of LAPACK's `dgebal`
+/// does, so that each off-diagonal row and column have comparable norms. The similarity
+/// transform is exact in binary arithmetic and makes the eigenvalues better conditioned.
+fn balance<F: Field>(a: &mut Array2<F>) {
+    const MAX_PASSES: usize = 100;
+    let n = a.nrows();
+    for _ in 0..MAX_PASSES {
+        let mut converged = true;
+        for i in 0..n {
+            let column: f64 = (0..n).filter(|&j| j != i).map(|j| a[[j, i]].abs()).sum();
+            let row: f64 = (0..n).filter(|&j| j != i).map(|j| a[[i, j]].abs()).sum();
+            if column == 0.0 || row == 0.0 {
+                continue;
+            }
+            // Scaling the column by f and the row by 1/f changes their sum to
+            // column * f + row / f, which is smallest when f = sqrt(row / column).
+            let exponent = (0.5 * (row / column).log2()).round() as i32;
+            let f = 2f64.powi(exponent);
+            if column * f + row / f < 0.95 * (column + row) {
+                converged = false;
+                a.column_mut(i).mapv_inplace(|v| v.scale(f));
+                a.row_mut(i).mapv_inplace(|v| v.unscale(f));
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+}
+
+/// Returns the Householder vector `v` and factor `beta` with `(I - beta v v^H) u` a multiple of
+/// the first unit vector, or `None` if `u` is zero.
+fn householder<F: Field>(u: &[F]) -> Option<(Vec<F>, f64)> {
+    let norm = u.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return None;
+    }
+    // Reflect onto -phase(u[0]) * norm so that forming v[0] does not cancel.
+    let phase = if u[0].abs() == 0.0 {
+        F::one()
+    } else {
+        u[0].unscale(u[0].abs())
+    };
+    let alpha = -phase.scale(norm);
+    let mut v = u.to_vec();
+    v[0] = v[0] - alpha;
+    let vv: f64 = v.iter().map(|x| x.norm_sqr()).sum();
+    if vv == 0.0 {
+        return None;
+    }
+    Some((v, 2.0 / vv))
+}
+
+/// Applies `I - beta v v^H` from the left to rows `first..first + v.len()` of `a`, restricted
+/// to `columns`.
+fn reflect_rows<F: Field>(
+    a: &mut Array2<F>,
+    v: &[F],
+    beta: f64,
+    first: usize,
+    columns: Range<usize>,
+) {
+    for j in columns {
+        let dot: F = v
+            .iter()
+            .enumerate()
+            .map(|(i, vi)| vi.conj() * a[[first + i, j]])
+            .sum();
+        for (i, &vi) in v.iter().enumerate() {
+            a[[first + i, j]] = a[[first + i, j]] - dot.scale(beta) * vi;
+        }
+    }
+}
+
+/// Applies `I - beta v v^H` from the right to columns `first..first + v.len()` of `a`,
+/// restricted to `rows`.
+fn reflect_columns<F: Field>(
+    a: &mut Array2<F>,
+    v: &[F],
+    beta: f64,
+    first: usize,
+    rows: Range<usize>,
+) {
+    for i in rows {
+        let dot: F = v
+            .iter()
+            .enumerate()
+            .map(|(j, &vj)| vj * a[[i, first + j]])
+            .sum();
+        for (j, vj) in v.iter().enumerate() {
+            a[[i, first + j]] = a[[i, first + j]] - dot.scale(beta) * vj.conj();
+        }
+    }
+}
+
+/// Reduces `a` to upper Hessenberg form with Householder similarity transformations, as
+/// LAPACK's `dgehrd` and `zgehrd` do.
+fn reduce_to_hessenberg<F: Field>(a: &mut Array2<F>) {
+    let n = a.nrows();
+    for k in 0..n.saturating_sub(2) {
+        let column: Vec<F> = (k + 1..n).map(|i| a[[i, k]]).collect();
+        if let Some((v, beta)) = householder(&column) {
+            reflect_rows(a, &v, beta, k + 1, k..n);
+            reflect_columns(a, &v, beta, k + 1, 0..n);
+        }
+        for i in k + 2..n {
+            a[[i, k]] = F::zero();
+        }
+    }
+}
+
+/// Returns the eigenvalues of the 2 x 2 matrix `[[a, b], [c, d]]`, computing the larger real
+/// root first and the other from the determinant to avoid cancellation.
+fn eigenvalues_2x2(a: f64, b: f64, c: f64, d: f64) -> (Complex64, Complex64) {
+    let mean = 0.5 * (a + d);
+    let half_gap = 0.5 * (a - d);
+    let discriminant = half_gap * half_gap + b * c;
+    if discriminant >= 0.0 {
+        let root = discriminant.sqrt();
+        let larger = mean + if half_gap >= 0.0 { root } else { -root };
+        let other = if larger != 0.0 {
+            (a * d - b * c) / larger
+        } else {
+            mean - root
+        };
+        (Complex64::new(larger, 0.0), Complex64::new(other, 0.0))
+    } else {
+        let root = (-discriminant).sqrt();
+        (Complex64::new(mean, root), Complex64::new(mean, -root))
+    }
+}
+
+/// Finds the eigenvalues of the upper Hessenberg matrix `h` with Francis double-shift QR
+/// steps, implicitly shifted by the eigenvalues of the trailing 2 x 2 block of the active
+/// window and deflating whenever a subdiagonal entry becomes negligible. `h` is destroyed.
+fn hessenberg_eigenvalues(h: &mut Array2<f64>) -> Result<Array1<Complex64>, NumrustError> {
+    const MAX_ITERATIONS: usize = 60;
+    let n = h.nrows();
+    let mut eigenvalues = vec![Complex64::new(0.0, 0.0); n];
+    let scale = h.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+    // The active window is lo..hi; everything from hi on has converged.
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 0 {
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let mut neighbours = h[[lo - 1, lo - 1]].abs() + h[[lo, lo]].abs();
+            if neighbours == 0.0 {
+                neighbours = scale;
+            }
+            if h[[lo, lo - 1]].abs() <= f64::EPSILON * neighbours {
+                h[[lo, lo - 1]] = 0.0;
+                break;
+            }
+            lo -= 1;
+        }
+
+        match hi - lo {
+            1 => {
+                eigenvalues[hi - 1] = Complex64::new(h[[hi - 1, hi - 1]], 0.0);
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+            2 => {
+                let (first, second) = eigenvalues_2x2(
+                    h[[hi - 2, hi - 2]],
+                    h[[hi - 2, hi - 1]],
+                    h[[hi - 1, hi - 2]],
+                    h[[hi - 1, hi - 1]],
+                );
+                eigenvalues[hi - 2] = first;
+                eigenvalues[hi - 1] = second;
+                hi -= 2;
+                iterations = 0;
+                continue;
+            }
+            _ => {}
+        }
+        if iterations == MAX_ITERATIONS {
+            return Err(NumrustError::LinAlgError(
+                "Eigenvalues did not converge".to_string(),
+            ));
+        }
+        iterations += 1;
+
+        // The shifts enter only through their sum and product.
+        let (sum, product) = if iterations % 10 == 0 {
+            // An ad hoc shift breaks the rare cycles of the standard one.
+            let w = h[[hi - 1, hi - 2]].abs() + h[[hi - 2, hi - 3]].abs();
+            (1.5 * w, w * w)
+        } else {
+            let (a, b) = (h[[hi - 2, hi - 2]], h[[hi - 2, hi - 1]]);
+            let (c, d) = (h[[hi - 1, hi - 2]], h[[hi - 1, hi - 1]]);
+            (a + d, a * d - b * c)
+        };
+
+        // The first column of (H - s1 I)(H - s2 I) has three nonzero entries; chase the bulge
+        // it creates down the subdiagonal.
+        let mut x = h[[lo, lo]] * h[[lo, lo]] + h[[lo, lo + 1]] * h[[lo + 1, lo]]
+            - sum * h[[lo, lo]]
+            + product;
+        let mut y = h[[lo + 1, lo]] * (h[[lo, lo]] + h[[lo + 1, lo + 1]] - sum);
+        let mut z = h[[lo + 1, lo]] * h[[lo + 2, lo + 1]];
+        for k in lo..hi - 2 {
+            if let Some((v, beta)) = householder(&[x, y, z]) {
+                let first_column = if k > lo { k - 1 } else { lo };
+                reflect_rows(h, &v, beta, k, first_column..hi);
+                reflect_columns(h, &v, beta, k, lo..(k + 4).min(hi));
+            }
+            x = h[[k + 1, k]];
+            y = h[[k + 2, k]];
+            if k + 3 < hi {
+                z = h[[k + 3, k]];
+            }
+        }
+        if let Some((v, beta)) = householder(&[x, y]) {
+            reflect_rows(h, &v, beta, hi - 2, hi - 3..hi);
+            reflect_columns(h, &v, beta, hi - 2, lo..hi);
+        }
+    }
+    Ok(Array1::from(eigenvalues))
+}
+
+/// Finds the eigenvalues of the complex upper Hessenberg matrix `h` with single-shift QR
+/// steps, shifted by the eigenvalue of the trailing 2 x 2 block of the active window closest to
+/// its last diagonal entry and deflating whenever a subdiagonal entry becomes negligible. `h`
+/// is destroyed.
+fn complex_hessenberg_eigenvalues(
+    h: &mut Array2<Complex64>,
+) -> Result<Array1<Complex64>, NumrustError> {
+    const MAX_ITERATIONS: usize = 60;
+    let n = h.nrows();
+    let mut eigenvalues = vec![Complex64::new(0.0, 0.0); n];
+    let scale = h.iter().map(|v| v.norm_sqr()).sum::<f64>().sqrt();
+
+    // The active window is lo..hi; everything from hi on has converged.
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 0 {
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let mut neighbours = h[[lo - 1, lo - 1]].norm() + h[[lo, lo]].norm();
+            if neighbours == 0.0 {
+                neighbours = scale;
+            }
+            if h[[lo, lo - 1]].norm() <= f64::EPSILON * neighbours {
+                h[[lo, lo - 1]] = Complex64::new(0.0, 0.0);
+                break;
+            }
+            lo -= 1;
+        }
+
+        if hi - lo == 1 {
+            eigenvalues[hi - 1] = h[[hi - 1, hi - 1]];
+            hi -= 1;
+            iterations = 0;
+            continue;
+        }
+        if iterations == MAX_ITERATIONS {
+            return Err(NumrustError::LinAlgError(
+                "Eigenvalues did not converge".to_string(),
+            ));
+        }
+        iterations += 1;
+
+        let d = h[[hi - 1, hi - 1]];
+        let shift = if iterations % 10 == 0 {
+            // An ad hoc shift breaks the rare cycles of the standard one.
+            let below = if hi - lo > 2 {
+                h[[hi - 2, hi - 3]].norm()
+            } else {
+                0.0
+            };
+            d + h[[hi - 1, hi - 2]].norm() + below
+        } else {
+            let (a, b, c) = (
+                h[[hi - 2, hi - 2]],
+                h[[hi - 2, hi - 1]],
+                h[[hi - 1, hi - 2]],
+            );
+            let half_gap = (a - d) * 0.5;
+            let root = (half_gap * half_gap + b * c).sqrt();
+            let mean = (a + d) * 0.5;
+            let (first, second) = (mean + root, mean - root);
+            if (first - d).norm() <= (second - d).norm() {
+                first
+            } else {
+                second
+            }
+        };
+
+        // Factor H - shift I = QR with Givens rotations, then form RQ + shift I.
+        for k in lo..hi {
+            h[[k, k]] -= shift;
+        }
+        let mut rotations = Vec::with_capacity(hi - lo - 1);
+        for k in lo..hi - 1 {
+            let (x, y) = (h[[k, k]], h[[k + 1, k]]);
+            let r = x.norm().hypot(y.norm());
+            let (c, s) = if r == 0.0 {
+                (1.0, Complex64::new(0.0, 0.0))
+            } else if x.norm() == 0.0 {
+                (0.0, y.conj() / y.norm())
+            } else {
+                (x.norm() / r, x / x.norm() * y.conj() / r)
+            };
+            for j in k..hi {
+                let (p, q) = (h[[k, j]], h[[k + 1, j]]);
+                h[[k, j]] = p * c + s * q;
+                h[[k + 1, j]] = q * c - s.conj() * p;
+            }
+            rotations.push((c, s));
+        }
+        for (k, &(c, s)) in (lo..).zip(&rotations) {
+            for i in lo..(k + 2).min(hi) {
+                let (p, q) = (h[[i, k]], h[[i, k + 1]]);
+                h[[i, k]] = p * c + q * s.conj();
+                h[[i, k + 1]] = q * c - p * s;
+            }
+        }
+        for k in lo..hi {
+            h[[k, k]] += shift;
+        }
+    }
+    Ok(Array1::from(eigenvalues))
+}
+
+#[cfg(test)]
+mod numrust_linalg_tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array3};
+
+    #[test]
+    fn test_vector_norm_orders() {
+        let x = [1.0, -2.0, 0.0, 2.0];
+        assert_eq!(vector_norm(&x, NormOrd::P(2.0)).unwrap(), 3.0);
+        assert_eq!(vector_norm(&x, NormOrd::P(1.0)).unwrap(), 5.0);
+        assert_eq!(vector_norm(&x, NormOrd::P(0.0)).unwrap(), 3.0);
+        assert_eq!(vector_norm(&x, NormOrd::Inf).unwrap(), 2.0);
+        assert_eq!(vector_norm(&x, NormOrd::NegInf).unwrap(), 0.0);
+        assert_abs_diff_eq!(
+            vector_norm(&x, NormOrd::P(3.0)).unwrap(),
+            17f64.powf(1.0 / 3.0),
+            epsilon = 1e-12
+        );
+        assert_eq!(vector_norm(&x, NormOrd::P(-1.0)).unwrap(), 0.0);
+        assert_abs_diff_eq!(
+            vector_norm(&[1.0, 2.0], NormOrd::P(-1.0)).unwrap(),
+            2.0 / 3.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_vector_norm_rejects_matrix_orders() {
+        assert!(vector_norm(&[1.0, 2.0], NormOrd::Fro).is_err());
+        assert!(vector_norm(&[1.0, 2.0], NormOrd::Nuc).is_err());
+        let empty: [f64; 0] = [];
+        assert!(vector_norm(&empty, NormOrd::Inf).is_err());
+        assert_eq!(vector_norm(&empty, NormOrd::P(2.0)).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_norm_avoids_overflow_and_underflow() {
+        let big = [3e200, 4e200];
+        assert_abs_diff_eq!(
+            vector_norm(&big, NormOrd::P(2.0)).unwrap(),
+            5e200,
+            epsilon = 1e186
+        );
+        let tiny = [3e-200, 4e-200];
+        assert_abs_diff_eq!(
+            vector_norm(&tiny, NormOrd::P(2.0)).unwrap(),
+            5e-200,
+            epsilon = 1e-214
+        );
+        assert_abs_diff_eq!(
+            vector_norm(&big, NormOrd::P(3.0)).unwrap(),
+            91f64.powf(1.0 / 3.0) * 1e200,
+            epsilon = 1e186
+        );
+
+        let m = array![[3e200, 0.0], [0.0, 4e200]];
+        let two = norm(&m, Some(NormOrd::P(2.0)), None, false).unwrap();
+        assert_abs_diff_eq!(two[[]], 4e200, epsilon = 1e186);
+        let fro = norm(&m, None, None, false).unwrap();
+        assert_abs_diff_eq!(fro[[]], 5e200, epsilon = 1e186);
+    }
+
+    #[test]
+    fn test_norm_non_finite() {
+        let x = [1.0, f64::INFINITY];
+        assert_eq!(vector_norm(&x, NormOrd::P(2.0)).unwrap(), f64::INFINITY);
+        let x = [1.0, f64::NAN];
+        assert!(vector_norm(&x, NormOrd::P(2.0)).unwrap().is_nan());
+        assert!(vector_norm(&x, NormOrd::Inf).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_matrix_norm_orders() {
+        let a = array![[1.0, 2.0], [3.0, 4.0]];
+        let n = |ord| norm(&a, Some(ord), None, false).unwrap()[[]];
+        assert_abs_diff_eq!(n(NormOrd::Fro), 5.477225575051661, epsilon = 1e-12);
+        assert_abs_diff_eq!(n(NormOrd::Nuc), 5.830951894845301, epsilon = 1e-12);
+        assert_abs_diff_eq!(n(NormOrd::P(2.0)), 5.464985704219043, epsilon = 1e-12);
+        assert_abs_diff_eq!(n(NormOrd::P(-2.0)), 0.365966190626258, epsilon = 1e-12);
+        assert_eq!(n(NormOrd::P(1.0)), 6.0);
+        assert_eq!(n(NormOrd::P(-1.0)), 4.0);
+        assert_eq!(n(NormOrd::Inf), 7.0);
+        assert_eq!(n(NormOrd::NegInf), 3.0);
+        assert!(norm(&a, Some(NormOrd::P(3.0)), None, false).is_err());
+    }
+
+    #[test]
+    fn test_norm_default_flattens() {
+        let a = Array3::from_shape_vec((2, 2, 2), (1..=8).map(|x| x as f64).collect()).unwrap();
+        let result = norm(&a, None, None, false).unwrap();
+        assert_abs_diff_eq!(result[[]], 204f64.sqrt(), epsilon = 1e-12);
+        assert!(norm(&a, Some(NormOrd::Fro), None, false).is_err());
+
+        let kept = norm(&a, None, None, true).unwrap();
+        assert_eq!(kept.shape(), &[1, 1, 1]);
+    }
+
+    #[test]
+    fn test_norm_along_axis() {
+        let a = array![[3.0, 0.0], [4.0, 1.0]];
+        let result = norm(&a, None, Some(&[0]), false).unwrap();
+        assert_eq!(result.into_raw_vec(), vec![5.0, 1.0]);
+
+        let result = norm(&a, Some(NormOrd::Inf), Some(&[-1]), true).unwrap();
+        assert_eq!(result.shape(), &[2, 1]);
+        assert_eq!(result.into_raw_vec(), vec![3.0, 4.0]);
+
+        assert!(norm(&a, None, Some(&[2]), false).is_err());
+    }
+
+    #[test]
+    fn test_norm_over_matrix_axes() {
+        let a = Array3::from_shape_vec((2, 2, 2), (1..=8).map(|x| x as f64).collect()).unwrap();
+        let result = norm(&a, Some(NormOrd::P(1.0)), Some(&[1, 2]), false).unwrap();
+        assert_eq!(result.into_raw_vec(), vec![6.0, 14.0]);
+
+        // Swapping the axes transposes every matrix, so the 1-norm becomes the inf-norm.
+        let result = norm(&a, Some(NormOrd::P(1.0)), Some(&[2, 1]), false).unwrap();
+        assert_eq!(result.into_raw_vec(), vec![7.0, 15.0]);
+
+        let result = norm(&a, None, Some(&[0, 2]), true).unwrap();
+        assert_eq!(result.shape(), &[1, 2, 1]);
+        assert_abs_diff_eq!(result[[0, 0, 0]], 66f64.sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(result[[0, 1, 0]], 138f64.sqrt(), epsilon = 1e-12);
+
+        assert!(norm(&a, None, Some(&[1, 1]), false).is_err());
+        assert!(norm(&a, None, Some(&[0, 1, 2]), false).is_err());
+    }
+
+    #[test]
+    fn test_norm_of_complex_data() {
+        let z = [Complex64::new(3.0, 4.0), Complex64::new(0.0, 0.0)];
+        assert_eq!(vector_norm(&z, NormOrd::P(2.0)).unwrap(), 5.0);
+        assert_eq!(vector_norm(&z, NormOrd::P(1.0)).unwrap(), 5.0);
+
+        let m = array![
+            [Complex64::new(1.0, 1.0), Complex64::new(0.0, 2.0)],
+            [Complex64::new(3.0, 0.0), Complex64::new(-1.0, -1.0)]
+        ];
+        let n = |ord| norm(&m, Some(ord), None, false).unwrap()[[]];
+        assert_abs_diff_eq!(n(NormOrd::Fro), 17f64.sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(n(NormOrd::P(1.0)), 2f64.sqrt() + 3.0, epsilon = 1e-12);
+        // The singular values are the square roots of the eigenvalues of m^H m.
+        assert_abs_diff_eq!(n(NormOrd::P(2.0)), 3.3722813232690143, epsilon = 1e-12);
+        assert_abs_diff_eq!(n(NormOrd::P(-2.0)), 2.3722813232690143, epsilon = 1e-12);
+        assert_abs_diff_eq!(n(NormOrd::Nuc), 5.744562646538029, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_svd_reconstructs_matrix() {
+        let a = array![[2.0, -1.0, 0.5], [1.0, 3.0, -2.0]];
+        let (u, s, vt) = svd(&a).unwrap();
+        assert_eq!(u.dim(), (2, 2));
+        assert_eq!(vt.dim(), (2, 3));
+        assert!(s[0] >= s[1]);
+        let rebuilt = u.dot(&Array2::from_diag(&s)).dot(&vt);
+        for (x, y) in rebuilt.iter().zip(a.iter()) {
+            assert_abs_diff_eq!(x, y, epsilon = 1e-12);
+        }
+        let identity = u.t().dot(&u);
+        for ((i, j), v) in identity.indexed_iter() {
+            assert_abs_diff_eq!(*v, if i == j { 1.0 } else { 0.0 }, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_svd_rank_deficient() {
+        let a = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        let (u, s, _) = svd(&a).unwrap();
+        assert_abs_diff_eq!(s[0], 70f64.sqrt(), epsilon = 1e-12);
+        assert_eq!(s[1], 0.0);
+        let identity = u.t().dot(&u);
+        for ((i, j), v) in identity.indexed_iter() {
+            assert_abs_diff_eq!(*v, if i == j { 1.0 } else { 0.0 }, epsilon = 1e-12);
+        }
+        assert!(svd(&array![[f64::NAN]]).is_err());
+    }
+
+    fn sorted_eigenvalues(a: &Array2<f64>) -> Vec<Complex64> {
+        let mut w = eigvals(a).unwrap().to_vec();
+        w.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+        w
+    }
+
+    #[test]
+    fn test_eigvals_real_spectrum() {
+        let a = array![[2.0, 0.0, 0.0], [0.0, 3.0, 4.0], [0.0, 4.0, 9.0]];
+        let w = sorted_eigenvalues(&a);
+        for (z, expected) in w.iter().zip([1.0, 2.0, 11.0]) {
+            assert_abs_diff_eq!(z.re, expected, epsilon = 1e-10);
+            assert_abs_diff_eq!(z.im, 0.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_eigvals_complex_pairs() {
+        // Companion matrix of x^3 - 1, whose eigenvalues are the cube roots of unity.
+        let a = array![[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+        let w = sorted_eigenvalues(&a);
+        let half_root3 = 3f64.sqrt() / 2.0;
+        let expected = [(-0.5, -half_root3), (-0.5, half_root3), (1.0, 0.0)];
+        for (z, (re, im)) in w.iter().zip(expected) {
+            assert_abs_diff_eq!(z.re, re, epsilon = 1e-10);
+            assert_abs_diff_eq!(z.im, im, epsilon = 1e-10);
+        }
+        let trace: Complex64 = eigvals(&array![
+            [4.0, -2.0, 1.0, 0.5],
+            [3.0, 1.0, -1.0, 2.0],
+            [0.0, 2.0, 5.0, -3.0],
+            [1.0, 1.0, 1.0, 1.0]
+        ])
+        .unwrap()
+        .sum();
+        assert_abs_diff_eq!(trace.re, 11.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(trace.im, 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_eigvals_badly_scaled_and_larger() {
+        // Balancing brings the off-diagonal entries to the same size; the eigenvalues are 0 and 2.
+        let w = sorted_eigenvalues(&array![[1.0, 1e10], [1e-10, 1.0]]);
+        assert_abs_diff_eq!(w[0].re, 0.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(w[1].re, 2.0, epsilon = 1e-10);
+
+        // The eigenvalues of a 16 x 16 matrix match the traces of A and A^2.
+        let a = Array2::from_shape_fn((16, 16), |(i, j)| {
+            ((i * 7 + j * 13) % 17) as f64 - 8.0 + if i == j { 3.0 } else { 0.0 }
+        });
+        let w = eigvals(&a).unwrap();
+        let trace: Complex64 = w.sum();
+        let trace_of_square: Complex64 = w.iter().map(|z| z * z).sum();
+        assert_abs_diff_eq!(trace.re, a.diag().sum(), epsilon = 1e-8);
+        assert_abs_diff_eq!(trace.im, 0.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(trace_of_square.re, a.dot(&a).diag().sum(), epsilon = 1e-7);
+        assert_abs_diff_eq!(trace_of_square.im, 0.0, epsilon = 1e-7);
+    }
+
+    #[test]
+    fn test_eigvals_complex_non_hermitian() {
+        // [[0, 1], [-1, 2i]] has characteristic polynomial x^2 - 2i x + 1, with roots
+        // i (1 +- sqrt(2)).
+        let a = array![
+            [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+            [Complex64::new(-1.0, 0.0), Complex64::new(0.0, 2.0)]
+        ];
+        let mut w = eigvals(&a).unwrap().to_vec();
+        w.sort_by(|x, y| x.im.total_cmp(&y.im));
+        for (z, expected) in w.iter().zip([1.0 - 2f64.sqrt(), 1.0 + 2f64.sqrt()]) {
+            assert_abs_diff_eq!(z.re, 0.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(z.im, expected, epsilon = 1e-12);
+        }
+
+        // The power sums of the eigenvalues match the traces of powers of a, which fixes
+        // the spectrum of a 4 x 4 matrix.
+        let a = Array2::from_shape_fn((4, 4), |(i, j)| {
+            Complex64::new(
+                ((i * 5 + j * 3) % 7) as f64 - 3.0,
+                ((i + 2 * j) % 5) as f64 - 2.0,
+            )
+        });
+        let w = eigvals(&a).unwrap();
+        let mut power = Array2::<Complex64>::eye(4);
+        for k in 1..=4 {
+            power = power.dot(&a);
+            let trace = power.diag().sum();
+            let sum: Complex64 = w.iter().map(|z| z.powi(k)).sum();
+            assert_abs_diff_eq!(sum.re, trace.re, epsilon = 1e-8);
+            assert_abs_diff_eq!(sum.im, trace.im, epsilon = 1e-8);
+        }
+
+        // Complex input with no imaginary part takes the real path.
+        let real = array![[0.0, -1.0], [1.0, 0.0]];
+        assert_eq!(
+            eigvals(&real).unwrap(),
+            eigvals(&real.mapv(|x| Complex64::new(x, 0.0))).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_eigvals_rejects_bad_input() {
+        assert!(eigvals(&array![[1.0, 2.0]]).is_err());
+        assert!(eigvals(&array![[f64::INFINITY]]).is_err());
+        assert_eq!(
+            eigvals(&array![[5.0]]).unwrap()[0],
+            Complex64::new(5.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_cholesky_and_eigh() {
+        let a = array![
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0]
+        ];
+        let l = cholesky(&a).unwrap();
+        assert_abs_diff_eq!(
+            l,
+            array![[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]],
+            epsilon = 1e-12
+        );
+        assert!(cholesky(&array![[1.0, 0.0], [0.0, 0.0]]).is_err());
+        assert!(cholesky(&array![[1.0, 2.0, 3.0]]).is_err());
+
+        let (w, v) = eigh(&a).unwrap();
+        assert!(w.windows(2).into_iter().all(|p| p[0] <= p[1]));
+        assert_abs_diff_eq!(v.t().dot(&v), Array2::eye(3), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            v.dot(&Array2::from_diag(&w)).dot(&v.t()),
+            a,
+            epsilon = 1e-10
+        );
+
+        // Only the lower triangle is read, and indefinite matrices have negative eigenvalues.
+        let (w, _) = eigh(&array![[1.0, 99.0], [2.0, 1.0]]).unwrap();
+        assert_abs_diff_eq!(w, array![-1.0, 3.0], epsilon = 1e-12);
+        assert!(eigh(&array![[f64::NAN]]).is_err());
+    }
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    fn assert_unitary_columns(m: &Array2<Complex64>) {
+        let gram = m.t().mapv(|z| z.conj()).dot(m);
+        for ((i, j), z) in gram.indexed_iter() {
+            assert_abs_diff_eq!(z.re, if i == j { 1.0 } else { 0.0 }, epsilon = 1e-12);
+            assert_abs_diff_eq!(z.im, 0.0, epsilon = 1e-12);
+        }
+    }
+
+    fn assert_complex_close(a: &Array2<Complex64>, b: &Array2<Complex64>, epsilon: f64) {
+        assert_eq!(a.dim(), b.dim());
+        for (x, y) in a.iter().zip(b) {
+            assert_abs_diff_eq!(x.re, y.re, epsilon = epsilon);
+            assert_abs_diff_eq!(x.im, y.im, epsilon = epsilon);
+        }
+    }
+
+    #[test]
+    fn test_svd_of_complex_matrices() {
+        let a = array![
+            [c(1.0, 2.0), c(0.0, -1.0), c(3.0, 0.0)],
+            [c(-2.0, 1.0), c(4.0, 0.5), c(0.0, 1.0)]
+        ];
+        for m in [a.clone(), a.t().to_owned()] {
+            let (u, s, vt) = svd(&m).unwrap();
+            assert!(s[0] >= s[1]);
+            assert_unitary_columns(&u);
+            assert_unitary_columns(&vt.t().to_owned());
+            let sigma = Array2::from_diag(&s.mapv(|x| c(x, 0.0)));
+            assert_complex_close(&u.dot(&sigma).dot(&vt), &m, 1e-12);
+            assert_abs_diff_eq!(s, svdvals(&m).unwrap(), epsilon = 1e-12);
+        }
+
+        // A rank-one matrix keeps an orthonormal u.
+        let a = array![[c(1.0, 1.0), c(2.0, 2.0)], [c(0.0, 1.0), c(0.0, 2.0)]];
+        let (u, s, _) = svd(&a).unwrap();
+        assert_abs_diff_eq!(s[1], 0.0, epsilon = 1e-12);
+        assert_unitary_columns(&u);
+        assert!(svd(&array![[c(f64::NAN, 0.0)]]).is_err());
+    }
+
+    #[test]
+    fn test_lstsq_complex() {
+        let a = array![
+            [c(1.0, 0.0), c(0.0, 1.0)],
+            [c(1.0, 0.0), c(2.0, 0.0)],
+            [c(0.0, 1.0), c(1.0, 0.0)]
+        ];
+        let expected = array![c(1.0, -1.0), c(2.0, 1.0)];
+        let x = lstsq(&a, &a.dot(&expected), None).unwrap();
+        for (x, e) in x.iter().zip(&expected) {
+            assert_abs_diff_eq!(x.re, e.re, epsilon = 1e-12);
+            assert_abs_diff_eq!(x.im, e.im, epsilon = 1e-12);
+        }
+
+        // For an inconsistent system the residual is orthogonal to the columns of a.
+        let b = array![c(1.0, 0.0), c(0.0, 2.0), c(-1.0, 1.0)];
+        let x = lstsq(&a, &b, None).unwrap();
+        let normal = a.t().mapv(|z| z.conj()).dot(&(a.dot(&x) - &b));
+        for z in normal.iter() {
+            assert_abs_diff_eq!(z.norm(), 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cholesky_and_eigh_hermitian() {
+        let l = array![
+            [c(2.0, 0.0), c(0.0, 0.0), c(0.0, 0.0)],
+            [c(1.0, 1.0), c(3.0, 0.0), c(0.0, 0.0)],
+            [c(0.0, -1.0), c(2.0, -1.0), c(1.0, 0.0)]
+        ];
+        let a = l.dot(&l.t().mapv(|z| z.conj()));
+        assert_complex_close(&cholesky(&a).unwrap(), &l, 1e-12);
+        let indefinite = array![[c(1.0, 0.0), c(0.0, -2.0)], [c(0.0, 2.0), c(1.0, 0.0)]];
+        assert!(cholesky(&indefinite).is_err());
+
+        let (w, v) = eigh(&a).unwrap();
+        assert!(w.windows(2).into_iter().all(|p| p[0] <= p[1]));
+        assert!(w.iter().all(|&x| x > 0.0));
+        assert_unitary_columns(&v);
+        let lambda = Array2::from_diag(&w.mapv(|x| c(x, 0.0)));
+        let rebuilt = v.dot(&lambda).dot(&v.t().mapv(|z| z.conj()));
+        assert_complex_close(&rebuilt, &a, 1e-10);
+
+        // Only the lower triangle is read: sigma_y with a garbage upper entry.
+        let (w, v) = eigh(&array![
+            [c(0.0, 0.0), c(99.0, 0.0)],
+            [c(0.0, 1.0), c(0.0, 0.0)]
+        ])
+        .unwrap();
+        assert_abs_diff_eq!(w, array![-1.0, 1.0], epsilon = 1e-12);
+        assert_unitary_columns(&v);
+        let (w, _) = eigh(&indefinite).unwrap();
+        assert_abs_diff_eq!(w, array![-1.0, 3.0], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_lstsq_overdetermined_and_rank_deficient() {
+        let a = array![[1.0, 1.0], [1.0, 2.0], [1.0, 3.0], [1.0, 4.0]];
+        let b = array![6.0, 5.0, 7.0, 10.0];
+        let x = lstsq(&a, &b, None).unwrap();
+        assert_abs_diff_eq!(x[0], 3.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(x[1], 1.4, epsilon = 1e-12);
+
+        // Duplicate columns: the minimum-norm solution splits the weight evenly.
+        let a = array![[1.0, 1.0], [2.0, 2.0]];
+        let x = lstsq(&a, &array![2.0, 4.0], None).unwrap();
+        assert_abs_diff_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(x[1], 1.0, epsilon = 1e-12);
+
+        assert!(lstsq(&a, &array![1.0], None).is_err());
+    }
+}
@@ -0,0 +1,656 @@
+use ndarray::{arr0, Array, ArrayBase, ArrayViewD, Axis, Data, Dimension, IxDyn, Zip};
+use num_traits::Float;
+
+use crate::{broadcast_shapes, normalize_axis, NdArray, NumrustError};
+
+/// Applies `f` to the elements of `a` and `b` after broadcasting them to a common shape.
+fn broadcast_with<A, B, C, S1, S2, D1, D2, F>(
+    a: &ArrayBase<S1, D1>,
+    b: &ArrayBase<S2, D2>,
+    f: F,
+) -> Result<NdArray<C>, NumrustError>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = B>,
+    D1: Dimension,
+    D2: Dimension,
+    F: FnMut(&A, &B) -> C,
+{
+    let shape = IxDyn(&broadcast_shapes(a.shape(), b.shape())?);
+    let a = a.broadcast(shape.clone()).expect("shapes are compatible");
+    let b = b.broadcast(shape).expect("shapes are compatible");
+    Ok(Zip::from(&a).and(&b).map_collect(f))
+}
+
+/// Returns whether `a` is close to `b`, using NumPy's asymmetric test
+/// `|a - b| <= atol + rtol * |b|`.
+fn is_close(a: f64, b: f64, rtol: f64, atol: f64, equal_nan: bool) -> bool {
+    if a.is_finite() && b.is_finite() {
+        (a - b).abs() <= atol + rtol * b.abs()
+    } else if a.is_nan() || b.is_nan() {
+        equal_nan && a.is_nan() && b.is_nan()
+    } else {
+        a == b
+    }
+}
+
+/// Compares two arrays elementwise within a tolerance, following `numpy.isclose`.
+///
+/// `a` and `b` are broadcast together, and each pair of elements is close if
+/// `|a - b| <= atol + rtol * |b|`. The test is not symmetric: `b` is the reference value.
+/// Infinities are close only to infinities of the same sign, and NaNs are close to each other
+/// only if `equal_nan` is `true`. NumPy's defaults are `rtol = 1e-5` and `atol = 1e-8`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::logic::isclose;
+///
+/// let a = array![1e10, 1e-8, f64::NAN];
+/// let b = array![1.00001e10, 1e-9, f64::NAN];
+/// assert_eq!(isclose(&a, &b, 1e-5, 1e-8, false).unwrap(), array![true, true, false].into_dyn());
+/// assert_eq!(isclose(&a, &b, 1e-5, 0.0, true).unwrap(), array![true, false, true].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the shapes of `a` and `b` cannot be broadcast
+/// together.
+pub fn isclose<A, S1, S2, D1, D2>(
+    a: &ArrayBase<S1, D1>,
+    b: &ArrayBase<S2, D2>,
+    rtol: f64,
+    atol: f64,
+    equal_nan: bool,
+) -> Result<NdArray<bool>, NumrustError>
+where
+    A: Into<f64> + Copy,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+{
+    broadcast_with(a, b, |&x, &y| {
+        is_close(x.into(), y.into(), rtol, atol, equal_nan)
+    })
+}
+
+/// Returns `true` if all elements of `a` and `b` are close, following `numpy.allclose`. See
+/// [`isclose`] for the tolerance test.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::logic::allclose;
+///
+/// let a = array![[1.0, 2.0], [3.0, 4.0]];
+/// assert!(!allclose(&a, &array![1.0, 2.000001], 1e-5, 1e-8, false).unwrap());
+/// assert!(allclose(&(&a + 1e-9), &a, 1e-5, 1e-8, false).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the shapes of `a` and `b` cannot be broadcast
+/// together.
+pub fn allclose<A, S1, S2, D1, D2>(
+    a: &ArrayBase<S1, D1>,
+    b: &ArrayBase<S2, D2>,
+    rtol: f64,
+    atol: f64,
+    equal_nan: bool,
+) -> Result<bool, NumrustError>
+where
+    A: Into<f64> + Copy,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+{
+    Ok(isclose(a, b, rtol, atol, equal_nan)?
+        .iter()
+        .all(|&close| close))
+}
+
+/// Returns `true` if `a1` and `a2` have the same shape and elements, following
+/// `numpy.array_equal`.
+///
+/// If `equal_nan` is `true`, elements that are not equal to themselves, such as NaN, compare
+/// equal to each other.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::logic::array_equal;
+///
+/// assert!(array_equal(&array![1, 2], &array![1, 2], false));
+/// assert!(!array_equal(&array![1, 2], &array![[1, 2]], false));
+/// assert!(!array_equal(&array![1.0, f64::NAN], &array![1.0, f64::NAN], false));
+/// assert!(array_equal(&array![1.0, f64::NAN], &array![1.0, f64::NAN], true));
+/// ```
+pub fn array_equal<A, S1, S2, D1, D2>(
+    a1: &ArrayBase<S1, D1>,
+    a2: &ArrayBase<S2, D2>,
+    equal_nan: bool,
+) -> bool
+where
+    A: PartialEq,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+{
+    #[allow(clippy::eq_op)]
+    let unordered = |x: &A| x != x;
+    a1.shape() == a2.shape()
+        && a1
+            .iter()
+            .zip(a2.iter())
+            .all(|(x, y)| x == y || (equal_nan && unordered(x) && unordered(y)))
+}
+
+/// Returns `true` if `a1` and `a2` can be broadcast to a common shape and are then equal
+/// elementwise, following `numpy.array_equiv`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::logic::array_equiv;
+///
+/// assert!(array_equiv(&array![1, 2], &array![[1, 2], [1, 2]]));
+/// assert!(!array_equiv(&array![1, 2], &array![[1, 2], [1, 3]]));
+/// assert!(!array_equiv(&array![1, 2], &array![1, 2, 3]));
+/// ```
+pub fn array_equiv<A, S1, S2, D1, D2>(a1: &ArrayBase<S1, D1>, a2: &ArrayBase<S2, D2>) -> bool
+where
+    A: PartialEq,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+{
+    broadcast_with(a1, a2, |x, y| x == y).is_ok_and(|equal| equal.iter().all(|&e| e))
+}
+
+macro_rules! comparison {
+    ($(#[$doc:meta])* $name:ident, $op:tt) => {
+        $(#[$doc])*
+        ///
+        /// # Errors
+        ///
+        /// Returns a `NumrustError::ValueError` if the shapes of `x1` and `x2` cannot be broadcast
+        /// together.
+        pub fn $name<A, S1, S2, D1, D2>(
+            x1: &ArrayBase<S1, D1>,
+            x2: &ArrayBase<S2, D2>,
+        ) -> Result<NdArray<bool>, NumrustError>
+        where
+            A: PartialOrd,
+            S1: Data<Elem = A>,
+            S2: Data<Elem = A>,
+            D1: Dimension,
+            D2: Dimension,
+        {
+            broadcast_with(x1, x2, |a, b| a $op b)
+        }
+    };
+}
+
+comparison!(
+    /// Returns `x1 > x2` elementwise after broadcasting, following `numpy.greater`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::{arr0, array};
+    /// use numrust::logic::greater;
+    ///
+    /// let a = array![[1, 5], [3, 2]];
+    /// assert_eq!(greater(&a, &arr0(2)).unwrap(), array![[false, true], [true, false]].into_dyn());
+    /// ```
+    greater, >
+);
+
+comparison!(
+    /// Returns `x1 >= x2` elementwise after broadcasting, following `numpy.greater_equal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use numrust::logic::greater_equal;
+    ///
+    /// let result = greater_equal(&array![1.0, 2.0, f64::NAN], &array![2.0, 2.0, 0.0]).unwrap();
+    /// assert_eq!(result, array![false, true, false].into_dyn());
+    /// ```
+    greater_equal, >=
+);
+
+comparison!(
+    /// Returns `x1 < x2` elementwise after broadcasting, following `numpy.less`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use numrust::logic::less;
+    ///
+    /// let result = less(&array![[1], [3]], &array![2, 3]).unwrap();
+    /// assert_eq!(result, array![[true, true], [false, false]].into_dyn());
+    /// ```
+    less, <
+);
+
+comparison!(
+    /// Returns `x1 <= x2` elementwise after broadcasting, following `numpy.less_equal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use numrust::logic::less_equal;
+    ///
+    /// let result = less_equal(&array![1, 2, 3], &array![2, 2, 2]).unwrap();
+    /// assert_eq!(result, array![true, true, false].into_dyn());
+    /// ```
+    less_equal, <=
+);
+
+comparison!(
+    /// Returns `x1 == x2` elementwise after broadcasting, following `numpy.equal`. NaN is not
+    /// equal to anything, including itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use numrust::logic::equal;
+    ///
+    /// let result = equal(&array![1.0, f64::NAN], &array![1.0, f64::NAN]).unwrap();
+    /// assert_eq!(result, array![true, false].into_dyn());
+    /// ```
+    equal, ==
+);
+
+comparison!(
+    /// Returns `x1 != x2` elementwise after broadcasting, following `numpy.not_equal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use numrust::logic::not_equal;
+    ///
+    /// let result = not_equal(&array![1, 2], &array![1, 3]).unwrap();
+    /// assert_eq!(result, array![false, true].into_dyn());
+    /// ```
+    not_equal, !=
+);
+
+/// Returns whether `x` is truthy, meaning not equal to `A::default()` as in [`nonzero`].
+///
+/// [`nonzero`]: crate::indexing::nonzero
+fn truthy<A: Default + PartialEq>(x: &A) -> bool {
+    *x != A::default()
+}
+
+macro_rules! logical {
+    ($(#[$doc:meta])* $name:ident, $op:tt) => {
+        $(#[$doc])*
+        ///
+        /// # Errors
+        ///
+        /// Returns a `NumrustError::ValueError` if the shapes of `x1` and `x2` cannot be broadcast
+        /// together.
+        pub fn $name<A, S1, S2, D1, D2>(
+            x1: &ArrayBase<S1, D1>,
+            x2: &ArrayBase<S2, D2>,
+        ) -> Result<NdArray<bool>, NumrustError>
+        where
+            A: Default + PartialEq,
+            S1: Data<Elem = A>,
+            S2: Data<Elem = A>,
+            D1: Dimension,
+            D2: Dimension,
+        {
+            broadcast_with(x1, x2, |a, b| truthy(a) $op truthy(b))
+        }
+    };
+}
+
+logical!(
+    /// Computes the truth value of `x1 AND x2` elementwise after broadcasting, following
+    /// `numpy.logical_and`. Elements are true if they are not equal to `A::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use numrust::logic::logical_and;
+    ///
+    /// let result = logical_and(&array![true, true, false], &array![true, false, false]).unwrap();
+    /// assert_eq!(result, array![true, false, false].into_dyn());
+    /// assert_eq!(logical_and(&array![2, 0], &array![1, 1]).unwrap(), array![true, false].into_dyn());
+    /// ```
+    logical_and, &
+);
+
+logical!(
+    /// Computes the truth value of `x1 OR x2` elementwise after broadcasting, following
+    /// `numpy.logical_or`. Elements are true if they are not equal to `A::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use numrust::logic::logical_or;
+    ///
+    /// let result = logical_or(&array![true, true, false], &array![true, false, false]).unwrap();
+    /// assert_eq!(result, array![true, true, false].into_dyn());
+    /// ```
+    logical_or, |
+);
+
+logical!(
+    /// Computes the truth value of `x1 XOR x2` elementwise after broadcasting, following
+    /// `numpy.logical_xor`. Elements are true if they are not equal to `A::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use numrust::logic::logical_xor;
+    ///
+    /// let result = logical_xor(&array![true, true, false], &array![true, false, false]).unwrap();
+    /// assert_eq!(result, array![false, true, false].into_dyn());
+    /// ```
+    logical_xor, ^
+);
+
+/// Computes the truth value of `NOT x` elementwise, following `numpy.logical_not`. Elements are
+/// true if they are not equal to `A::default()`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::logic::logical_not;
+///
+/// assert_eq!(logical_not(&array![true, false]), array![false, true]);
+/// assert_eq!(logical_not(&array![0.0, -1.5, f64::NAN]), array![true, false, false]);
+/// ```
+pub fn logical_not<A, S, D>(x: &ArrayBase<S, D>) -> Array<bool, D>
+where
+    A: Default + PartialEq,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    x.map(|a| !truthy(a))
+}
+
+/// Reduces `a` along `axis`, or over all elements if `axis` is `None`, with `f` applied to the
+/// elements of each lane.
+fn reduce<A, B, S, D, F>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+    f: F,
+) -> Result<NdArray<B>, NumrustError>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    F: Fn(ArrayViewD<'_, A>) -> B,
+{
+    match axis {
+        None => Ok(arr0(f(a.view().into_dyn())).into_dyn()),
+        Some(axis) => {
+            let axis = Axis(normalize_axis(axis, a.ndim())?);
+            Ok(a.view()
+                .into_dyn()
+                .map_axis(axis, |lane| f(lane.into_dyn())))
+        }
+    }
+}
+
+/// Tests whether any element along `axis` is true, or any element at all if `axis` is `None`,
+/// following `numpy.any`. Elements are true if they are not equal to `A::default()`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{arr0, array};
+/// use numrust::logic::any;
+///
+/// let a = array![[true, false], [false, false]];
+/// assert_eq!(any(&a, None).unwrap(), arr0(true).into_dyn());
+/// assert_eq!(any(&a, Some(0)).unwrap(), array![true, false].into_dyn());
+/// assert_eq!(any(&a, Some(-1)).unwrap(), array![true, false].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn any<A, S, D>(a: &ArrayBase<S, D>, axis: Option<isize>) -> Result<NdArray<bool>, NumrustError>
+where
+    A: Default + PartialEq,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    reduce(a, axis, |lane| lane.iter().any(truthy))
+}
+
+/// Tests whether all elements along `axis` are true, or all elements if `axis` is `None`,
+/// following `numpy.all`. Elements are true if they are not equal to `A::default()`, and an empty
+/// lane is all true.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{arr0, array};
+/// use numrust::logic::all;
+///
+/// let a = array![[1, 0], [3, 4]];
+/// assert_eq!(all(&a, None).unwrap(), arr0(false).into_dyn());
+/// assert_eq!(all(&a, Some(0)).unwrap(), array![true, false].into_dyn());
+/// assert_eq!(all(&a, Some(1)).unwrap(), array![false, true].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn all<A, S, D>(a: &ArrayBase<S, D>, axis: Option<isize>) -> Result<NdArray<bool>, NumrustError>
+where
+    A: Default + PartialEq,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    reduce(a, axis, |lane| lane.iter().all(truthy))
+}
+
+/// Counts the elements not equal to `A::default()` along `axis`, or in the whole array if `axis`
+/// is `None`, following `numpy.count_nonzero`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{arr0, array};
+/// use numrust::logic::count_nonzero;
+///
+/// let a = array![[0, 1, 7, 0], [3, 0, 2, 19]];
+/// assert_eq!(count_nonzero(&a, None).unwrap(), arr0(5).into_dyn());
+/// assert_eq!(count_nonzero(&a, Some(0)).unwrap(), array![1, 1, 2, 1].into_dyn());
+/// assert_eq!(count_nonzero(&a, Some(1)).unwrap(), array![2, 3].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn count_nonzero<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+) -> Result<NdArray<usize>, NumrustError>
+where
+    A: Default + PartialEq,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    reduce(a, axis, |lane| lane.iter().filter(|x| truthy(*x)).count())
+}
+
+/// Tests elementwise for NaN, following `numpy.isnan`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::indexing::mask_select;
+/// use numrust::logic::{isnan, logical_not};
+/// use numrust::mean;
+///
+/// let a = array![1.0, f64::NAN, 3.0];
+/// assert_eq!(isnan(&a), array![false, true, false]);
+/// let present = mask_select(&a, &logical_not(&isnan(&a))).unwrap();
+/// assert_eq!(mean(&present.to_vec()), 2.0);
+/// ```
+pub fn isnan<A, S, D>(x: &ArrayBase<S, D>) -> Array<bool, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    x.map(|a| a.is_nan())
+}
+
+/// Tests elementwise for positive or negative infinity, following `numpy.isinf`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::logic::isinf;
+///
+/// let a = array![f64::NEG_INFINITY, 0.0, f64::NAN, f64::INFINITY];
+/// assert_eq!(isinf(&a), array![true, false, false, true]);
+/// ```
+pub fn isinf<A, S, D>(x: &ArrayBase<S, D>) -> Array<bool, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    x.map(|a| a.is_infinite())
+}
+
+/// Tests elementwise for values that are neither infinite nor NaN, following `numpy.isfinite`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::logic::isfinite;
+///
+/// let a = array![1.0f32, f32::INFINITY, f32::NAN];
+/// assert_eq!(isfinite(&a), array![true, false, false]);
+/// ```
+pub fn isfinite<A, S, D>(x: &ArrayBase<S, D>) -> Array<bool, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    x.map(|a| a.is_finite())
+}
+
+#[cfg(test)]
+mod numrust_logic_tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+    use ndarray::{arr0, array};
+
+    #[test]
+    fn test_isclose_matches_numpy_edge_cases() {
+        let a = array![f64::INFINITY, f64::INFINITY, 0.0, 1e-300, 1.0];
+        let b = array![f64::INFINITY, f64::NEG_INFINITY, 1e-9, 0.0, f64::INFINITY];
+        assert_eq!(
+            isclose(&a, &b, 1e-5, 1e-8, false).unwrap(),
+            array![true, false, true, true, false].into_dyn()
+        );
+        // The reference value is `b`, so the test is asymmetric.
+        let x = arr0(10.0);
+        let y = arr0(11.0);
+        assert!(allclose(&x, &y, 0.095, 0.0, false).unwrap());
+        assert!(!allclose(&y, &x, 0.095, 0.0, false).unwrap());
+        assert!(isclose(&array![1, 2, 3], &array![1, 2], 0.0, 0.0, false).is_err());
+        assert_eq!(
+            isclose(
+                &array![[1.0f32], [2.0]],
+                &array![1.0f32, 2.0],
+                0.0,
+                0.0,
+                false
+            )
+            .unwrap()
+            .shape(),
+            &[2, 2]
+        );
+    }
+
+    #[test]
+    fn test_array_equal_and_equiv() {
+        assert!(array_equal(
+            &array![[1, 2]],
+            &array![[1, 2]].into_dyn(),
+            false
+        ));
+        assert!(!array_equal(&array![1, 2], &array![1, 3], true));
+        assert!(array_equiv(&arr0(1), &array![[1, 1], [1, 1]]));
+        assert!(!array_equiv(&array![f64::NAN], &array![f64::NAN]));
+    }
+
+    #[test]
+    fn test_approx_traits_on_arrays() {
+        let a = array![[1.0, 2.0], [3.0, 4.0]];
+        let b = &a + 1e-12;
+        assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+        assert_relative_eq!(a, b, max_relative = 1e-9);
+        assert_relative_ne!(a, &a * 1.001, max_relative = 1e-6);
+        assert_ulps_eq!(array![0.1 + 0.2], array![0.3], max_ulps = 1);
+    }
+
+    #[test]
+    fn test_comparisons_broadcast() {
+        let a = array![[1.0, 2.0, 3.0]];
+        let b = array![[2.0], [f64::NAN]];
+        assert_eq!(
+            less_equal(&a, &b).unwrap(),
+            array![[true, true, false], [false, false, false]].into_dyn()
+        );
+        assert_eq!(
+            not_equal(&a, &b).unwrap(),
+            array![[true, false, true], [true, true, true]].into_dyn()
+        );
+        assert!(greater(&array![1, 2], &array![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_reductions_on_empty_and_bad_axes() {
+        let empty = ndarray::Array2::<bool>::from_elem((0, 3), true);
+        assert_eq!(
+            any(&empty, Some(0)).unwrap(),
+            array![false, false, false].into_dyn()
+        );
+        assert_eq!(
+            all(&empty, Some(0)).unwrap(),
+            array![true, true, true].into_dyn()
+        );
+        assert_eq!(count_nonzero(&empty, Some(1)).unwrap().shape(), &[0]);
+        assert_eq!(all(&empty, None).unwrap(), arr0(true).into_dyn());
+        assert!(any(&empty, Some(2)).is_err());
+        assert_eq!(
+            count_nonzero(&array![0.0, f64::NAN, -0.0], None).unwrap(),
+            arr0(1).into_dyn()
+        );
+    }
+}
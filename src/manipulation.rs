@@ -0,0 +1,1164 @@
+use ndarray::{
+    Array1, ArrayBase, ArrayView1, ArrayViewD, Axis, CowArray, Data, Dimension, Ix1, IxDyn,
+    ShapeBuilder, Slice,
+};
+use num_traits::NumCast;
+
+use crate::{normalize_axis, NdArray, NumrustError};
+
+/// The order in which elements are read and written when changing the shape of an array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Row-major order: the last index changes fastest.
+    C,
+    /// Column-major order: the first index changes fastest.
+    F,
+}
+
+/// How [`split`] and [`array_split`] divide an array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sections<'a> {
+    /// This many sub-arrays of (nearly) equal length.
+    Count(usize),
+    /// The indices along the axis at which to split.
+    Indices(&'a [usize]),
+}
+
+/// The values [`pad`] fills the new elements with, mirroring the `mode` argument of `numpy.pad`.
+///
+/// The statistic modes take an optional `stat_length`, the number of values at each edge used to
+/// compute the statistic; `None` uses the whole axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<A> {
+    /// A constant value.
+    Constant(A),
+    /// The edge values of the array.
+    Edge,
+    /// A linear ramp from the edge value to the given end value.
+    LinearRamp(A),
+    /// The maximum of the values at each edge.
+    Maximum(Option<usize>),
+    /// The mean of the values at each edge, rounded for integer types.
+    Mean(Option<usize>),
+    /// The median of the values at each edge, rounded for integer types.
+    Median(Option<usize>),
+    /// The minimum of the values at each edge.
+    Minimum(Option<usize>),
+    /// The reflection of the array about its edge values, which are not repeated.
+    Reflect,
+    /// The reflection of the array about its edges, repeating the edge values.
+    Symmetric,
+    /// The array repeated periodically.
+    Wrap,
+}
+
+fn collect_order<A: Clone, S: Data<Elem = A>, D: Dimension>(
+    a: &ArrayBase<S, D>,
+    order: Order,
+) -> Vec<A> {
+    match order {
+        Order::C => a.iter().cloned().collect(),
+        Order::F => a.view().reversed_axes().iter().cloned().collect(),
+    }
+}
+
+/// Gives a new shape to an array without changing its data.
+///
+/// # Arguments
+///
+/// * `a` - The array to reshape.
+/// * `shape` - The new shape. At most one dimension may be `-1`, in which case its length is
+///   inferred from the size of the array.
+/// * `order` - Whether elements are read from `a` and placed into the result in C or F order.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::{reshape, Order};
+///
+/// let a = array![[1, 2, 3], [4, 5, 6]];
+/// assert_eq!(reshape(&a, &[3, -1], Order::C).unwrap(), array![[1, 2], [3, 4], [5, 6]].into_dyn());
+/// assert_eq!(reshape(&a, &[3, 2], Order::F).unwrap(), array![[1, 5], [4, 3], [2, 6]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if more than one dimension is `-1`, a dimension is
+/// negative, or the new shape does not hold the same number of elements.
+pub fn reshape<A, S, D>(
+    a: &ArrayBase<S, D>,
+    shape: &[isize],
+    order: Order,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let shape = infer_shape(shape, a.len())?;
+    let values = collect_order(a, order);
+    let result = match order {
+        Order::C => NdArray::from_shape_vec(IxDyn(&shape), values),
+        Order::F => NdArray::from_shape_vec(IxDyn(&shape).f(), values),
+    };
+    Ok(result.expect("the inferred shape holds every element"))
+}
+
+fn infer_shape(shape: &[isize], size: usize) -> Result<Vec<usize>, NumrustError> {
+    let unknown: Vec<usize> = (0..shape.len()).filter(|&i| shape[i] == -1).collect();
+    if unknown.len() > 1 {
+        return Err(NumrustError::ValueError(
+            "can only specify one unknown dimension".to_string(),
+        ));
+    }
+    if shape.iter().any(|&d| d < -1) {
+        return Err(NumrustError::ValueError(
+            "negative dimensions not allowed".to_string(),
+        ));
+    }
+    let known: usize = shape
+        .iter()
+        .filter(|&&d| d >= 0)
+        .map(|&d| d as usize)
+        .product();
+    let mut result: Vec<usize> = shape.iter().map(|&d| d.max(0) as usize).collect();
+    if let Some(&i) = unknown.first() {
+        if known == 0 || !size.is_multiple_of(known) {
+            return Err(NumrustError::ValueError(format!(
+                "cannot reshape array of size {} into shape {:?}",
+                size, shape
+            )));
+        }
+        result[i] = size / known;
+    } else if known != size {
+        return Err(NumrustError::ValueError(format!(
+            "cannot reshape array of size {} into shape {:?}",
+            size, shape
+        )));
+    }
+    Ok(result)
+}
+
+/// Returns the elements of `a` as a one-dimensional array, borrowing them when `a` is already
+/// contiguous in the requested order and copying them otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::{ravel, Order};
+///
+/// let a = array![[1, 2], [3, 4]];
+/// let flat = ravel(&a, Order::C);
+/// assert!(flat.is_view());
+/// assert_eq!(flat, array![1, 2, 3, 4]);
+/// assert_eq!(ravel(&a, Order::F), array![1, 3, 2, 4]);
+/// ```
+pub fn ravel<A, S, D>(a: &ArrayBase<S, D>, order: Order) -> CowArray<'_, A, Ix1>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let view = match order {
+        Order::C => a.view(),
+        Order::F => a.view().reversed_axes(),
+    };
+    if view.is_standard_layout() {
+        if let Ok(flat) = view.into_shape(a.len()) {
+            return CowArray::from(flat);
+        }
+    }
+    CowArray::from(flatten(a, order))
+}
+
+/// Returns a copy of `a` collapsed into one dimension.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::{flatten, Order};
+///
+/// let a = array![[1, 2], [3, 4]];
+/// assert_eq!(flatten(&a, Order::C), array![1, 2, 3, 4]);
+/// ```
+pub fn flatten<A, S, D>(a: &ArrayBase<S, D>, order: Order) -> Array1<A>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    Array1::from(collect_order(a, order))
+}
+
+/// Removes axes of length one from `a`.
+///
+/// # Arguments
+///
+/// * `a` - The input array.
+/// * `axis` - The axes to remove, or `None` to remove every axis of length one.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::Array3;
+/// use numrust::manipulation::squeeze;
+///
+/// let a = Array3::<f64>::zeros((1, 3, 1));
+/// assert_eq!(squeeze(&a, None).unwrap().shape(), &[3]);
+/// assert_eq!(squeeze(&a, Some(&[-1])).unwrap().shape(), &[1, 3]);
+/// assert!(squeeze(&a, Some(&[1])).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if an axis is out of bounds or does not have length one.
+pub fn squeeze<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<&[isize]>,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut axes: Vec<usize> = match axis {
+        Some(axes) => {
+            let mut resolved = Vec::with_capacity(axes.len());
+            for &ax in axes {
+                let ax = normalize_axis(ax, a.ndim())?;
+                if a.len_of(Axis(ax)) != 1 {
+                    return Err(NumrustError::ValueError(
+                        "cannot select an axis to squeeze out which has size not equal to one"
+                            .to_string(),
+                    ));
+                }
+                resolved.push(ax);
+            }
+            resolved
+        }
+        None => (0..a.ndim())
+            .filter(|&ax| a.len_of(Axis(ax)) == 1)
+            .collect(),
+    };
+    axes.sort_unstable();
+    axes.dedup();
+    let mut view = a.view().into_dyn();
+    for &ax in axes.iter().rev() {
+        view = view.index_axis_move(Axis(ax), 0);
+    }
+    Ok(view.to_owned())
+}
+
+/// Inserts new axes of length one into `a`.
+///
+/// # Arguments
+///
+/// * `a` - The input array.
+/// * `axis` - The positions of the new axes in the result. Negative values count from the last
+///   axis of the result.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::expand_dims;
+///
+/// let a = array![1, 2];
+/// assert_eq!(expand_dims(&a, &[0]).unwrap().shape(), &[1, 2]);
+/// assert_eq!(expand_dims(&a, &[0, -1]).unwrap().shape(), &[1, 2, 1]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if an axis is out of bounds or repeated.
+pub fn expand_dims<A, S, D>(a: &ArrayBase<S, D>, axis: &[isize]) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let ndim = a.ndim() + axis.len();
+    let mut axes = Vec::with_capacity(axis.len());
+    for &ax in axis {
+        axes.push(normalize_axis(ax, ndim)?);
+    }
+    axes.sort_unstable();
+    if axes.windows(2).any(|w| w[0] == w[1]) {
+        return Err(NumrustError::ValueError("repeated axis".to_string()));
+    }
+    let mut view = a.view().into_dyn();
+    for ax in axes {
+        view = view.insert_axis(Axis(ax));
+    }
+    Ok(view.to_owned())
+}
+
+/// Joins arrays along an existing axis.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::concatenate;
+///
+/// let a = array![[1, 2], [3, 4]];
+/// let b = array![[5, 6]];
+/// assert_eq!(concatenate(&[&a, &b], 0).unwrap(), array![[1, 2], [3, 4], [5, 6]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if no arrays are given, `axis` is out of bounds, or the arrays
+/// differ in shape except along `axis`.
+pub fn concatenate<A, S, D>(
+    arrays: &[&ArrayBase<S, D>],
+    axis: isize,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let views: Vec<ArrayViewD<A>> = arrays.iter().map(|a| a.view().into_dyn()).collect();
+    concatenate_views(&views, axis)
+}
+
+fn concatenate_views<A: Clone>(
+    views: &[ArrayViewD<A>],
+    axis: isize,
+) -> Result<NdArray<A>, NumrustError> {
+    let first = views.first().ok_or_else(|| {
+        NumrustError::ValueError("need at least one array to concatenate".to_string())
+    })?;
+    let axis = normalize_axis(axis, first.ndim())?;
+    ndarray::concatenate(Axis(axis), views).map_err(|_| {
+        NumrustError::ValueError(
+            "all the input array dimensions except for the concatenation axis must match exactly"
+                .to_string(),
+        )
+    })
+}
+
+/// Joins arrays of the same shape along a new axis.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::stack;
+///
+/// let a = array![1, 2];
+/// let b = array![3, 4];
+/// assert_eq!(stack(&[&a, &b], -1).unwrap(), array![[1, 3], [2, 4]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if no arrays are given, `axis` is out of bounds, or the arrays have
+/// different shapes.
+pub fn stack<A, S, D>(arrays: &[&ArrayBase<S, D>], axis: isize) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let first = arrays
+        .first()
+        .ok_or_else(|| NumrustError::ValueError("need at least one array to stack".to_string()))?;
+    let axis = normalize_axis(axis, first.ndim() + 1)?;
+    if arrays.iter().any(|a| a.shape() != first.shape()) {
+        return Err(NumrustError::ValueError(
+            "all input arrays must have the same shape".to_string(),
+        ));
+    }
+    let views: Vec<ArrayViewD<A>> = arrays
+        .iter()
+        .map(|a| a.view().into_dyn().insert_axis(Axis(axis)))
+        .collect();
+    concatenate_views(&views, axis as isize)
+}
+
+/// Views `a` with at least `ndim` dimensions, placing the existing axes as NumPy's
+/// `atleast_2d` and `atleast_3d` do.
+fn at_least<'a, A>(a: ArrayViewD<'a, A>, ndim: usize) -> ArrayViewD<'a, A> {
+    match (a.ndim(), ndim) {
+        (0, _) => (0..ndim).fold(a, |v, _| v.insert_axis(Axis(0))),
+        (1, 2) => a.insert_axis(Axis(0)),
+        (1, 3) => a.insert_axis(Axis(0)).insert_axis(Axis(2)),
+        (2, 3) => a.insert_axis(Axis(2)),
+        _ => a,
+    }
+}
+
+/// Joins arrays horizontally: along the first axis for one-dimensional arrays and along the
+/// second axis otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::hstack;
+///
+/// assert_eq!(hstack(&[&array![1, 2], &array![3]]).unwrap(), array![1, 2, 3].into_dyn());
+/// assert_eq!(
+///     hstack(&[&array![[1], [2]], &array![[3], [4]]]).unwrap(),
+///     array![[1, 3], [2, 4]].into_dyn()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if no arrays are given or their shapes are incompatible.
+pub fn hstack<A, S, D>(arrays: &[&ArrayBase<S, D>]) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let views: Vec<ArrayViewD<A>> = arrays
+        .iter()
+        .map(|a| at_least(a.view().into_dyn(), 1))
+        .collect();
+    let axis = if views.first().is_some_and(|v| v.ndim() == 1) {
+        0
+    } else {
+        1
+    };
+    concatenate_views(&views, axis)
+}
+
+/// Joins arrays vertically, after viewing one-dimensional arrays as single rows.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::vstack;
+///
+/// assert_eq!(vstack(&[&array![1, 2], &array![3, 4]]).unwrap(), array![[1, 2], [3, 4]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if no arrays are given or their shapes are incompatible.
+pub fn vstack<A, S, D>(arrays: &[&ArrayBase<S, D>]) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let views: Vec<ArrayViewD<A>> = arrays
+        .iter()
+        .map(|a| at_least(a.view().into_dyn(), 2))
+        .collect();
+    concatenate_views(&views, 0)
+}
+
+/// Joins arrays depth-wise along the third axis, after viewing one-dimensional arrays of length
+/// `N` as `(1, N, 1)` and two-dimensional arrays of shape `(M, N)` as `(M, N, 1)`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::dstack;
+///
+/// let stacked = dstack(&[&array![1, 2], &array![3, 4]]).unwrap();
+/// assert_eq!(stacked, array![[[1, 3], [2, 4]]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if no arrays are given or their shapes are incompatible.
+pub fn dstack<A, S, D>(arrays: &[&ArrayBase<S, D>]) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let views: Vec<ArrayViewD<A>> = arrays
+        .iter()
+        .map(|a| at_least(a.view().into_dyn(), 3))
+        .collect();
+    concatenate_views(&views, 2)
+}
+
+/// Splits `a` into equal sub-arrays along `axis`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::{split, Sections};
+///
+/// let a = array![0, 1, 2, 3, 4, 5];
+/// let parts = split(&a, Sections::Indices(&[2, 5]), 0).unwrap();
+/// assert_eq!(parts[1], array![2, 3, 4].into_dyn());
+/// assert!(split(&a, Sections::Count(4), 0).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds or if `Sections::Count` does not divide
+/// the axis into equal parts.
+pub fn split<A, S, D>(
+    a: &ArrayBase<S, D>,
+    sections: Sections,
+    axis: isize,
+) -> Result<Vec<NdArray<A>>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    if let Sections::Count(count) = sections {
+        let len = a.len_of(Axis(normalize_axis(axis, a.ndim())?));
+        if count == 0 || !len.is_multiple_of(count) {
+            return Err(NumrustError::ValueError(
+                "array split does not result in an equal division".to_string(),
+            ));
+        }
+    }
+    array_split(a, sections, axis)
+}
+
+/// Splits `a` into sub-arrays along `axis`, allowing a count that does not divide the axis
+/// evenly: the first `len % count` parts get one extra element.
+///
+/// Indices beyond the end of the axis give empty sub-arrays.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::{array_split, Sections};
+///
+/// let parts = array_split(&array![0, 1, 2, 3, 4], Sections::Count(3), 0).unwrap();
+/// let lengths: Vec<usize> = parts.iter().map(|p| p.len()).collect();
+/// assert_eq!(lengths, vec![2, 2, 1]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds or the count is zero.
+pub fn array_split<A, S, D>(
+    a: &ArrayBase<S, D>,
+    sections: Sections,
+    axis: isize,
+) -> Result<Vec<NdArray<A>>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let axis = Axis(normalize_axis(axis, a.ndim())?);
+    let len = a.len_of(axis);
+    let bounds: Vec<usize> = match sections {
+        Sections::Count(0) => {
+            return Err(NumrustError::ValueError(
+                "number sections must be larger than 0.".to_string(),
+            ))
+        }
+        Sections::Count(count) => {
+            let (size, extra) = (len / count, len % count);
+            let mut bounds = vec![0];
+            for i in 0..count {
+                bounds.push(bounds[i] + size + (i < extra) as usize);
+            }
+            bounds
+        }
+        Sections::Indices(indices) => {
+            let mut bounds = vec![0];
+            bounds.extend(indices.iter().map(|&i| i.min(len)));
+            bounds.push(len);
+            bounds
+        }
+    };
+    let view = a.view().into_dyn();
+    Ok(bounds
+        .windows(2)
+        .map(|w| {
+            view.slice_axis(axis, Slice::from(w[0]..w[1].max(w[0])))
+                .to_owned()
+        })
+        .collect())
+}
+
+/// Constructs an array by repeating `a` the number of times given by `reps` along each axis.
+///
+/// If `reps` has more entries than `a` has dimensions, `a` is promoted by prepending axes of
+/// length one; if it has fewer, `reps` is padded with leading ones.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::tile;
+///
+/// let a = array![0, 1];
+/// assert_eq!(tile(&a, &[2]), array![0, 1, 0, 1].into_dyn());
+/// assert_eq!(tile(&a, &[2, 2]), array![[0, 1, 0, 1], [0, 1, 0, 1]].into_dyn());
+/// ```
+pub fn tile<A, S, D>(a: &ArrayBase<S, D>, reps: &[usize]) -> NdArray<A>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let ndim = a.ndim().max(reps.len());
+    let offset = ndim - a.ndim();
+    let mut shape = vec![1; offset];
+    shape.extend_from_slice(a.shape());
+    let mut full_reps = vec![1; ndim - reps.len()];
+    full_reps.extend_from_slice(reps);
+    let out_shape: Vec<usize> = shape.iter().zip(&full_reps).map(|(s, r)| s * r).collect();
+
+    let view = a.view().into_dyn();
+    NdArray::from_shape_fn(IxDyn(&out_shape), |index| {
+        let source: Vec<usize> = (offset..ndim).map(|d| index[d] % shape[d]).collect();
+        view[IxDyn(&source)].clone()
+    })
+}
+
+/// Repeats each element of `a`.
+///
+/// # Arguments
+///
+/// * `a` - The input array.
+/// * `repeats` - The number of repetitions, either one for every element or one per element
+///   along `axis`.
+/// * `axis` - The axis along which to repeat, or `None` to repeat the flattened array.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::repeat;
+///
+/// let a = array![[1, 2], [3, 4]];
+/// assert_eq!(repeat(&a, &[2], None).unwrap(), array![1, 1, 2, 2, 3, 3, 4, 4].into_dyn());
+/// assert_eq!(repeat(&a, &[1, 2], Some(0)).unwrap(), array![[1, 2], [3, 4], [3, 4]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` is out of bounds or `repeats` does not match the length of
+/// the axis.
+pub fn repeat<A, S, D>(
+    a: &ArrayBase<S, D>,
+    repeats: &[usize],
+    axis: Option<isize>,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let (source, axis) = match axis {
+        Some(ax) => (
+            a.view().into_dyn().to_owned(),
+            normalize_axis(ax, a.ndim())?,
+        ),
+        None => (flatten(a, Order::C).into_dyn(), 0),
+    };
+    let len = source.len_of(Axis(axis));
+    if repeats.len() != 1 && repeats.len() != len {
+        return Err(NumrustError::ValueError(format!(
+            "operands could not be broadcast together with shape ({},) ({},)",
+            len,
+            repeats.len()
+        )));
+    }
+    let indices: Vec<usize> = (0..len)
+        .flat_map(|i| std::iter::repeat_n(i, repeats[if repeats.len() == 1 { 0 } else { i }]))
+        .collect();
+    Ok(source.select(Axis(axis), &indices))
+}
+
+/// Reverses the order of elements along the given axes.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::flip;
+///
+/// let a = array![[1, 2], [3, 4]];
+/// assert_eq!(flip(&a, Some(&[0])).unwrap(), array![[3, 4], [1, 2]].into_dyn());
+/// assert_eq!(flip(&a, None).unwrap(), array![[4, 3], [2, 1]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if an axis is out of bounds.
+pub fn flip<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<&[isize]>,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut view = a.view().into_dyn();
+    match axis {
+        Some(axes) => {
+            for &ax in axes {
+                view.invert_axis(Axis(normalize_axis(ax, a.ndim())?));
+            }
+        }
+        None => {
+            for ax in 0..a.ndim() {
+                view.invert_axis(Axis(ax));
+            }
+        }
+    }
+    Ok(view.to_owned())
+}
+
+/// Rolls the elements of `a` by `shift` positions along `axis`, so that elements shifted beyond
+/// the last position reappear at the first.
+///
+/// # Arguments
+///
+/// * `a` - The input array.
+/// * `shift` - The number of positions to shift by. Negative values shift backwards.
+/// * `axis` - The axis to roll along, or `None` to roll the flattened array and restore the shape.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::roll;
+///
+/// let a = array![[0, 1, 2], [3, 4, 5]];
+/// assert_eq!(roll(&a, 1, None).unwrap(), array![[5, 0, 1], [2, 3, 4]].into_dyn());
+/// assert_eq!(roll(&a, -1, Some(1)).unwrap(), array![[1, 2, 0], [4, 5, 3]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn roll<A, S, D>(
+    a: &ArrayBase<S, D>,
+    shift: isize,
+    axis: Option<isize>,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    match axis {
+        Some(ax) => {
+            let ax = Axis(normalize_axis(ax, a.ndim())?);
+            let mut result = a.view().into_dyn().to_owned();
+            for mut lane in result.lanes_mut(ax) {
+                let mut values: Vec<A> = lane.iter().cloned().collect();
+                if !values.is_empty() {
+                    let k = shift.rem_euclid(values.len() as isize) as usize;
+                    values.rotate_right(k);
+                }
+                for (dst, v) in lane.iter_mut().zip(values) {
+                    *dst = v;
+                }
+            }
+            Ok(result)
+        }
+        None => {
+            let rolled = roll(&flatten(a, Order::C), shift, Some(0))?;
+            Ok(NdArray::from_shape_vec(a.shape(), rolled.into_raw_vec())
+                .expect("rolling keeps the number of elements"))
+        }
+    }
+}
+
+/// Rotates `a` by 90 degrees `k` times in the plane given by `axes`, from the first axis
+/// towards the second.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::rot90;
+///
+/// let a = array![[1, 2], [3, 4]];
+/// assert_eq!(rot90(&a, 1, (0, 1)).unwrap(), array![[2, 4], [1, 3]].into_dyn());
+/// assert_eq!(rot90(&a, -1, (0, 1)).unwrap(), array![[3, 1], [4, 2]].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if the array has fewer than two dimensions, the axes are out of
+/// bounds, or they refer to the same axis.
+pub fn rot90<A, S, D>(
+    a: &ArrayBase<S, D>,
+    k: isize,
+    axes: (isize, isize),
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let first = normalize_axis(axes.0, a.ndim())?;
+    let second = normalize_axis(axes.1, a.ndim())?;
+    if first == second {
+        return Err(NumrustError::ValueError(
+            "Axes must be different.".to_string(),
+        ));
+    }
+    let mut view: ArrayViewD<A> = a.view().into_dyn();
+    match k.rem_euclid(4) {
+        0 => {}
+        1 => {
+            view.invert_axis(Axis(second));
+            view.swap_axes(first, second);
+        }
+        2 => {
+            view.invert_axis(Axis(first));
+            view.invert_axis(Axis(second));
+        }
+        _ => {
+            view.swap_axes(first, second);
+            view.invert_axis(Axis(second));
+        }
+    }
+    Ok(view.to_owned())
+}
+
+/// Pads an array.
+///
+/// # Arguments
+///
+/// * `a` - The array to pad.
+/// * `pad_width` - The number of values added `(before, after)` each axis, either one pair for
+///   every axis or one per axis.
+/// * `mode` - The values to pad with.
+///
+/// Axes are padded in order, so the padding of later axes covers the corners added by earlier
+/// ones, as in `numpy.pad`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::manipulation::{pad, PadMode};
+///
+/// let a = array![1, 2, 3, 4, 5];
+/// assert_eq!(pad(&a, &[(2, 3)], PadMode::Constant(0)).unwrap(), array![0, 0, 1, 2, 3, 4, 5, 0, 0, 0].into_dyn());
+/// assert_eq!(pad(&a, &[(2, 3)], PadMode::Reflect).unwrap(), array![3, 2, 1, 2, 3, 4, 5, 4, 3, 2].into_dyn());
+/// assert_eq!(pad(&a, &[(2, 3)], PadMode::Symmetric).unwrap(), array![2, 1, 1, 2, 3, 4, 5, 5, 4, 3].into_dyn());
+/// assert_eq!(pad(&a, &[(2, 3)], PadMode::Wrap).unwrap(), array![4, 5, 1, 2, 3, 4, 5, 1, 2, 3].into_dyn());
+/// assert_eq!(pad(&a, &[(2, 3)], PadMode::LinearRamp(7)).unwrap(), array![7, 4, 1, 2, 3, 4, 5, 5, 6, 7].into_dyn());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `pad_width` does not match the number of dimensions
+/// or if a non-constant mode has to extend an empty axis.
+pub fn pad<A, S, D>(
+    a: &ArrayBase<S, D>,
+    pad_width: &[(usize, usize)],
+    mode: PadMode<A>,
+) -> Result<NdArray<A>, NumrustError>
+where
+    A: Copy + PartialOrd + NumCast,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    if pad_width.len() != 1 && pad_width.len() != a.ndim() {
+        return Err(NumrustError::ValueError(format!(
+            "pad_width has {} entries but the array has {} dimensions",
+            pad_width.len(),
+            a.ndim()
+        )));
+    }
+    let mut result = a.view().into_dyn().to_owned();
+    for ax in 0..a.ndim() {
+        let (before, after) = pad_width[if pad_width.len() == 1 { 0 } else { ax }];
+        let mut shape = result.shape().to_vec();
+        shape[ax] += before + after;
+        let fill = match mode {
+            PadMode::Constant(value) | PadMode::LinearRamp(value) => value,
+            _ => match result.first() {
+                Some(&value) => value,
+                None if shape.iter().product::<usize>() == 0 => {
+                    result = NdArray::from_shape_vec(IxDyn(&shape), Vec::new())
+                        .expect("an empty shape holds no elements");
+                    continue;
+                }
+                None => {
+                    return Err(NumrustError::ValueError(
+                        "can't extend empty axis using modes other than 'constant'".to_string(),
+                    ))
+                }
+            },
+        };
+        let mut padded = NdArray::from_elem(IxDyn(&shape), fill);
+        for (lane, mut out) in result
+            .lanes(Axis(ax))
+            .into_iter()
+            .zip(padded.lanes_mut(Axis(ax)))
+        {
+            let values = pad_lane(lane, before, after, &mode)?;
+            for (dst, v) in out.iter_mut().zip(values) {
+                *dst = v;
+            }
+        }
+        result = padded;
+    }
+    Ok(result)
+}
+
+/// Converts `value` to `A`, applying `round` first if `A` is an integer type. NumPy rounds half
+/// to even when storing a statistic into an integer array and floors the values of a ramp.
+fn cast_like<A: NumCast>(value: f64, round: fn(f64) -> f64) -> A {
+    let is_integer = A::from(0.5).and_then(|half| half.to_f64()) != Some(0.5);
+    let value = if is_integer { round(value) } else { value };
+    A::from(value).expect("a statistic of the values fits in their type")
+}
+
+fn pad_lane<A>(
+    lane: ArrayView1<A>,
+    before: usize,
+    after: usize,
+    mode: &PadMode<A>,
+) -> Result<Vec<A>, NumrustError>
+where
+    A: Copy + PartialOrd + NumCast,
+{
+    let values: Vec<A> = lane.iter().copied().collect();
+    let n = values.len();
+    if let PadMode::Constant(value) = *mode {
+        let mut out = vec![value; before];
+        out.extend_from_slice(&values);
+        out.extend(std::iter::repeat_n(value, after));
+        return Ok(out);
+    }
+    if n == 0 {
+        if before + after == 0 {
+            return Ok(values);
+        }
+        return Err(NumrustError::ValueError(
+            "can't extend empty axis using modes other than 'constant'".to_string(),
+        ));
+    }
+
+    let to_f64 = |x: A| x.to_f64().expect("padded values convert to f64");
+    let stat = |edge: &[A], mode: &PadMode<A>| -> A {
+        match *mode {
+            PadMode::Maximum(_) => edge
+                .iter()
+                .copied()
+                .fold(edge[0], |m, x| if x > m { x } else { m }),
+            PadMode::Minimum(_) => edge
+                .iter()
+                .copied()
+                .fold(edge[0], |m, x| if x < m { x } else { m }),
+            PadMode::Mean(_) => cast_like(
+                edge.iter().map(|&x| to_f64(x)).sum::<f64>() / edge.len() as f64,
+                f64::round_ties_even,
+            ),
+            _ => {
+                let mut sorted: Vec<f64> = edge.iter().map(|&x| to_f64(x)).collect();
+                sorted.sort_by(crate::sorting::nan_last_cmp);
+                let mid = sorted.len() / 2;
+                if sorted.len().is_multiple_of(2) {
+                    cast_like((sorted[mid - 1] + sorted[mid]) / 2.0, f64::round_ties_even)
+                } else {
+                    cast_like(sorted[mid], f64::round_ties_even)
+                }
+            }
+        }
+    };
+
+    let mut out = Vec::with_capacity(before + n + after);
+    match *mode {
+        PadMode::Maximum(len)
+        | PadMode::Minimum(len)
+        | PadMode::Mean(len)
+        | PadMode::Median(len) => {
+            let len = len.unwrap_or(n).clamp(1, n);
+            let head = stat(&values[..len], mode);
+            let tail = stat(&values[n - len..], mode);
+            out.extend(std::iter::repeat_n(head, before));
+            out.extend_from_slice(&values);
+            out.extend(std::iter::repeat_n(tail, after));
+        }
+        PadMode::LinearRamp(end) => {
+            let (first, last, end) = (to_f64(values[0]), to_f64(values[n - 1]), to_f64(end));
+            out.extend((0..before).map(|i| {
+                cast_like::<A>(end + (first - end) * i as f64 / before as f64, f64::floor)
+            }));
+            out.extend_from_slice(&values);
+            out.extend((1..=after).map(|i| {
+                cast_like::<A>(last + (end - last) * i as f64 / after as f64, f64::floor)
+            }));
+        }
+        _ => {
+            let source = |p: isize| -> usize {
+                let n = n as isize;
+                let index = match *mode {
+                    PadMode::Edge => p.clamp(0, n - 1),
+                    PadMode::Wrap => p.rem_euclid(n),
+                    PadMode::Symmetric => {
+                        let m = p.rem_euclid(2 * n);
+                        if m >= n {
+                            2 * n - 1 - m
+                        } else {
+                            m
+                        }
+                    }
+                    _ if n == 1 => 0,
+                    _ => {
+                        let m = p.rem_euclid(2 * (n - 1));
+                        if m >= n {
+                            2 * (n - 1) - m
+                        } else {
+                            m
+                        }
+                    }
+                };
+                index as usize
+            };
+            let start = -(before as isize);
+            let stop = (n + after) as isize;
+            out.extend((start..stop).map(|p| values[source(p)]));
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod numrust_manipulation_tests {
+    use super::*;
+    use ndarray::{array, Array, Array3};
+
+    #[test]
+    fn test_reshape_inference_and_errors() {
+        let a = Array::from_iter(0..12);
+        assert_eq!(
+            reshape(&a, &[2, -1, 3], Order::C).unwrap().shape(),
+            &[2, 2, 3]
+        );
+        assert!(reshape(&a, &[-1, -1], Order::C).is_err());
+        assert!(reshape(&a, &[5, -1], Order::C).is_err());
+        assert!(reshape(&a, &[3, 5], Order::C).is_err());
+
+        let f = reshape(&a, &[3, 4], Order::F).unwrap();
+        assert_eq!(f.index_axis(Axis(0), 0), array![0, 3, 6, 9].into_dyn());
+        assert_eq!(flatten(&f, Order::F), a);
+        assert!(ravel(&f.t(), Order::C).is_view());
+        assert!(!ravel(&f, Order::C).is_view());
+        assert!(ravel(&f, Order::F).is_view());
+    }
+
+    #[test]
+    fn test_squeeze_and_expand_dims_round_trip() {
+        let a = Array3::from_shape_fn((2, 1, 3), |(i, _, k)| i * 3 + k);
+        let squeezed = squeeze(&a, Some(&[1])).unwrap();
+        assert_eq!(squeezed.shape(), &[2, 3]);
+        assert_eq!(expand_dims(&squeezed, &[1]).unwrap(), a.into_dyn());
+        assert!(expand_dims(&squeezed, &[0, 0]).is_err());
+        assert!(expand_dims(&squeezed, &[3]).is_err());
+    }
+
+    #[test]
+    fn test_stacking() {
+        let a = array![[1, 2], [3, 4]];
+        let b = array![[5, 6], [7, 8]];
+        assert_eq!(stack(&[&a, &b], 0).unwrap().shape(), &[2, 2, 2]);
+        assert_eq!(stack(&[&a, &b], 2).unwrap()[[1, 0, 1]], 7);
+        assert!(stack(&[&a, &array![[1, 2]]], 0).is_err());
+        assert_eq!(vstack(&[&a, &b]).unwrap().shape(), &[4, 2]);
+        assert_eq!(
+            hstack(&[&a, &b]).unwrap().index_axis(Axis(0), 0),
+            array![1, 2, 5, 6].into_dyn()
+        );
+        assert_eq!(dstack(&[&a, &b]).unwrap().shape(), &[2, 2, 2]);
+        assert!(concatenate(&[&a, &array![[1, 2, 3]]], 0).is_err());
+        let none: [&ndarray::Array2<i32>; 0] = [];
+        assert!(concatenate(&none, 0).is_err());
+    }
+
+    #[test]
+    fn test_split_out_of_range_indices() {
+        let a = Array::from_iter(0..4);
+        let parts = array_split(&a, Sections::Indices(&[3, 10]), 0).unwrap();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].len(), 3);
+        assert_eq!(parts[1].len(), 1);
+        assert_eq!(parts[2].len(), 0);
+        assert!(array_split(&a, Sections::Count(0), 0).is_err());
+    }
+
+    #[test]
+    fn test_tile_promotes_array() {
+        let a = array![[1, 2], [3, 4]];
+        let t = tile(&a, &[2, 1, 2]);
+        assert_eq!(t.shape(), &[2, 2, 4]);
+        assert_eq!(
+            t.index_axis(Axis(0), 1),
+            array![[1, 2, 1, 2], [3, 4, 3, 4]].into_dyn()
+        );
+    }
+
+    #[test]
+    fn test_rot90_three_dimensional() {
+        let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+        let r = rot90(&a, 1, (1, 2)).unwrap();
+        assert_eq!(r.shape(), &[2, 4, 3]);
+        assert_eq!(rot90(&r, 3, (1, 2)).unwrap(), a.clone().into_dyn());
+        assert_eq!(rot90(&a, 4, (0, 1)).unwrap(), a.clone().into_dyn());
+        assert!(rot90(&a, 1, (1, -2)).is_err());
+    }
+
+    #[test]
+    fn test_pad_statistics_and_two_dimensions() {
+        let a = array![1, 2, 3, 4, 5];
+        assert_eq!(
+            pad(&a, &[(2, 3)], PadMode::Maximum(None)).unwrap(),
+            array![5, 5, 1, 2, 3, 4, 5, 5, 5, 5].into_dyn()
+        );
+        assert_eq!(
+            pad(&a, &[(2, 3)], PadMode::Mean(Some(2))).unwrap(),
+            array![2, 2, 1, 2, 3, 4, 5, 4, 4, 4].into_dyn()
+        );
+        assert_eq!(
+            pad(&a, &[(2, 3)], PadMode::Median(None)).unwrap(),
+            array![3, 3, 1, 2, 3, 4, 5, 3, 3, 3].into_dyn()
+        );
+        assert_eq!(
+            pad(&a, &[(2, 3)], PadMode::Minimum(None)).unwrap(),
+            array![1, 1, 1, 2, 3, 4, 5, 1, 1, 1].into_dyn()
+        );
+        assert_eq!(
+            pad(&a, &[(2, 3)], PadMode::Edge).unwrap(),
+            array![1, 1, 1, 2, 3, 4, 5, 5, 5, 5].into_dyn()
+        );
+        assert_eq!(
+            pad(&array![1.0, 2.0], &[(1, 1)], PadMode::Mean(None)).unwrap(),
+            array![1.5, 1.0, 2.0, 1.5].into_dyn()
+        );
+        assert_eq!(
+            pad(&array![1, 2, 3], &[(5, 0)], PadMode::Reflect).unwrap(),
+            array![2, 1, 2, 3, 2, 1, 2, 3].into_dyn()
+        );
+
+        let b = array![[1, 2], [3, 4]];
+        let padded = pad(&b, &[(1, 1)], PadMode::Edge).unwrap();
+        assert_eq!(padded.index_axis(Axis(0), 0), array![1, 1, 2, 2].into_dyn());
+        assert_eq!(padded.index_axis(Axis(0), 3), array![3, 3, 4, 4].into_dyn());
+        assert!(pad(&b, &[(1, 1); 3], PadMode::Edge).is_err());
+        let empty = Array::<i32, _>::zeros((0,));
+        assert!(pad(&empty, &[(1, 1)], PadMode::Wrap).is_err());
+        assert_eq!(
+            pad(&empty, &[(1, 1)], PadMode::Constant(7)).unwrap(),
+            array![7, 7].into_dyn()
+        );
+    }
+}
@@ -0,0 +1,471 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, Write};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::path::Path;
+
+use memmap2::{Mmap, MmapMut, MmapOptions};
+use ndarray::{ArrayViewD, ArrayViewMutD, IxDyn, ShapeBuilder};
+use num_complex::{Complex32, Complex64};
+
+use crate::complex::Scalar;
+use crate::npy::{check_descr, data_len, header_bytes, read_header, too_big, NpyElement};
+use crate::{Moment, NumrustError};
+
+/// How a file is mapped into memory, following the `mode` argument of `numpy.memmap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MmapMode {
+    /// The data can only be read (`'r'`).
+    ReadOnly,
+    /// Changes are written back to the file (`'r+'`).
+    ReadWrite,
+    /// Changes are kept in memory and never written to the file (`'c'`).
+    CopyOnWrite,
+}
+
+/// An element type that can be viewed directly in a memory-mapped file.
+///
+/// # Safety
+///
+/// Every bit pattern of `SIZE` bytes must be a valid value of the type, and the type must be
+/// laid out in memory as NumPy stores it in native byte order.
+pub unsafe trait MemmapElement: NpyElement {}
+
+macro_rules! impl_memmap_element {
+    ($($ty:ty),*) => {
+        $(unsafe impl MemmapElement for $ty {})*
+    };
+}
+
+impl_memmap_element!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, Complex32, Complex64);
+
+enum Mapping {
+    ReadOnly(Mmap),
+    Mutable(MmapMut),
+}
+
+impl Mapping {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Mapping::ReadOnly(map) => map,
+            Mapping::Mutable(map) => map,
+        }
+    }
+}
+
+/// An array whose elements live in a memory-mapped file, so that data larger than memory can be
+/// processed without loading it.
+///
+/// The elements are available as a slice through `Deref`, in the order they are stored in the
+/// file, and as an array view with [`Memmap::view`]. Statistics are computed through the
+/// [`Moment`] trait.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::memmap::{Memmap, MmapMode};
+/// use numrust::Moment;
+///
+/// let path = std::env::temp_dir().join("numrust_doc_memmap.npy");
+/// let mut data = unsafe { Memmap::<f64>::create_npy(&path, &[2, 3]).unwrap() };
+/// data.as_mut_slice().unwrap().copy_from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// data.flush().unwrap();
+/// drop(data);
+///
+/// let data = unsafe { Memmap::<f64>::open_npy(&path, MmapMode::ReadOnly).unwrap() };
+/// assert_eq!(data.shape(), &[2, 3]);
+/// assert_eq!(data.mean(), Some(3.5));
+/// assert_eq!(data.view().sum_axis(ndarray::Axis(0)).into_raw_vec(), vec![5.0, 7.0, 9.0]);
+/// # drop(data);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct Memmap<T> {
+    map: Mapping,
+    mode: MmapMode,
+    offset: usize,
+    shape: Vec<usize>,
+    fortran_order: bool,
+    _element: PhantomData<T>,
+}
+
+fn io_error(err: std::io::Error) -> NumrustError {
+    NumrustError::IoError(err.to_string())
+}
+
+impl<T: MemmapElement> Memmap<T> {
+    /// Maps the raw data starting `offset` bytes into the file at `path`.
+    ///
+    /// If `shape` is `None`, the array is one-dimensional and covers the rest of the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or modified by anyone else while it is mapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if `offset` is not a multiple of the alignment of `T`,
+    /// the size of `shape` in bytes overflows or the file is too small for it, and a
+    /// `NumrustError::IoError` if the file cannot be opened or mapped.
+    pub unsafe fn open(
+        path: impl AsRef<Path>,
+        mode: MmapMode,
+        offset: usize,
+        shape: Option<&[usize]>,
+    ) -> Result<Self, NumrustError> {
+        let map = map_file(path.as_ref(), mode)?;
+        let available = map.bytes().len().saturating_sub(offset) / T::SIZE;
+        let shape = shape.map_or_else(|| vec![available], <[usize]>::to_vec);
+        Self::from_mapping(map, mode, offset, shape, false)
+    }
+
+    /// Maps the array stored in the `.npy` file at `path`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or modified by anyone else while it is mapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if the file is not a valid `.npy` file, holds a dtype
+    /// other than `T`, stores it in non-native byte order or is too small for the shape in its
+    /// header, and a `NumrustError::IoError` if the file cannot be opened or mapped.
+    pub unsafe fn open_npy(path: impl AsRef<Path>, mode: MmapMode) -> Result<Self, NumrustError> {
+        let path = path.as_ref();
+        let mut reader = BufReader::new(File::open(path).map_err(io_error)?);
+        let (header, offset) = read_header(&mut reader)?;
+        let little_endian = check_descr::<T>(&header.descr)?;
+        if T::SIZE > 1 && little_endian != cfg!(target_endian = "little") {
+            return Err(NumrustError::ValueError(
+                "cannot memory-map data stored in non-native byte order".to_string(),
+            ));
+        }
+        let map = map_file(path, mode)?;
+        Self::from_mapping(map, mode, offset, header.shape, header.fortran_order)
+    }
+
+    /// Creates or overwrites the file at `path` with zeros for an array of `shape`, and maps it
+    /// in [`MmapMode::ReadWrite`] mode (`'w+'` in NumPy).
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or modified by anyone else while it is mapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if the size of `shape` in bytes overflows, and a
+    /// `NumrustError::IoError` if the file cannot be created or mapped.
+    pub unsafe fn create(path: impl AsRef<Path>, shape: &[usize]) -> Result<Self, NumrustError> {
+        let path = path.as_ref();
+        let len = data_len::<T>(shape)?;
+        let file = File::create(path).map_err(io_error)?;
+        file.set_len(len as u64).map_err(io_error)?;
+        Self::open(path, MmapMode::ReadWrite, 0, Some(shape))
+    }
+
+    /// Creates or overwrites the `.npy` file at `path` with zeros for an array of `shape`, and
+    /// maps it in [`MmapMode::ReadWrite`] mode.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or modified by anyone else while it is mapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if the size of `shape` in bytes overflows, and a
+    /// `NumrustError::IoError` if the file cannot be created or mapped.
+    pub unsafe fn create_npy(
+        path: impl AsRef<Path>,
+        shape: &[usize],
+    ) -> Result<Self, NumrustError> {
+        let path = path.as_ref();
+        let header = header_bytes::<T>(shape);
+        let len = data_len::<T>(shape)?
+            .checked_add(header.len())
+            .ok_or_else(too_big)?;
+        let mut file = File::create(path).map_err(io_error)?;
+        file.write_all(&header).map_err(io_error)?;
+        file.set_len(len as u64).map_err(io_error)?;
+        drop(file);
+        Self::open_npy(path, MmapMode::ReadWrite)
+    }
+
+    fn from_mapping(
+        map: Mapping,
+        mode: MmapMode,
+        offset: usize,
+        shape: Vec<usize>,
+        fortran_order: bool,
+    ) -> Result<Self, NumrustError> {
+        if !offset.is_multiple_of(std::mem::align_of::<T>()) {
+            return Err(NumrustError::ValueError(format!(
+                "offset {} is not a multiple of the element alignment {}",
+                offset,
+                std::mem::align_of::<T>()
+            )));
+        }
+        // The shape may come from an untrusted header, and the slices handed out later rely on
+        // this bound, so it must not overflow.
+        let needed = data_len::<T>(&shape)?
+            .checked_add(offset)
+            .ok_or_else(too_big)?;
+        if needed > map.bytes().len() {
+            return Err(NumrustError::ValueError(format!(
+                "the file holds {} bytes, but the array needs {}",
+                map.bytes().len(),
+                needed
+            )));
+        }
+        Ok(Memmap {
+            map,
+            mode,
+            offset,
+            shape,
+            fortran_order,
+            _element: PhantomData,
+        })
+    }
+
+    /// Returns the shape of the array.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the mode the file was mapped with.
+    pub fn mode(&self) -> MmapMode {
+        self.mode
+    }
+
+    /// Returns the elements as a mutable slice, in the order they are stored in the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if the file was mapped in [`MmapMode::ReadOnly`]
+    /// mode.
+    pub fn as_mut_slice(&mut self) -> Result<&mut [T], NumrustError> {
+        let len = self.shape.iter().product();
+        match &mut self.map {
+            Mapping::ReadOnly(_) => Err(NumrustError::ValueError(
+                "the array is mapped read-only".to_string(),
+            )),
+            Mapping::Mutable(_) if len == 0 => Ok(&mut []),
+            Mapping::Mutable(map) => {
+                let data = map[self.offset..].as_mut_ptr() as *mut T;
+                // SAFETY: `from_mapping` checked that the mapping holds `len` elements after
+                // `offset` and that they are aligned; any bit pattern is a valid `T`.
+                Ok(unsafe { std::slice::from_raw_parts_mut(data, len) })
+            }
+        }
+    }
+
+    /// Returns a view of the array with its shape.
+    pub fn view(&self) -> ArrayViewD<'_, T> {
+        let shape = IxDyn(&self.shape).set_f(self.fortran_order);
+        ArrayViewD::from_shape(shape, self).expect("the mapping holds one value per element")
+    }
+
+    /// Returns a mutable view of the array with its shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if the file was mapped in [`MmapMode::ReadOnly`]
+    /// mode.
+    pub fn view_mut(&mut self) -> Result<ArrayViewMutD<'_, T>, NumrustError> {
+        let shape = IxDyn(&self.shape).set_f(self.fortran_order);
+        let data = self.as_mut_slice()?;
+        Ok(
+            ArrayViewMutD::from_shape(shape, data)
+                .expect("the mapping holds one value per element"),
+        )
+    }
+
+    /// Writes changes back to the file. This does nothing unless the file was mapped in
+    /// [`MmapMode::ReadWrite`] mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::IoError` if flushing fails.
+    pub fn flush(&self) -> Result<(), NumrustError> {
+        match (&self.map, self.mode) {
+            (Mapping::Mutable(map), MmapMode::ReadWrite) => map.flush().map_err(io_error),
+            _ => Ok(()),
+        }
+    }
+}
+
+unsafe fn map_file(path: &Path, mode: MmapMode) -> Result<Mapping, NumrustError> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(mode == MmapMode::ReadWrite)
+        .open(path)
+        .map_err(io_error)?;
+    let map = match mode {
+        MmapMode::ReadOnly => Mapping::ReadOnly(Mmap::map(&file).map_err(io_error)?),
+        MmapMode::ReadWrite => Mapping::Mutable(MmapMut::map_mut(&file).map_err(io_error)?),
+        MmapMode::CopyOnWrite => {
+            Mapping::Mutable(MmapOptions::new().map_copy(&file).map_err(io_error)?)
+        }
+    };
+    Ok(map)
+}
+
+impl<T: MemmapElement> Deref for Memmap<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        let len = self.shape.iter().product();
+        if len == 0 {
+            return &[];
+        }
+        let data = self.map.bytes()[self.offset..].as_ptr() as *const T;
+        // SAFETY: `from_mapping` checked that the mapping holds `len` elements after `offset`
+        // and that they are aligned; any bit pattern is a valid `T`.
+        unsafe { std::slice::from_raw_parts(data, len) }
+    }
+}
+
+impl<T: MemmapElement + Scalar> Moment for Memmap<T> {
+    type Output = T::Wide;
+
+    fn mean(&self) -> Option<T::Wide> {
+        (**self).mean()
+    }
+
+    fn var(&self) -> Option<f64> {
+        (**self).var()
+    }
+
+    fn std(&self) -> Option<f64> {
+        (**self).std()
+    }
+
+    fn skew(&self) -> Option<T::Wide> {
+        (**self).skew()
+    }
+}
+
+#[cfg(test)]
+mod numrust_memmap_tests {
+    use super::*;
+    use crate::npy::{load_npy, save_npy};
+    use ndarray::array;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("numrust_memmap_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_raw_offset_and_inferred_shape() {
+        let path = temp_path("raw.bin");
+        let mut bytes = vec![0u8; 8];
+        for x in [1i32, -2, 3, -4] {
+            bytes.extend_from_slice(&x.to_ne_bytes());
+        }
+        std::fs::write(&path, &bytes).unwrap();
+
+        let data = unsafe { Memmap::<i32>::open(&path, MmapMode::ReadOnly, 8, None).unwrap() };
+        assert_eq!(&*data, &[1, -2, 3, -4]);
+        assert_eq!(data.mean(), Some(-0.5));
+        let shaped =
+            unsafe { Memmap::<i32>::open(&path, MmapMode::ReadOnly, 8, Some(&[2, 2])).unwrap() };
+        assert_eq!(shaped.view(), array![[1, -2], [3, -4]].into_dyn());
+
+        assert!(unsafe { Memmap::<i32>::open(&path, MmapMode::ReadOnly, 2, None) }.is_err());
+        assert!(unsafe { Memmap::<i32>::open(&path, MmapMode::ReadOnly, 8, Some(&[5])) }.is_err());
+        let mut read_only = data;
+        assert!(read_only.as_mut_slice().is_err());
+        assert!(read_only.view_mut().is_err());
+        drop(read_only);
+        drop(shaped);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_write_modes() {
+        let path = temp_path("modes.npy");
+        save_npy(&path, &array![[1.0f32, 2.0], [3.0, 4.0]]).unwrap();
+
+        let mut copy = unsafe { Memmap::<f32>::open_npy(&path, MmapMode::CopyOnWrite).unwrap() };
+        copy.view_mut().unwrap()[[0, 1]] = 20.0;
+        copy.flush().unwrap();
+        assert_eq!(&*copy, &[1.0, 20.0, 3.0, 4.0]);
+        drop(copy);
+        assert_eq!(load_npy::<f32>(&path).unwrap()[[0, 1]], 2.0);
+
+        let mut shared = unsafe { Memmap::<f32>::open_npy(&path, MmapMode::ReadWrite).unwrap() };
+        shared.view_mut().unwrap()[[1, 0]] = 30.0;
+        shared.flush().unwrap();
+        drop(shared);
+        assert_eq!(
+            load_npy::<f32>(&path).unwrap(),
+            array![[1.0, 2.0], [30.0, 4.0]].into_dyn()
+        );
+
+        assert!(unsafe { Memmap::<f64>::open_npy(&path, MmapMode::ReadOnly) }.is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_create_and_fortran_order() {
+        let path = temp_path("create.bin");
+        let mut data = unsafe { Memmap::<u16>::create(&path, &[3]).unwrap() };
+        assert_eq!(&*data, &[0, 0, 0]);
+        data.as_mut_slice().unwrap()[2] = 7;
+        data.flush().unwrap();
+        drop(data);
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            [0, 0, 0, 0, 7u16.to_ne_bytes()[0], 7u16.to_ne_bytes()[1]]
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        let path = temp_path("fortran.npy");
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        let mut header = "{'descr': '|u1', 'fortran_order': True, 'shape': (2, 3), }".to_string();
+        header.push_str(&" ".repeat(128 - 10 - header.len() - 1));
+        header.push('\n');
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&[1, 4, 2, 5, 3, 6]);
+        std::fs::write(&path, &bytes).unwrap();
+        let data = unsafe { Memmap::<u8>::open_npy(&path, MmapMode::ReadOnly).unwrap() };
+        assert_eq!(data.view(), array![[1u8, 2, 3], [4, 5, 6]].into_dyn());
+        drop(data);
+        std::fs::remove_file(&path).unwrap();
+
+        let path = temp_path("empty.npy");
+        let empty = unsafe { Memmap::<f64>::create_npy(&path, &[0, 4]).unwrap() };
+        assert!(empty.is_empty());
+        assert_eq!(empty.mean(), None);
+        drop(empty);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_oversized_shapes_are_rejected() {
+        // A header whose shape overflows the byte count must not map 8 bytes as 2^61 + 1
+        // elements.
+        let path = temp_path("oversized.npy");
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        let mut header =
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2305843009213693953,), }"
+                .to_string();
+        header.push_str(&" ".repeat(128 - 10 - header.len() - 1));
+        header.push('\n');
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&1.0f64.to_le_bytes());
+        std::fs::write(&path, &bytes).unwrap();
+        let result = unsafe { Memmap::<f64>::open_npy(&path, MmapMode::ReadOnly) };
+        assert!(matches!(result, Err(NumrustError::ValueError(_))));
+        assert!(unsafe {
+            Memmap::<f64>::open(&path, MmapMode::ReadOnly, 0, Some(&[usize::MAX, 2]))
+        }
+        .is_err());
+        std::fs::remove_file(&path).unwrap();
+
+        let path = temp_path("oversized.bin");
+        assert!(unsafe { Memmap::<u32>::create(&path, &[usize::MAX / 2]) }.is_err());
+        assert!(unsafe { Memmap::<u8>::create_npy(&path, &[usize::MAX]) }.is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,735 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+
+use ndarray::{ArrayBase, ArrayD, Data, Dimension, IxDyn, ShapeBuilder};
+use num_complex::{Complex32, Complex64};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::NumrustError;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// An element type that can be stored in `.npy` files.
+///
+/// Implemented for `bool`, the fixed-width integer and floating-point types, and
+/// `Complex32`/`Complex64`.
+pub trait NpyElement: Copy {
+    /// The NumPy type character: `b` for booleans, `i`/`u` for integers, `f` for floats and `c`
+    /// for complex numbers.
+    const KIND: char;
+    /// The size of one element in bytes.
+    const SIZE: usize;
+
+    /// Decodes one element from exactly `SIZE` bytes.
+    fn from_bytes(bytes: &[u8], little_endian: bool) -> Self;
+
+    /// Appends the little-endian encoding of the element.
+    fn write_le(self, out: &mut Vec<u8>);
+}
+
+impl NpyElement for bool {
+    const KIND: char = 'b';
+    const SIZE: usize = 1;
+
+    fn from_bytes(bytes: &[u8], _little_endian: bool) -> Self {
+        bytes[0] != 0
+    }
+
+    fn write_le(self, out: &mut Vec<u8>) {
+        out.push(self as u8);
+    }
+}
+
+macro_rules! impl_npy_element {
+    ($($ty:ty => $kind:expr),*) => {
+        $(
+            impl NpyElement for $ty {
+                const KIND: char = $kind;
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn from_bytes(bytes: &[u8], little_endian: bool) -> Self {
+                    let raw = bytes.try_into().expect("one element's worth of bytes");
+                    if little_endian {
+                        <$ty>::from_le_bytes(raw)
+                    } else {
+                        <$ty>::from_be_bytes(raw)
+                    }
+                }
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_element!(
+    i8 => 'i', i16 => 'i', i32 => 'i', i64 => 'i',
+    u8 => 'u', u16 => 'u', u32 => 'u', u64 => 'u',
+    f32 => 'f', f64 => 'f'
+);
+
+macro_rules! impl_npy_complex {
+    ($($ty:ty => $part:ty),*) => {
+        $(
+            impl NpyElement for $ty {
+                const KIND: char = 'c';
+                const SIZE: usize = 2 * std::mem::size_of::<$part>();
+
+                fn from_bytes(bytes: &[u8], little_endian: bool) -> Self {
+                    let half = Self::SIZE / 2;
+                    <$ty>::new(
+                        <$part>::from_bytes(&bytes[..half], little_endian),
+                        <$part>::from_bytes(&bytes[half..], little_endian),
+                    )
+                }
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    self.re.write_le(out);
+                    self.im.write_le(out);
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_complex!(Complex32 => f32, Complex64 => f64);
+
+/// The parsed header of a `.npy` file.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Header {
+    pub(crate) descr: String,
+    pub(crate) fortran_order: bool,
+    pub(crate) shape: Vec<usize>,
+}
+
+fn format_error(msg: impl Into<String>) -> NumrustError {
+    NumrustError::ValueError(msg.into())
+}
+
+fn io_error(err: std::io::Error) -> NumrustError {
+    NumrustError::IoError(err.to_string())
+}
+
+/// Returns the number of bytes taken by the elements of an array of `shape`, or an error if
+/// that does not fit in a `usize`.
+pub(crate) fn data_len<T: NpyElement>(shape: &[usize]) -> Result<usize, NumrustError> {
+    shape
+        .iter()
+        .try_fold(T::SIZE, |len, &d| len.checked_mul(d))
+        .ok_or_else(too_big)
+}
+
+/// The error for an array whose size in bytes does not fit in a `usize`.
+pub(crate) fn too_big() -> NumrustError {
+    format_error(
+        "array is too big; `arr.size * arr.dtype.itemsize` is larger than the maximum possible \
+         size.",
+    )
+}
+
+/// Returns the text following `'key':` in a header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NumrustError> {
+    let pattern = format!("'{}':", key);
+    let start = header
+        .find(&pattern)
+        .ok_or_else(|| format_error(format!("header does not contain the key '{}'", key)))?;
+    Ok(header[start + pattern.len()..].trim_start())
+}
+
+fn parse_header(header: &str) -> Result<Header, NumrustError> {
+    let descr = header_value(header, "descr")?;
+    if descr.starts_with('[') {
+        return Err(format_error("structured dtypes are not supported"));
+    }
+    let quote = descr
+        .chars()
+        .next()
+        .filter(|c| *c == '\'' || *c == '"')
+        .ok_or_else(|| format_error("descr is not a string"))?;
+    let end = descr[1..]
+        .find(quote)
+        .ok_or_else(|| format_error("unterminated descr string"))?;
+    let descr = descr[1..=end].to_string();
+
+    let fortran = header_value(header, "fortran_order")?;
+    let fortran_order = if fortran.starts_with("True") {
+        true
+    } else if fortran.starts_with("False") {
+        false
+    } else {
+        return Err(format_error("fortran_order is not a boolean"));
+    };
+
+    let shape = header_value(header, "shape")?;
+    let close = shape
+        .find(')')
+        .filter(|_| shape.starts_with('('))
+        .ok_or_else(|| format_error("shape is not a tuple"))?;
+    let shape = shape[1..close]
+        .split(',')
+        .map(|d| d.trim().trim_end_matches('L'))
+        .filter(|d| !d.is_empty())
+        .map(|d| {
+            d.parse::<usize>()
+                .map_err(|_| format_error(format!("invalid dimension '{}' in shape", d)))
+        })
+        .collect::<Result<Vec<usize>, NumrustError>>()?;
+
+    Ok(Header {
+        descr,
+        fortran_order,
+        shape,
+    })
+}
+
+/// Checks that `descr` describes `T` and returns whether the data is little-endian.
+pub(crate) fn check_descr<T: NpyElement>(descr: &str) -> Result<bool, NumrustError> {
+    let (order, code) = match descr.chars().next() {
+        Some(c @ ('<' | '>' | '|' | '=')) => (c, &descr[1..]),
+        _ => ('=', descr),
+    };
+    let kind = code.chars().next().unwrap_or(' ');
+    if kind == 'O' {
+        return Err(format_error(
+            "Object arrays cannot be loaded: they require Python's pickle",
+        ));
+    }
+    let size: Option<usize> = code[kind.len_utf8()..].parse().ok();
+    let kind = if kind == '?' { 'b' } else { kind };
+    if kind != T::KIND || size.unwrap_or(1) != T::SIZE {
+        return Err(format_error(format!(
+            "the file holds dtype '{}', which does not match the requested element type '{}{}'",
+            descr,
+            T::KIND,
+            T::SIZE
+        )));
+    }
+    Ok(match order {
+        '>' => false,
+        '<' => true,
+        _ => cfg!(target_endian = "little"),
+    })
+}
+
+fn descr<T: NpyElement>() -> String {
+    let order = if T::SIZE == 1 { '|' } else { '<' };
+    format!("{}{}{}", order, T::KIND, T::SIZE)
+}
+
+/// Encodes the preamble and header of a little-endian, C-order `.npy` file holding an array of
+/// the given shape. The result is padded to a multiple of 64 bytes.
+pub(crate) fn header_bytes<A: NpyElement>(shape: &[usize]) -> Vec<u8> {
+    let shape = match shape {
+        [n] => format!("({},)", n),
+        dims => format!(
+            "({})",
+            dims.iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr::<A>(),
+        shape
+    );
+    let version: u8 = if header.len() + 64 <= u16::MAX as usize {
+        1
+    } else {
+        2
+    };
+    let prefix = if version == 1 { 10 } else { 12 };
+    let padding = (64 - (prefix + header.len() + 1) % 64) % 64;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(prefix + header.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[version, 0]);
+    if version == 1 {
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    } else {
+        bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    }
+    bytes.extend_from_slice(header.as_bytes());
+    bytes
+}
+
+/// Reads an array in the `.npy` format from `reader`.
+///
+/// All format versions (1.0, 2.0 and 3.0), both byte orders and C or Fortran order are
+/// supported. The element type `T` must match the dtype stored in the file.
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the data is not a valid `.npy` array, holds objects
+/// or a structured dtype, or has a dtype other than `T`, and a `NumrustError::IoError` if reading
+/// fails.
+pub fn read_npy<T: NpyElement, R: Read>(mut reader: R) -> Result<ArrayD<T>, NumrustError> {
+    let (header, _) = read_header(&mut reader)?;
+    let little_endian = check_descr::<T>(&header.descr)?;
+
+    // The header is untrusted, so the buffer grows only as data actually arrives.
+    let len = data_len::<T>(&header.shape)?;
+    let mut data = Vec::new();
+    reader
+        .take(len as u64)
+        .read_to_end(&mut data)
+        .map_err(io_error)?;
+    if data.len() != len {
+        return Err(io_error(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            format!(
+                "the header describes {} bytes of data, but only {} follow it",
+                len,
+                data.len()
+            ),
+        )));
+    }
+    let values: Vec<T> = data
+        .chunks_exact(T::SIZE)
+        .map(|bytes| T::from_bytes(bytes, little_endian))
+        .collect();
+    let shape = IxDyn(&header.shape);
+    let array = if header.fortran_order {
+        ArrayD::from_shape_vec(shape.f(), values)
+    } else {
+        ArrayD::from_shape_vec(shape, values)
+    };
+    Ok(array.expect("the data holds one value per element"))
+}
+
+/// Reads the preamble and header of a `.npy` file, returning the header and the offset of the
+/// data from the start of the file.
+pub(crate) fn read_header<R: Read>(reader: &mut R) -> Result<(Header, usize), NumrustError> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble).map_err(io_error)?;
+    if &preamble[..6] != MAGIC {
+        return Err(format_error(
+            "the data does not start with the .npy magic string",
+        ));
+    }
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len).map_err(io_error)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len).map_err(io_error)?;
+            u32::from_le_bytes(len) as usize
+        }
+        major => {
+            return Err(format_error(format!(
+                "unsupported .npy format version {}.{}",
+                major, preamble[7]
+            )))
+        }
+    };
+    let mut header = vec![0u8; header_len];
+    reader.read_exact(&mut header).map_err(io_error)?;
+    let offset = preamble.len() + if preamble[6] == 1 { 2 } else { 4 } + header.len();
+    let header =
+        String::from_utf8(header).map_err(|_| format_error("the header is not valid text"))?;
+    Ok((parse_header(&header)?, offset))
+}
+
+/// Writes `a` to `writer` in the `.npy` format, in C order and little-endian byte order.
+///
+/// Format version 1.0 is used unless the header is too long for it, in which case 2.0 is used.
+///
+/// # Errors
+///
+/// Returns a `NumrustError::IoError` if writing fails.
+pub fn write_npy<A, S, D, W>(mut writer: W, a: &ArrayBase<S, D>) -> Result<(), NumrustError>
+where
+    A: NpyElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+    W: Write,
+{
+    let mut bytes = header_bytes::<A>(a.shape());
+    bytes.reserve(a.len() * A::SIZE);
+    for &x in a.iter() {
+        x.write_le(&mut bytes);
+    }
+    writer.write_all(&bytes).map_err(io_error)
+}
+
+/// Loads an array from a `.npy` file.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::npy::{load_npy, save_npy};
+///
+/// let path = std::env::temp_dir().join("numrust_doc_load_npy.npy");
+/// save_npy(&path, &array![[1.0, 2.0], [3.0, 4.0]]).unwrap();
+/// let loaded = load_npy::<f64>(&path).unwrap();
+/// assert_eq!(loaded, array![[1.0, 2.0], [3.0, 4.0]].into_dyn());
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns the errors of [`read_npy`], and a `NumrustError::IoError` if the file cannot be
+/// opened.
+pub fn load_npy<T: NpyElement>(path: impl AsRef<Path>) -> Result<ArrayD<T>, NumrustError> {
+    let file = File::open(path).map_err(io_error)?;
+    read_npy(BufReader::new(file))
+}
+
+/// Saves an array to a `.npy` file.
+///
+/// # Errors
+///
+/// Returns a `NumrustError::IoError` if the file cannot be written.
+pub fn save_npy<A, S, D>(path: impl AsRef<Path>, a: &ArrayBase<S, D>) -> Result<(), NumrustError>
+where
+    A: NpyElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let file = File::create(path).map_err(io_error)?;
+    let mut writer = BufWriter::new(file);
+    write_npy(&mut writer, a)?;
+    writer.flush().map_err(io_error)
+}
+
+/// The arrays of an `.npz` archive, read into memory by [`load_npz`].
+#[derive(Debug, Clone)]
+pub struct Npz {
+    entries: Vec<(String, Vec<u8>)>,
+}
+
+impl Npz {
+    /// Returns the names of the arrays in the archive, in archive order.
+    pub fn names(&self) -> Vec<&str> {
+        self.entries.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Decodes the array called `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if there is no such array, and the errors of
+    /// [`read_npy`] otherwise.
+    pub fn get<T: NpyElement>(&self, name: &str) -> Result<ArrayD<T>, NumrustError> {
+        let (_, bytes) = self
+            .entries
+            .iter()
+            .find(|(n, _)| n == name)
+            .ok_or_else(|| format_error(format!("{} is not a file in the archive", name)))?;
+        read_npy(bytes.as_slice())
+    }
+}
+
+/// Loads the arrays of an `.npz` archive written by `numpy.savez`, `numpy.savez_compressed`,
+/// [`savez`] or [`savez_compressed`].
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::npy::{load_npz, NpzWriter};
+///
+/// let path = std::env::temp_dir().join("numrust_doc_load_npz.npz");
+/// let mut writer = NpzWriter::create(&path, true).unwrap();
+/// writer.add_array("x", &array![1.0, 2.0]).unwrap();
+/// writer.add_array("mask", &array![true, false]).unwrap();
+/// writer.finish().unwrap();
+///
+/// let npz = load_npz(&path).unwrap();
+/// assert_eq!(npz.names(), vec!["x", "mask"]);
+/// assert_eq!(npz.get::<bool>("mask").unwrap(), array![true, false].into_dyn());
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::IoError` if the file cannot be read, and a
+/// `NumrustError::ValueError` if it is not a valid zip archive.
+pub fn load_npz(path: impl AsRef<Path>) -> Result<Npz, NumrustError> {
+    let file = File::open(path).map_err(io_error)?;
+    read_npz(BufReader::new(file))
+}
+
+fn read_npz<R: Read + Seek>(reader: R) -> Result<Npz, NumrustError> {
+    let zip_error = |e: zip::result::ZipError| format_error(format!("invalid .npz archive: {}", e));
+    let mut archive = ZipArchive::new(reader).map_err(zip_error)?;
+    let mut entries = Vec::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(zip_error)?;
+        let name = file.name().trim_end_matches(".npy").to_string();
+        // The sizes in the zip headers are untrusted, so they are not used to pre-allocate.
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes).map_err(io_error)?;
+        entries.push((name, bytes));
+    }
+    Ok(Npz { entries })
+}
+
+/// Writes arrays of different element types into an `.npz` archive one at a time.
+pub struct NpzWriter<W: Write + Seek> {
+    zip: ZipWriter<W>,
+    options: SimpleFileOptions,
+}
+
+impl NpzWriter<BufWriter<File>> {
+    /// Creates an archive at `path`, deflating its entries if `compressed` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::IoError` if the file cannot be created.
+    pub fn create(path: impl AsRef<Path>, compressed: bool) -> Result<Self, NumrustError> {
+        let file = File::create(path).map_err(io_error)?;
+        Ok(NpzWriter::new(BufWriter::new(file), compressed))
+    }
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    /// Starts an archive written to `writer`, deflating its entries if `compressed` is set.
+    pub fn new(writer: W, compressed: bool) -> Self {
+        let method = if compressed {
+            CompressionMethod::Deflated
+        } else {
+            CompressionMethod::Stored
+        };
+        NpzWriter {
+            zip: ZipWriter::new(writer),
+            options: SimpleFileOptions::default()
+                .compression_method(method)
+                .large_file(true),
+        }
+    }
+
+    /// Adds `a` to the archive as `name.npy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::IoError` if writing fails.
+    pub fn add_array<A, S, D>(
+        &mut self,
+        name: &str,
+        a: &ArrayBase<S, D>,
+    ) -> Result<(), NumrustError>
+    where
+        A: NpyElement,
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        self.zip
+            .start_file(format!("{}.npy", name), self.options)
+            .map_err(|e| NumrustError::IoError(e.to_string()))?;
+        write_npy(&mut self.zip, a)
+    }
+
+    /// Writes the archive's central directory and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::IoError` if writing fails.
+    pub fn finish(self) -> Result<W, NumrustError> {
+        self.zip
+            .finish()
+            .map_err(|e| NumrustError::IoError(e.to_string()))
+    }
+}
+
+fn write_npz<A, S, D>(
+    path: impl AsRef<Path>,
+    arrays: &[(&str, &ArrayBase<S, D>)],
+    compressed: bool,
+) -> Result<(), NumrustError>
+where
+    A: NpyElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut writer = NpzWriter::create(path, compressed)?;
+    for (name, a) in arrays {
+        writer.add_array(name, *a)?;
+    }
+    writer.finish()?.flush().map_err(io_error)
+}
+
+/// Saves several arrays of the same element type into an uncompressed `.npz` archive. Use
+/// [`NpzWriter`] to mix element types.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::npy::{load_npz, savez};
+///
+/// let path = std::env::temp_dir().join("numrust_doc_savez.npz");
+/// savez(&path, &[("a", &array![1, 2]), ("b", &array![3, 4])]).unwrap();
+/// assert_eq!(load_npz(&path).unwrap().get::<i32>("b").unwrap(), array![3, 4].into_dyn());
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::IoError` if the file cannot be written.
+pub fn savez<A, S, D>(
+    path: impl AsRef<Path>,
+    arrays: &[(&str, &ArrayBase<S, D>)],
+) -> Result<(), NumrustError>
+where
+    A: NpyElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    write_npz(path, arrays, false)
+}
+
+/// Saves several arrays of the same element type into a deflate-compressed `.npz` archive.
+///
+/// # Errors
+///
+/// Returns a `NumrustError::IoError` if the file cannot be written.
+pub fn savez_compressed<A, S, D>(
+    path: impl AsRef<Path>,
+    arrays: &[(&str, &ArrayBase<S, D>)],
+) -> Result<(), NumrustError>
+where
+    A: NpyElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    write_npz(path, arrays, true)
+}
+
+#[cfg(test)]
+mod numrust_npy_tests {
+    use super::*;
+    use ndarray::{array, Array3};
+    use std::io::Cursor;
+
+    fn npy_bytes(major: u8, descr: &str, fortran: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+        let fortran = if fortran { "True" } else { "False" };
+        let header = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}\n",
+            descr, fortran, shape
+        );
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[major, 0]);
+        if major == 1 {
+            bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        } else {
+            bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        }
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn test_header_matches_numpy_layout() {
+        let mut bytes = Vec::new();
+        write_npy(&mut bytes, &array![0i32, 1, 2]).unwrap();
+        assert_eq!(&bytes[..10], b"\x93NUMPY\x01\x00v\x00");
+        let header = std::str::from_utf8(&bytes[10..128]).unwrap();
+        assert!(header.starts_with("{'descr': '<i4', 'fortran_order': False, 'shape': (3,), }"));
+        assert!(header.ends_with(" \n"));
+        assert_eq!(bytes.len(), 128 + 12);
+    }
+
+    #[test]
+    fn test_read_big_endian_fortran_version_2() {
+        let data: Vec<u8> = [1.0f64, 3.0, 2.0, 4.0]
+            .iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect();
+        let bytes = npy_bytes(2, ">f8", true, "(2, 2)", &data);
+        let a = read_npy::<f64, _>(bytes.as_slice()).unwrap();
+        assert_eq!(a, array![[1.0, 2.0], [3.0, 4.0]].into_dyn());
+
+        let bytes = npy_bytes(3, "<u2", false, "()", &7u16.to_le_bytes());
+        assert_eq!(read_npy::<u16, _>(bytes.as_slice()).unwrap()[[]], 7);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let bytes = npy_bytes(1, "|O", false, "(1,)", &[0; 8]);
+        let err = read_npy::<f64, _>(bytes.as_slice()).unwrap_err();
+        assert!(err.to_string().contains("Object arrays"));
+
+        let bytes = npy_bytes(1, "<f4", false, "(1,)", &[0; 4]);
+        assert!(read_npy::<f64, _>(bytes.as_slice()).is_err());
+
+        let bytes = npy_bytes(1, "<f8", false, "(2,)", &[0; 8]);
+        assert!(matches!(
+            read_npy::<f64, _>(bytes.as_slice()),
+            Err(NumrustError::IoError(_))
+        ));
+
+        // Shapes from the header are checked before anything is allocated.
+        let bytes = npy_bytes(1, "<f8", false, "(2305843009213693953,)", &[0; 8]);
+        assert!(matches!(
+            read_npy::<f64, _>(bytes.as_slice()),
+            Err(NumrustError::ValueError(_))
+        ));
+        let bytes = npy_bytes(1, "<f8", false, "(1000000000000,)", &[0; 8]);
+        assert!(matches!(
+            read_npy::<f64, _>(bytes.as_slice()),
+            Err(NumrustError::IoError(_))
+        ));
+
+        assert!(read_npy::<f64, _>(&b"not an npy file"[..]).is_err());
+        let bytes = npy_bytes(1, "[('a', '<i4')]", false, "(1,)", &[0; 4]);
+        assert!(read_npy::<i32, _>(bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_element_types() {
+        fn round_trip<T: NpyElement + PartialEq + std::fmt::Debug>(values: Vec<T>) {
+            let a = Array3::from_shape_vec((1, 2, values.len() / 2), values).unwrap();
+            let mut bytes = Vec::new();
+            write_npy(&mut bytes, &a.view().reversed_axes()).unwrap();
+            let loaded = read_npy::<T, _>(bytes.as_slice()).unwrap();
+            assert_eq!(loaded, a.reversed_axes().into_dyn());
+        }
+        round_trip(vec![true, false, false, true]);
+        round_trip(vec![i8::MIN, 0, 1, i8::MAX]);
+        round_trip(vec![u64::MAX, 0, 1, 2]);
+        round_trip(vec![-1.5f32, 0.0, f32::INFINITY, 2.0]);
+        round_trip(vec![Complex64::new(1.0, -2.0), Complex64::new(0.5, 3.0)]);
+    }
+
+    #[test]
+    fn test_npz_in_memory_with_mixed_types() {
+        let mut writer = NpzWriter::new(Cursor::new(Vec::new()), true);
+        writer
+            .add_array("ints", &array![[1i64, 2], [3, 4]])
+            .unwrap();
+        writer
+            .add_array("cplx", &array![Complex32::new(1.0, 1.0)])
+            .unwrap();
+        let buffer = writer.finish().unwrap().into_inner();
+        let npz = read_npz(Cursor::new(buffer)).unwrap();
+        assert_eq!(npz.names(), vec!["ints", "cplx"]);
+        assert_eq!(npz.get::<i64>("ints").unwrap().shape(), &[2, 2]);
+        assert!(npz.get::<i32>("ints").is_err());
+        assert!(npz.get::<i64>("missing").is_err());
+    }
+
+    #[test]
+    fn test_npz_sizes_are_not_trusted() {
+        let mut writer = NpzWriter::new(Cursor::new(Vec::new()), false);
+        writer.add_array("a", &array![1u8, 2, 3]).unwrap();
+        let mut buffer = writer.finish().unwrap().into_inner();
+        // Claim an uncompressed size of nearly 4 GiB in the central directory.
+        let central = buffer.windows(4).rposition(|w| w == b"PK\x01\x02").unwrap();
+        buffer[central + 24..central + 28].copy_from_slice(&0xffff_fff0u32.to_le_bytes());
+        let npz = read_npz(Cursor::new(buffer)).unwrap();
+        assert_eq!(npz.get::<u8>("a").unwrap(), array![1u8, 2, 3].into_dyn());
+    }
+}
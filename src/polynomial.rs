@@ -0,0 +1,1355 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use ndarray::{Array1, Array2};
+
+use crate::complex::Complex64;
+use crate::linalg::{eigvals, svd};
+use crate::NumrustError;
+
+/// Evaluates the polynomial `p` at each value of `x`.
+///
+/// # Arguments
+///
+/// * `p` - The coefficients, highest degree first, as in `numpy.polyval`.
+/// * `x` - The points at which to evaluate the polynomial.
+///
+/// # Returns
+///
+/// A `Vec<f64>` with `p[0] * x^(n-1) + ... + p[n-1]` for every value of `x`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::polynomial::polyval;
+///
+/// // 3x^2 + 0x + 1
+/// assert_eq!(polyval(&[3.0, 0.0, 1.0], &[0.0, 1.0, 2.0]), vec![1.0, 4.0, 13.0]);
+/// ```
+pub fn polyval<T: Into<f64> + Copy>(p: &[f64], x: &[T]) -> Vec<f64> {
+    x.iter()
+        .map(|&v| {
+            let v = v.into();
+            p.iter().fold(0.0, |acc, &c| acc * v + c)
+        })
+        .collect()
+}
+
+/// The result of a weighted least-squares fit: the coefficients, in the column order of the
+/// design matrix, the unscaled covariance `inv(A^T A)` and the weighted residual sum of squares.
+struct LeastSquaresFit {
+    coef: Vec<f64>,
+    cov: Array2<f64>,
+    residuals: f64,
+}
+
+/// Solves the weighted least-squares problem `min ||w * (vander c - y)||`. The columns of the
+/// design matrix are scaled to unit norm before the SVD, as NumPy does, to improve conditioning.
+fn least_squares(
+    mut vander: Array2<f64>,
+    y: &[f64],
+    w: Option<&[f64]>,
+) -> Result<LeastSquaresFit, NumrustError> {
+    let (n, order) = vander.dim();
+    if n == 0 {
+        return Err(NumrustError::ValueError(
+            "expected non-empty vector for x".to_string(),
+        ));
+    }
+    if y.len() != n {
+        return Err(NumrustError::ValueError(
+            "expected x and y to have same length".to_string(),
+        ));
+    }
+    let mut rhs = Array1::from(y.to_vec());
+    if let Some(w) = w {
+        if w.len() != n {
+            return Err(NumrustError::ValueError(
+                "expected w and y to have the same length".to_string(),
+            ));
+        }
+        for (i, &wi) in w.iter().enumerate() {
+            vander.row_mut(i).mapv_inplace(|v| v * wi);
+            rhs[i] *= wi;
+        }
+    }
+
+    let scale: Vec<f64> = vander
+        .columns()
+        .into_iter()
+        .map(|c| {
+            let norm = c.dot(&c).sqrt();
+            if norm == 0.0 {
+                1.0
+            } else {
+                norm
+            }
+        })
+        .collect();
+    let mut lhs = vander.clone();
+    for (j, &s) in scale.iter().enumerate() {
+        lhs.column_mut(j).mapv_inplace(|v| v / s);
+    }
+
+    let (u, s, vt) = svd(&lhs)?;
+    let cutoff = n as f64 * f64::EPSILON * s.first().copied().unwrap_or(0.0);
+    let mut scaled_coef = Array1::<f64>::zeros(order);
+    let mut cov = Array2::<f64>::zeros((order, order));
+    for (k, &sigma) in s.iter().enumerate() {
+        if sigma > cutoff {
+            scaled_coef.scaled_add(u.column(k).dot(&rhs) / sigma, &vt.row(k));
+            let v = vt.row(k);
+            for i in 0..order {
+                for j in 0..order {
+                    cov[[i, j]] += v[i] * v[j] / (sigma * sigma);
+                }
+            }
+        }
+    }
+    let residuals = (&rhs - &lhs.dot(&scaled_coef)).mapv(|r| r * r).sum();
+    for i in 0..order {
+        for j in 0..order {
+            cov[[i, j]] /= scale[i] * scale[j];
+        }
+    }
+    let coef = scaled_coef.iter().zip(&scale).map(|(c, s)| c / s).collect();
+    Ok(LeastSquaresFit {
+        coef,
+        cov,
+        residuals,
+    })
+}
+
+fn power_vander(x: &[f64], deg: usize) -> Array2<f64> {
+    Array2::from_shape_fn((x.len(), deg + 1), |(i, j)| x[i].powi((deg - j) as i32))
+}
+
+/// Fits a polynomial of degree `deg` to the points `(x, y)` by least squares.
+///
+/// # Arguments
+///
+/// * `x` - The x-coordinates of the sample points.
+/// * `y` - The y-coordinates of the sample points.
+/// * `deg` - The degree of the fitted polynomial.
+/// * `w` - Optional weights applied to the residuals. For Gaussian uncertainties use `1 / sigma`.
+///
+/// # Returns
+///
+/// The `deg + 1` coefficients, highest degree first, minimising
+/// `sum(w[i]^2 * (y[i] - p(x[i]))^2)`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::polynomial::polyfit;
+/// use approx::assert_abs_diff_eq;
+///
+/// let x = [0.0, 1.0, 2.0, 3.0];
+/// let y = [1.0, 3.0, 7.0, 13.0]; // x^2 + x + 1
+/// let p = polyfit(&x, &y, 2, None).unwrap();
+/// for (c, expected) in p.iter().zip([1.0, 1.0, 1.0]) {
+///     assert_abs_diff_eq!(*c, expected, epsilon = 1e-10);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `x` is empty or `x`, `y` and `w` have different
+/// lengths, or a `NumrustError::LinAlgError` if the least-squares solve fails.
+pub fn polyfit(
+    x: &[f64],
+    y: &[f64],
+    deg: usize,
+    w: Option<&[f64]>,
+) -> Result<Vec<f64>, NumrustError> {
+    Ok(least_squares(power_vander(x, deg), y, w)?.coef)
+}
+
+/// Fits a polynomial like [`polyfit`] and also returns the covariance matrix of the
+/// coefficients.
+///
+/// # Arguments
+///
+/// * `x`, `y`, `deg`, `w` - As in [`polyfit`].
+/// * `unscaled` - If `true`, the covariance is `inv(A^T A)` for the weighted design matrix `A`,
+///   appropriate when the weights are `1 / sigma` of known uncertainties. Otherwise it is scaled
+///   by `chi^2 / (n - deg - 1)`, matching `numpy.polyfit(..., cov=True)`.
+///
+/// # Returns
+///
+/// A tuple of the coefficients, highest degree first, and the `(deg + 1) x (deg + 1)`
+/// covariance matrix in the same order.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::polynomial::polyfit_with_cov;
+/// use approx::assert_abs_diff_eq;
+///
+/// let (p, cov) = polyfit_with_cov(&[0.0, 1.0, 2.0], &[1.0, 3.0, 5.0], 1, None, true).unwrap();
+/// assert_abs_diff_eq!(p[0], 2.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(cov[[0, 0]], 0.5, epsilon = 1e-12);
+/// assert_abs_diff_eq!(cov[[1, 1]], 5.0 / 6.0, epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns the errors of [`polyfit`], and a `NumrustError::ValueError` if the covariance is to
+/// be scaled but there are no more points than coefficients.
+pub fn polyfit_with_cov(
+    x: &[f64],
+    y: &[f64],
+    deg: usize,
+    w: Option<&[f64]>,
+    unscaled: bool,
+) -> Result<(Vec<f64>, Array2<f64>), NumrustError> {
+    let fit = least_squares(power_vander(x, deg), y, w)?;
+    if unscaled {
+        return Ok((fit.coef, fit.cov));
+    }
+    let order = deg + 1;
+    if x.len() <= order {
+        return Err(NumrustError::ValueError(
+            "the number of data points must exceed order to scale the covariance matrix"
+                .to_string(),
+        ));
+    }
+    let factor = fit.residuals / (x.len() - order) as f64;
+    Ok((fit.coef, fit.cov * factor))
+}
+
+/// Returns the roots of the polynomial `p`, computed as the eigenvalues of its companion matrix.
+///
+/// # Arguments
+///
+/// * `p` - The coefficients, highest degree first.
+///
+/// # Returns
+///
+/// The `n - 1` complex roots of a polynomial with `n` coefficients after leading zeros are
+/// removed, in no particular order. A constant polynomial has no roots.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::polynomial::roots;
+/// use approx::assert_abs_diff_eq;
+///
+/// // x^2 - 3x + 2 = (x - 1)(x - 2)
+/// let mut r: Vec<f64> = roots(&[1.0, -3.0, 2.0]).unwrap().iter().map(|z| z.re).collect();
+/// r.sort_by(f64::total_cmp);
+/// assert_abs_diff_eq!(r[0], 1.0, epsilon = 1e-12);
+/// assert_abs_diff_eq!(r[1], 2.0, epsilon = 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::LinAlgError` if the coefficients are not finite or the eigenvalue
+/// iteration does not converge.
+pub fn roots(p: &[f64]) -> Result<Vec<Complex64>, NumrustError> {
+    let start = match p.iter().position(|&c| c != 0.0) {
+        Some(start) => start,
+        None => return Ok(Vec::new()),
+    };
+    let end = p.iter().rposition(|&c| c != 0.0).unwrap();
+    let trailing_zeros = p.len() - 1 - end;
+    let p = &p[start..=end];
+
+    let n = p.len() - 1;
+    let mut result = Vec::with_capacity(n + trailing_zeros);
+    if n > 0 {
+        let mut companion = Array2::<f64>::zeros((n, n));
+        for j in 0..n {
+            companion[[0, j]] = -p[j + 1] / p[0];
+        }
+        for i in 1..n {
+            companion[[i, i - 1]] = 1.0;
+        }
+        result.extend(eigvals(&companion)?);
+    }
+    result.extend(std::iter::repeat_n(
+        Complex64::new(0.0, 0.0),
+        trailing_zeros,
+    ));
+    Ok(result)
+}
+
+/// Returns the `m`-th derivative of the polynomial `p`, coefficients highest degree first.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::polynomial::polyder;
+///
+/// // d/dx (x^3 + 2x^2 + 3x + 4) = 3x^2 + 4x + 3
+/// assert_eq!(polyder(&[1.0, 2.0, 3.0, 4.0], 1), vec![3.0, 4.0, 3.0]);
+/// assert_eq!(polyder(&[1.0, 2.0, 3.0, 4.0], 2), vec![6.0, 4.0]);
+/// // Differentiating past the degree leaves the zero polynomial
+/// assert_eq!(polyder(&[1.0, 2.0], 3), vec![0.0]);
+/// ```
+pub fn polyder(p: &[f64], m: usize) -> Vec<f64> {
+    let mut result = p.to_vec();
+    for _ in 0..m {
+        let n = result.len().saturating_sub(1);
+        result = result
+            .iter()
+            .take(n)
+            .enumerate()
+            .map(|(i, &c)| c * (n - i) as f64)
+            .collect();
+    }
+    if result.is_empty() {
+        result.push(0.0);
+    }
+    result
+}
+
+/// Returns the `m`-th antiderivative of the polynomial `p`, coefficients highest degree first.
+///
+/// # Arguments
+///
+/// * `p` - The coefficients, highest degree first.
+/// * `m` - The order of the antiderivative.
+/// * `k` - The integration constants, one per integration, with `k[0]` added by the first.
+///   `None` uses zeros.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::polynomial::polyint;
+///
+/// // The antiderivative of 3x^2 + 2 with constant 5 is x^3 + 2x + 5.
+/// assert_eq!(polyint(&[3.0, 0.0, 2.0], 1, Some(&[5.0])).unwrap(), vec![1.0, 0.0, 2.0, 5.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `k` does not have `m` entries.
+pub fn polyint(p: &[f64], m: usize, k: Option<&[f64]>) -> Result<Vec<f64>, NumrustError> {
+    let constants = match k {
+        Some(k) if k.len() != m => {
+            return Err(NumrustError::ValueError(
+                "k must be a scalar or a sequence of length m".to_string(),
+            ))
+        }
+        Some(k) => k.to_vec(),
+        None => vec![0.0; m],
+    };
+    let mut result = p.to_vec();
+    for constant in constants {
+        let n = result.len();
+        result = result
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| c / (n - i) as f64)
+            .chain(std::iter::once(constant))
+            .collect();
+    }
+    Ok(result)
+}
+
+/// Adds two coefficient vectors of possibly different lengths, lowest degree first.
+fn add_coef(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.0; a.len().max(b.len())];
+    for (i, &c) in a.iter().enumerate() {
+        result[i] += c;
+    }
+    for (i, &c) in b.iter().enumerate() {
+        result[i] += c;
+    }
+    result
+}
+
+/// Multiplies two coefficient vectors, lowest degree first.
+fn mul_coef(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            result[i + j] += x * y;
+        }
+    }
+    result
+}
+
+/// Removes trailing (highest-degree) zeros, always keeping at least one coefficient.
+fn trim_coef(mut coef: Vec<f64>) -> Vec<f64> {
+    while coef.len() > 1 && coef[coef.len() - 1] == 0.0 {
+        coef.pop();
+    }
+    if coef.is_empty() {
+        coef.push(0.0);
+    }
+    coef
+}
+
+/// A power series `c[0] + c[1] x + c[2] x^2 + ...`, with coefficients stored lowest degree first
+/// like `numpy.polynomial.Polynomial`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coef: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients, lowest degree first. An empty vector is the
+    /// zero polynomial.
+    pub fn new(coef: Vec<f64>) -> Polynomial {
+        Polynomial {
+            coef: if coef.is_empty() { vec![0.0] } else { coef },
+        }
+    }
+
+    /// The coefficients, lowest degree first.
+    pub fn coef(&self) -> &[f64] {
+        &self.coef
+    }
+
+    /// The degree of the series, i.e. the number of coefficients minus one.
+    pub fn degree(&self) -> usize {
+        self.coef.len() - 1
+    }
+
+    /// Returns a copy with trailing coefficients whose absolute value is at most `tol` removed.
+    pub fn trim(&self, tol: f64) -> Polynomial {
+        let end = self
+            .coef
+            .iter()
+            .rposition(|c| c.abs() > tol)
+            .map_or(0, |i| i + 1);
+        Polynomial::new(self.coef[..end].to_vec())
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's scheme.
+    pub fn eval(&self, x: f64) -> f64 {
+        self.coef.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+    }
+
+    /// Returns the `m`-th derivative.
+    pub fn deriv(&self, m: usize) -> Polynomial {
+        let mut high_first: Vec<f64> = self.coef.iter().rev().copied().collect();
+        high_first = polyder(&high_first, m);
+        Polynomial::new(high_first.into_iter().rev().collect())
+    }
+
+    /// Returns the `m`-th antiderivative, adding the constant `k[i]` after the `i`-th
+    /// integration (zeros when `k` is shorter than `m`).
+    pub fn integ(&self, m: usize, k: &[f64]) -> Polynomial {
+        let mut coef = self.coef.clone();
+        for i in 0..m {
+            let constant = k.get(i).copied().unwrap_or(0.0);
+            coef = std::iter::once(constant)
+                .chain(coef.iter().enumerate().map(|(j, &c)| c / (j + 1) as f64))
+                .collect();
+        }
+        Polynomial::new(coef)
+    }
+
+    /// Returns the roots of the polynomial.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::LinAlgError` if the eigenvalue iteration does not converge.
+    pub fn roots(&self) -> Result<Vec<Complex64>, NumrustError> {
+        let high_first: Vec<f64> = self.coef.iter().rev().copied().collect();
+        roots(&high_first)
+    }
+
+    /// Fits a polynomial of degree `deg` to the points `(x, y)` by weighted least squares.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`polyfit`].
+    pub fn fit(
+        x: &[f64],
+        y: &[f64],
+        deg: usize,
+        w: Option<&[f64]>,
+    ) -> Result<Polynomial, NumrustError> {
+        let high_first = polyfit(x, y, deg, w)?;
+        Ok(Polynomial::new(high_first.into_iter().rev().collect()))
+    }
+
+    /// Divides `self` by `divisor`, returning the quotient and remainder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::polynomial::Polynomial;
+    ///
+    /// // (x^2 - 1) = (x + 1)(x - 1) + 0
+    /// let (q, r) = Polynomial::new(vec![-1.0, 0.0, 1.0])
+    ///     .divmod(&Polynomial::new(vec![-1.0, 1.0]))
+    ///     .unwrap();
+    /// assert_eq!(q.coef(), &[1.0, 1.0]);
+    /// assert_eq!(r.coef(), &[0.0]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if `divisor` is the zero polynomial.
+    pub fn divmod(&self, divisor: &Polynomial) -> Result<(Polynomial, Polynomial), NumrustError> {
+        let divisor = trim_coef(divisor.coef.clone());
+        let lead = divisor[divisor.len() - 1];
+        if lead == 0.0 {
+            return Err(NumrustError::ValueError(
+                "division by the zero polynomial".to_string(),
+            ));
+        }
+        let mut remainder = trim_coef(self.coef.clone());
+        if remainder.len() < divisor.len() {
+            return Ok((Polynomial::new(vec![0.0]), Polynomial::new(remainder)));
+        }
+        let shift = remainder.len() - divisor.len();
+        let mut quotient = vec![0.0; shift + 1];
+        for i in (0..=shift).rev() {
+            let q = remainder[i + divisor.len() - 1] / lead;
+            quotient[i] = q;
+            for (j, &d) in divisor.iter().enumerate() {
+                remainder[i + j] -= q * d;
+            }
+        }
+        remainder.truncate(divisor.len() - 1);
+        Ok((
+            Polynomial::new(quotient),
+            Polynomial::new(trim_coef(remainder)),
+        ))
+    }
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: &Polynomial) -> Polynomial {
+        Polynomial::new(add_coef(&self.coef, &other.coef))
+    }
+}
+
+impl Sub for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: &Polynomial) -> Polynomial {
+        self + &(-other)
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        Polynomial::new(mul_coef(&self.coef, &other.coef))
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        Polynomial::new(self.coef.iter().map(|c| -c).collect())
+    }
+}
+
+/// A family of orthogonal polynomials defined by the three-term recurrence
+/// `P[0] = 1`, `P[1] = x`, `P[k+1] = alpha(k) * x * P[k] - beta(k) * P[k-1]`, together with the
+/// basis-specific rules for differentiating and integrating a series.
+pub trait OrthogonalBasis {
+    /// The coefficient of `x * P[k]` in the recurrence for `P[k+1]`, for `k >= 1`.
+    fn alpha(k: usize) -> f64;
+
+    /// The coefficient of `P[k-1]` in the recurrence for `P[k+1]`, for `k >= 1`.
+    fn beta(k: usize) -> f64;
+
+    /// Differentiates the series with coefficients `coef` once, returning the coefficients of
+    /// the derivative in the same basis. `coef` has at least two entries.
+    fn differentiate(coef: &[f64]) -> Vec<f64>;
+
+    /// Integrates the series with coefficients `coef` once, returning the coefficients of an
+    /// antiderivative in the same basis with one more entry. Any constant term may be returned;
+    /// callers fix the integration constant afterwards.
+    fn integrate(coef: &[f64]) -> Vec<f64>;
+}
+
+/// The Chebyshev polynomials of the first kind, `T[k+1] = 2x T[k] - T[k-1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChebyshevBasis;
+
+impl OrthogonalBasis for ChebyshevBasis {
+    fn alpha(_k: usize) -> f64 {
+        2.0
+    }
+
+    fn beta(_k: usize) -> f64 {
+        1.0
+    }
+
+    // T[j]' = 2j T[j-1] + j / (j - 2) T[j-2]', walked down from the top as in `numpy.chebder`.
+    fn differentiate(coef: &[f64]) -> Vec<f64> {
+        let n = coef.len() - 1;
+        let mut c = coef.to_vec();
+        let mut der = vec![0.0; n];
+        for j in (3..=n).rev() {
+            der[j - 1] = (2 * j) as f64 * c[j];
+            c[j - 2] += j as f64 * c[j] / (j - 2) as f64;
+        }
+        if n > 1 {
+            der[1] = 4.0 * c[2];
+        }
+        der[0] = c[1];
+        der
+    }
+
+    // The integral of T[j] is T[j+1] / (2(j+1)) - T[j-1] / (2(j-1)), as in `numpy.chebint`.
+    fn integrate(coef: &[f64]) -> Vec<f64> {
+        let n = coef.len();
+        let mut int = vec![0.0; n + 1];
+        int[1] = coef[0];
+        if n > 1 {
+            int[2] = coef[1] / 4.0;
+        }
+        for j in 2..n {
+            int[j + 1] = coef[j] / (2 * (j + 1)) as f64;
+            int[j - 1] -= coef[j] / (2 * (j - 1)) as f64;
+        }
+        int
+    }
+}
+
+/// The Legendre polynomials, `(k+1) P[k+1] = (2k+1) x P[k] - k P[k-1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegendreBasis;
+
+impl OrthogonalBasis for LegendreBasis {
+    fn alpha(k: usize) -> f64 {
+        (2 * k + 1) as f64 / (k + 1) as f64
+    }
+
+    fn beta(k: usize) -> f64 {
+        k as f64 / (k + 1) as f64
+    }
+
+    // P[j]' = (2j - 1) P[j-1] + P[j-2]', walked down from the top as in `numpy.legder`.
+    fn differentiate(coef: &[f64]) -> Vec<f64> {
+        let n = coef.len() - 1;
+        let mut c = coef.to_vec();
+        let mut der = vec![0.0; n];
+        for j in (3..=n).rev() {
+            der[j - 1] = (2 * j - 1) as f64 * c[j];
+            c[j - 2] += c[j];
+        }
+        if n > 1 {
+            der[1] = 3.0 * c[2];
+        }
+        der[0] = c[1];
+        der
+    }
+
+    // The integral of P[j] is (P[j+1] - P[j-1]) / (2j + 1), as in `numpy.legint`.
+    fn integrate(coef: &[f64]) -> Vec<f64> {
+        let n = coef.len();
+        let mut int = vec![0.0; n + 1];
+        int[1] = coef[0];
+        if n > 1 {
+            int[2] = coef[1] / 3.0;
+        }
+        for j in 2..n {
+            let t = coef[j] / (2 * j + 1) as f64;
+            int[j + 1] = t;
+            int[j - 1] -= t;
+        }
+        int
+    }
+}
+
+/// A series `c[0] P[0](t) + c[1] P[1](t) + ...` in an orthogonal polynomial basis.
+///
+/// As in `numpy.polynomial`, the series is evaluated at `t = off + scl * x`, the linear map
+/// that sends its `domain` onto its `window`. Both default to `[-1, 1]`, and [`fit`](Self::fit)
+/// sets the domain to the range of the data so that the basis is used where it is well
+/// conditioned. Calculus, roots, multiplication and division all work on the coefficients in
+/// the basis itself rather than going through power series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrthogonalSeries<B: OrthogonalBasis> {
+    coef: Vec<f64>,
+    domain: [f64; 2],
+    window: [f64; 2],
+    basis: PhantomData<B>,
+}
+
+/// A Chebyshev series, like `numpy.polynomial.Chebyshev`.
+pub type Chebyshev = OrthogonalSeries<ChebyshevBasis>;
+
+/// A Legendre series, like `numpy.polynomial.Legendre`.
+pub type Legendre = OrthogonalSeries<LegendreBasis>;
+
+/// The default domain and window of a series.
+const DEFAULT_WINDOW: [f64; 2] = [-1.0, 1.0];
+
+impl<B: OrthogonalBasis> OrthogonalSeries<B> {
+    /// Creates a series from its coefficients, lowest degree first, with the default domain and
+    /// window `[-1, 1]`. An empty vector is the zero series.
+    pub fn new(coef: Vec<f64>) -> Self {
+        OrthogonalSeries {
+            coef: if coef.is_empty() { vec![0.0] } else { coef },
+            domain: DEFAULT_WINDOW,
+            window: DEFAULT_WINDOW,
+            basis: PhantomData,
+        }
+    }
+
+    /// Creates a series whose argument is mapped linearly from `domain` onto `window` before the
+    /// basis polynomials are evaluated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::polynomial::Chebyshev;
+    ///
+    /// // T1 on [0, 10] is the line through (0, -1) and (10, 1).
+    /// let series = Chebyshev::with_domain(vec![0.0, 1.0], [0.0, 10.0], [-1.0, 1.0]).unwrap();
+    /// assert_eq!(series.eval(5.0), 0.0);
+    /// assert_eq!(series.eval(10.0), 1.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if `domain` or `window` has equal or non-finite
+    /// ends.
+    pub fn with_domain(
+        coef: Vec<f64>,
+        domain: [f64; 2],
+        window: [f64; 2],
+    ) -> Result<Self, NumrustError> {
+        let valid = |ends: [f64; 2]| ends.iter().all(|e| e.is_finite()) && ends[0] != ends[1];
+        if !valid(domain) || !valid(window) {
+            return Err(NumrustError::ValueError(
+                "domain and window must have distinct finite ends".to_string(),
+            ));
+        }
+        let mut series = Self::new(coef);
+        series.domain = domain;
+        series.window = window;
+        Ok(series)
+    }
+
+    /// The coefficients, lowest degree first.
+    pub fn coef(&self) -> &[f64] {
+        &self.coef
+    }
+
+    /// The degree of the series, i.e. the number of coefficients minus one.
+    pub fn degree(&self) -> usize {
+        self.coef.len() - 1
+    }
+
+    /// The interval of `x` that is mapped onto the window.
+    pub fn domain(&self) -> [f64; 2] {
+        self.domain
+    }
+
+    /// The interval of the basis polynomials' argument that the domain is mapped onto.
+    pub fn window(&self) -> [f64; 2] {
+        self.window
+    }
+
+    /// Returns `(off, scl)` such that `off + scl * x` maps the domain onto the window.
+    pub fn map_parameters(&self) -> (f64, f64) {
+        let [d0, d1] = self.domain;
+        let [w0, w1] = self.window;
+        let length = d1 - d0;
+        ((d1 * w0 - d0 * w1) / length, (w1 - w0) / length)
+    }
+
+    /// Returns a series with coefficients `coef` and the domain and window of `self`.
+    fn with_coef(&self, coef: Vec<f64>) -> Self {
+        OrthogonalSeries {
+            coef: if coef.is_empty() { vec![0.0] } else { coef },
+            domain: self.domain,
+            window: self.window,
+            basis: PhantomData,
+        }
+    }
+
+    /// Panics unless `self` and `other` share a domain and window.
+    fn assert_same_mapping(&self, other: &Self) {
+        assert!(
+            self.domain == other.domain && self.window == other.window,
+            "Domains differ"
+        );
+    }
+
+    /// The values of the first `deg + 1` basis polynomials at `t`.
+    fn basis_values(t: f64, deg: usize) -> Vec<f64> {
+        let mut values = Vec::with_capacity(deg + 1);
+        values.push(1.0);
+        if deg >= 1 {
+            values.push(t);
+        }
+        for k in 1..deg {
+            values.push(B::alpha(k) * t * values[k] - B::beta(k) * values[k - 1]);
+        }
+        values
+    }
+
+    /// Evaluates the series with coefficients `coef` at the window point `t`.
+    fn eval_coef(coef: &[f64], t: f64) -> f64 {
+        Self::basis_values(t, coef.len() - 1)
+            .iter()
+            .zip(coef)
+            .map(|(p, c)| p * c)
+            .sum()
+    }
+
+    /// Multiplies the series with coefficients `coef` by its argument, using
+    /// `t P[0] = P[1]` and `t P[k] = (P[k+1] + beta(k) P[k-1]) / alpha(k)`.
+    fn mul_t(coef: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; coef.len() + 1];
+        result[1] = coef[0];
+        for (k, &c) in coef.iter().enumerate().skip(1) {
+            result[k + 1] += c / B::alpha(k);
+            result[k - 1] += c * B::beta(k) / B::alpha(k);
+        }
+        result
+    }
+
+    /// Multiplies two coefficient vectors in this basis, summing `b[k] P[k] a` with each
+    /// `P[k] a` built by the recurrence.
+    fn mul_series(a: &[f64], b: &[f64]) -> Vec<f64> {
+        let mut result = a.iter().map(|c| c * b[0]).collect::<Vec<f64>>();
+        if b.len() == 1 {
+            return result;
+        }
+        let mut previous = a.to_vec();
+        let mut current = Self::mul_t(a);
+        result = add_coef(
+            &result,
+            &current.iter().map(|c| c * b[1]).collect::<Vec<f64>>(),
+        );
+        for (k, &c) in b.iter().enumerate().skip(2) {
+            let shifted = Self::mul_t(&current);
+            let lower: Vec<f64> = previous.iter().map(|p| -p * B::beta(k - 1)).collect();
+            let next = add_coef(
+                &shifted
+                    .iter()
+                    .map(|s| s * B::alpha(k - 1))
+                    .collect::<Vec<f64>>(),
+                &lower,
+            );
+            result = add_coef(&result, &next.iter().map(|v| v * c).collect::<Vec<f64>>());
+            previous = current;
+            current = next;
+        }
+        result
+    }
+
+    /// The power series coefficients of the first `deg + 1` basis polynomials.
+    fn basis_polynomials(deg: usize) -> Vec<Vec<f64>> {
+        let mut polys: Vec<Vec<f64>> = vec![vec![1.0]];
+        if deg >= 1 {
+            polys.push(vec![0.0, 1.0]);
+        }
+        for k in 1..deg {
+            let shifted: Vec<f64> = std::iter::once(0.0)
+                .chain(polys[k].iter().map(|c| c * B::alpha(k)))
+                .collect();
+            let lower: Vec<f64> = polys[k - 1].iter().map(|c| -c * B::beta(k)).collect();
+            polys.push(add_coef(&shifted, &lower));
+        }
+        polys
+    }
+
+    /// Evaluates the series at `x`.
+    pub fn eval(&self, x: f64) -> f64 {
+        let (off, scl) = self.map_parameters();
+        Self::eval_coef(&self.coef, off + scl * x)
+    }
+
+    /// Converts the series to a power series in `x`, composing with the domain mapping.
+    pub fn to_polynomial(&self) -> Polynomial {
+        let mut coef = vec![0.0];
+        for (c, p) in self.coef.iter().zip(Self::basis_polynomials(self.degree())) {
+            let term: Vec<f64> = p.iter().map(|v| v * c).collect();
+            coef = add_coef(&coef, &term);
+        }
+        let (off, scl) = self.map_parameters();
+        if (off, scl) == (0.0, 1.0) {
+            return Polynomial::new(coef);
+        }
+        let t = Polynomial::new(vec![off, scl]);
+        coef.iter()
+            .rev()
+            .fold(Polynomial::new(vec![0.0]), |acc, &c| {
+                &(&acc * &t) + &Polynomial::new(vec![c])
+            })
+    }
+
+    /// Converts a power series to this basis, with the default domain and window.
+    pub fn from_polynomial(p: &Polynomial) -> Self {
+        let deg = p.degree();
+        let basis = Self::basis_polynomials(deg);
+        let mut remaining = p.coef().to_vec();
+        let mut coef = vec![0.0; deg + 1];
+        // Each basis polynomial P[k] has degree k, so peel off the highest power each time.
+        for k in (0..=deg).rev() {
+            let c = remaining[k] / basis[k][k];
+            coef[k] = c;
+            for (j, v) in basis[k].iter().enumerate() {
+                remaining[j] -= c * v;
+            }
+        }
+        Self::new(coef)
+    }
+
+    /// Returns the `m`-th derivative with respect to `x`, computed in the basis itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::polynomial::Chebyshev;
+    ///
+    /// // d/dx T3 = 3 T0 + 6 T2.
+    /// let t3 = Chebyshev::new(vec![0.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(t3.deriv(1).coef(), &[3.0, 0.0, 6.0]);
+    /// ```
+    pub fn deriv(&self, m: usize) -> Self {
+        let (_, scl) = self.map_parameters();
+        let mut coef = self.coef.clone();
+        for _ in 0..m {
+            if coef.len() == 1 {
+                coef = vec![0.0];
+                break;
+            }
+            let scaled: Vec<f64> = coef.iter().map(|c| c * scl).collect();
+            coef = B::differentiate(&scaled);
+        }
+        self.with_coef(coef)
+    }
+
+    /// Returns the `m`-th antiderivative with respect to `x`, computed in the basis itself.
+    /// Each integration constant is chosen so that the antiderivative vanishes where the window
+    /// argument is zero, which is `x = 0` for the default domain, as in `numpy.polynomial`.
+    pub fn integ(&self, m: usize) -> Self {
+        let (_, scl) = self.map_parameters();
+        let mut coef = self.coef.clone();
+        for _ in 0..m {
+            if coef.iter().all(|&c| c == 0.0) {
+                continue;
+            }
+            let scaled: Vec<f64> = coef.iter().map(|c| c / scl).collect();
+            coef = B::integrate(&scaled);
+            coef[0] -= Self::eval_coef(&coef, 0.0);
+        }
+        self.with_coef(coef)
+    }
+
+    /// Returns the roots of the series as the eigenvalues of its comrade matrix, the matrix of
+    /// multiplication by the argument modulo the series, mapped back from the window to the
+    /// domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::LinAlgError` if the eigenvalue iteration does not converge.
+    pub fn roots(&self) -> Result<Vec<Complex64>, NumrustError> {
+        let coef = trim_coef(self.coef.clone());
+        let n = coef.len() - 1;
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        let mut comrade = Array2::<f64>::zeros((n, n));
+        for j in 0..n {
+            // t P[j] = P[j+1] / alpha(j) + beta(j) / alpha(j) P[j-1], with P[1] = t for j = 0.
+            let up = if j == 0 { 1.0 } else { 1.0 / B::alpha(j) };
+            if j > 0 {
+                comrade[[j - 1, j]] = B::beta(j) / B::alpha(j);
+            }
+            if j + 1 < n {
+                comrade[[j + 1, j]] = up;
+            } else {
+                // P[n] is replaced by its value modulo the series.
+                for k in 0..n {
+                    comrade[[k, j]] -= up * coef[k] / coef[n];
+                }
+            }
+        }
+        let (off, scl) = self.map_parameters();
+        Ok(eigvals(&comrade)?
+            .iter()
+            .map(|&t| (t - off) / scl)
+            .collect())
+    }
+
+    /// Fits a series of degree `deg` to the points `(x, y)` by weighted least squares, using the
+    /// basis polynomials directly as the columns of the design matrix. The domain of the result
+    /// is the range of `x`, mapped onto the default window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::polynomial::Chebyshev;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let x = [-1.0, -0.5, 0.0, 0.5, 1.0];
+    /// let y: Vec<f64> = x.iter().map(|v: &f64| 2.0 * v * v - 1.0).collect();
+    /// let series = Chebyshev::fit(&x, &y, 2, None).unwrap();
+    /// assert_abs_diff_eq!(series.coef()[2], 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(series.coef()[0], 0.0, epsilon = 1e-12);
+    ///
+    /// // Data far from [-1, 1] is fitted on its own range.
+    /// let x = [100.0, 101.0, 102.0];
+    /// let series = Chebyshev::fit(&x, &[1.0, 2.0, 3.0], 1, None).unwrap();
+    /// assert_eq!(series.domain(), [100.0, 102.0]);
+    /// assert_abs_diff_eq!(series.eval(101.5), 2.5, epsilon = 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`polyfit`].
+    pub fn fit(x: &[f64], y: &[f64], deg: usize, w: Option<&[f64]>) -> Result<Self, NumrustError> {
+        let low = x.iter().copied().fold(f64::INFINITY, f64::min);
+        let high = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mut series = Self::new(Vec::new());
+        if low.is_finite() && high.is_finite() && low < high {
+            series.domain = [low, high];
+        }
+        let (off, scl) = series.map_parameters();
+        let vander = Array2::from_shape_fn((x.len(), deg + 1), |(i, j)| {
+            Self::basis_values(off + scl * x[i], deg)[j]
+        });
+        Ok(series.with_coef(least_squares(vander, y, w)?.coef))
+    }
+
+    /// Divides `self` by `divisor`, returning the quotient and remainder in this basis. Each
+    /// step subtracts a multiple of `P[i]` times the divisor, as `numpy.polynomial` does.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if `divisor` is the zero series or the two series
+    /// have different domains or windows.
+    pub fn divmod(&self, divisor: &Self) -> Result<(Self, Self), NumrustError> {
+        if self.domain != divisor.domain || self.window != divisor.window {
+            return Err(NumrustError::ValueError("Domains differ".to_string()));
+        }
+        let divisor_coef = trim_coef(divisor.coef.clone());
+        let lead = divisor_coef[divisor_coef.len() - 1];
+        if lead == 0.0 {
+            return Err(NumrustError::ValueError(
+                "division by the zero series".to_string(),
+            ));
+        }
+        let mut remainder = trim_coef(self.coef.clone());
+        if remainder.len() < divisor_coef.len() {
+            return Ok((self.with_coef(vec![0.0]), self.with_coef(remainder)));
+        }
+        if divisor_coef.len() == 1 {
+            let quotient = remainder.iter().map(|c| c / lead).collect();
+            return Ok((self.with_coef(quotient), self.with_coef(vec![0.0])));
+        }
+        let shift = remainder.len() - divisor_coef.len();
+        let mut quotient = vec![0.0; shift + 1];
+        for i in (0..=shift).rev() {
+            let mut unit = vec![0.0; i + 1];
+            unit[i] = 1.0;
+            let product = Self::mul_series(&unit, &divisor_coef);
+            let q = remainder[remainder.len() - 1] / product[product.len() - 1];
+            remainder.pop();
+            for (r, p) in remainder.iter_mut().zip(&product) {
+                *r -= q * p;
+            }
+            quotient[i] = q;
+        }
+        Ok((
+            self.with_coef(quotient),
+            self.with_coef(trim_coef(remainder)),
+        ))
+    }
+}
+
+/// # Panics
+///
+/// Panics if the series have different domains or windows.
+impl<B: OrthogonalBasis> Add for &OrthogonalSeries<B> {
+    type Output = OrthogonalSeries<B>;
+
+    fn add(self, other: &OrthogonalSeries<B>) -> OrthogonalSeries<B> {
+        self.assert_same_mapping(other);
+        self.with_coef(add_coef(&self.coef, &other.coef))
+    }
+}
+
+/// # Panics
+///
+/// Panics if the series have different domains or windows.
+impl<B: OrthogonalBasis> Sub for &OrthogonalSeries<B> {
+    type Output = OrthogonalSeries<B>;
+
+    fn sub(self, other: &OrthogonalSeries<B>) -> OrthogonalSeries<B> {
+        self.assert_same_mapping(other);
+        let negated: Vec<f64> = other.coef.iter().map(|c| -c).collect();
+        self.with_coef(add_coef(&self.coef, &negated))
+    }
+}
+
+/// Multiplies in the basis itself.
+///
+/// # Panics
+///
+/// Panics if the series have different domains or windows.
+impl<B: OrthogonalBasis> Mul for &OrthogonalSeries<B> {
+    type Output = OrthogonalSeries<B>;
+
+    fn mul(self, other: &OrthogonalSeries<B>) -> OrthogonalSeries<B> {
+        self.assert_same_mapping(other);
+        self.with_coef(OrthogonalSeries::<B>::mul_series(&self.coef, &other.coef))
+    }
+}
+
+#[cfg(test)]
+mod numrust_polynomial_tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    fn assert_coef_eq(actual: &[f64], expected: &[f64]) {
+        assert_eq!(
+            actual.len(),
+            expected.len(),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+        for (a, e) in actual.iter().zip(expected) {
+            assert_abs_diff_eq!(*a, *e, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_polyval() {
+        assert_eq!(polyval(&[1.0, -2.0, 1.0], &[1, 3]), vec![0.0, 4.0]);
+        assert_eq!(polyval(&[], &[5.0]), vec![0.0]);
+    }
+
+    #[test]
+    fn test_polyfit_exact_and_weighted() {
+        let x: Vec<f64> = (0..10).map(|i| i as f64 * 0.5).collect();
+        let y: Vec<f64> = x.iter().map(|v| 0.5 * v * v * v - v + 2.0).collect();
+        assert_coef_eq(&polyfit(&x, &y, 3, None).unwrap(), &[0.5, 0.0, -1.0, 2.0]);
+
+        // A huge weight on the first point pulls a straight line through it.
+        let x = [0.0, 1.0, 2.0];
+        let y = [0.0, 1.0, 0.0];
+        let flat = polyfit(&x, &y, 0, None).unwrap();
+        assert_abs_diff_eq!(flat[0], 1.0 / 3.0, epsilon = 1e-12);
+        let pinned = polyfit(&x, &y, 0, Some(&[1000.0, 1.0, 1.0])).unwrap();
+        assert!(pinned[0].abs() < 1e-5);
+
+        assert!(polyfit(&x, &[1.0, 2.0], 1, None).is_err());
+        assert!(polyfit(&x, &y, 1, Some(&[1.0])).is_err());
+        assert!(polyfit(&[], &[], 1, None).is_err());
+    }
+
+    #[test]
+    fn test_polyfit_covariance() {
+        let x = [0.0, 1.0, 2.0, 3.0];
+        let y = [1.0, 2.0, 2.0, 4.0];
+        let (p, cov) = polyfit_with_cov(&x, &y, 1, None, false).unwrap();
+        assert_coef_eq(&p, &[0.9, 0.9]);
+        // Residuals are [0.1, 0.2, -0.7, 0.4], so chi^2 = 0.7 over 2 degrees of freedom, and
+        // inv(A^T A) = [[0.2, -0.3], [-0.3, 0.7]].
+        assert_abs_diff_eq!(cov[[0, 0]], 0.2 * 0.35, epsilon = 1e-12);
+        assert_abs_diff_eq!(cov[[0, 1]], -0.3 * 0.35, epsilon = 1e-12);
+        assert_abs_diff_eq!(cov[[1, 1]], 0.7 * 0.35, epsilon = 1e-12);
+        assert!(polyfit_with_cov(&x[..2], &y[..2], 1, None, false).is_err());
+    }
+
+    #[test]
+    fn test_roots() {
+        let mut r: Vec<f64> = roots(&[1.0, -6.0, 11.0, -6.0])
+            .unwrap()
+            .iter()
+            .map(|z| z.re)
+            .collect();
+        r.sort_by(f64::total_cmp);
+        assert_coef_eq(&r, &[1.0, 2.0, 3.0]);
+
+        let mut r = roots(&[0.0, 1.0, 0.0, 1.0]).unwrap();
+        r.sort_by(|a, b| a.im.total_cmp(&b.im));
+        assert_abs_diff_eq!(r[0].im, -1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(r[1].im, 1.0, epsilon = 1e-12);
+
+        assert_eq!(
+            roots(&[2.0, 0.0, 0.0]).unwrap(),
+            vec![Complex64::new(0.0, 0.0); 2]
+        );
+        assert!(roots(&[0.0, 0.0]).unwrap().is_empty());
+        assert!(roots(&[3.0]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_polyder_and_polyint() {
+        assert_eq!(polyder(&[5.0], 1), vec![0.0]);
+        assert_eq!(polyder(&[1.0, 2.0, 3.0], 5), vec![0.0]);
+        assert_eq!(polyder(&[1.0, 1.0], 0), vec![1.0, 1.0]);
+        assert_eq!(
+            polyint(&[6.0, 2.0], 2, Some(&[1.0, 3.0])).unwrap(),
+            vec![1.0, 1.0, 1.0, 3.0]
+        );
+        assert_eq!(polyint(&[2.0], 1, None).unwrap(), vec![2.0, 0.0]);
+        assert!(polyint(&[2.0], 2, Some(&[1.0])).is_err());
+        assert_eq!(
+            polyder(&polyint(&[4.0, 3.0, 2.0], 2, None).unwrap(), 2),
+            vec![4.0, 3.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn test_polynomial_arithmetic() {
+        let a = Polynomial::new(vec![1.0, 2.0]);
+        let b = Polynomial::new(vec![-1.0, 0.0, 3.0]);
+        assert_eq!((&a + &b).coef(), &[0.0, 2.0, 3.0]);
+        assert_eq!((&a - &b).coef(), &[2.0, 2.0, -3.0]);
+        assert_eq!((&a * &b).coef(), &[-1.0, -2.0, 3.0, 6.0]);
+
+        let (q, r) = (&a * &b).divmod(&b).unwrap();
+        assert_coef_eq(q.coef(), &[1.0, 2.0]);
+        assert_coef_eq(r.coef(), &[0.0]);
+
+        let (q, r) = b.divmod(&a).unwrap();
+        let rebuilt = &(&q * &a) + &r;
+        assert_coef_eq(rebuilt.coef(), b.coef());
+        assert_eq!(r.degree(), 0);
+        assert!(a.divmod(&Polynomial::new(vec![0.0, 0.0])).is_err());
+    }
+
+    #[test]
+    fn test_polynomial_calculus_and_fit() {
+        let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        assert_eq!(p.eval(2.0), 17.0);
+        assert_eq!(p.deriv(1).coef(), &[2.0, 6.0]);
+        assert_eq!(p.deriv(4).coef(), &[0.0]);
+        assert_eq!(p.integ(1, &[4.0]).coef(), &[4.0, 1.0, 1.0, 1.0]);
+        assert_eq!(
+            Polynomial::new(vec![1.0, 0.0, 0.0]).trim(0.0).coef(),
+            &[1.0]
+        );
+
+        let x = [0.0, 1.0, 2.0, 3.0];
+        let y: Vec<f64> = x.iter().map(|&v| p.eval(v)).collect();
+        assert_coef_eq(Polynomial::fit(&x, &y, 2, None).unwrap().coef(), p.coef());
+    }
+
+    #[test]
+    fn test_chebyshev_series() {
+        let t2 = Chebyshev::new(vec![0.0, 0.0, 1.0]);
+        assert_coef_eq(t2.to_polynomial().coef(), &[-1.0, 0.0, 2.0]);
+        assert_abs_diff_eq!(t2.eval(0.5), -0.5, epsilon = 1e-12);
+
+        let x_squared = Chebyshev::from_polynomial(&Polynomial::new(vec![0.0, 0.0, 1.0]));
+        assert_coef_eq(x_squared.coef(), &[0.5, 0.0, 0.5]);
+
+        let t1 = Chebyshev::new(vec![0.0, 1.0]);
+        assert_coef_eq((&t1 * &t1).coef(), &[0.5, 0.0, 0.5]);
+        assert_coef_eq((&t2 + &t1).coef(), &[0.0, 1.0, 1.0]);
+        assert_coef_eq((&t2 - &t1).coef(), &[0.0, -1.0, 1.0]);
+
+        // d/dx T3 = 3 U2 = 3 T0 + 6 T2.
+        let t3 = Chebyshev::new(vec![0.0, 0.0, 0.0, 1.0]);
+        assert_coef_eq(t3.deriv(1).coef(), &[3.0, 0.0, 6.0]);
+        assert_coef_eq(t3.deriv(1).integ(1).coef(), &[0.0, 0.0, 0.0, 1.0]);
+
+        let mut r: Vec<f64> = t2.roots().unwrap().iter().map(|z| z.re).collect();
+        r.sort_by(f64::total_cmp);
+        assert_coef_eq(&r, &[-(0.5f64.sqrt()), 0.5f64.sqrt()]);
+    }
+
+    #[test]
+    fn test_legendre_series() {
+        let p2 = Legendre::new(vec![0.0, 0.0, 1.0]);
+        assert_coef_eq(p2.to_polynomial().coef(), &[-0.5, 0.0, 1.5]);
+        assert_abs_diff_eq!(p2.eval(1.0), 1.0, epsilon = 1e-12);
+
+        let x_squared = Legendre::from_polynomial(&Polynomial::new(vec![0.0, 0.0, 1.0]));
+        assert_coef_eq(x_squared.coef(), &[1.0 / 3.0, 0.0, 2.0 / 3.0]);
+
+        let x: Vec<f64> = (0..7).map(|i| -1.0 + i as f64 / 3.0).collect();
+        let y: Vec<f64> = x.iter().map(|&v| p2.eval(v) + 2.0).collect();
+        assert_coef_eq(
+            Legendre::fit(&x, &y, 2, None).unwrap().coef(),
+            &[2.0, 0.0, 1.0],
+        );
+
+        let (q, r) = p2.divmod(&Legendre::new(vec![0.0, 1.0])).unwrap();
+        assert_coef_eq(q.coef(), &[0.0, 1.5]);
+        assert_coef_eq(r.coef(), &[-0.5]);
+
+        // P3' = 5 P2 + P0, and integrating back recovers P3, which vanishes at zero.
+        let p3 = Legendre::new(vec![0.0, 0.0, 0.0, 1.0]);
+        assert_coef_eq(p3.deriv(1).coef(), &[1.0, 0.0, 5.0]);
+        assert_coef_eq(p3.deriv(1).integ(1).coef(), p3.coef());
+        assert_coef_eq((&p2 * &p2).coef(), &[0.2, 0.0, 2.0 / 7.0, 0.0, 18.0 / 35.0]);
+    }
+
+    #[test]
+    fn test_series_stay_in_basis_at_high_degree() {
+        // The roots of T50 are cos((2k - 1) pi / 100); its power series coefficients reach
+        // 2^49, far beyond what the power basis could resolve them from.
+        let mut coef = vec![0.0; 51];
+        coef[50] = 1.0;
+        let t50 = Chebyshev::new(coef);
+        let mut r: Vec<f64> = t50.roots().unwrap().iter().map(|z| z.re).collect();
+        r.sort_by(f64::total_cmp);
+        let mut expected: Vec<f64> = (1..=50)
+            .map(|k| ((2 * k - 1) as f64 * std::f64::consts::PI / 100.0).cos())
+            .collect();
+        expected.sort_by(f64::total_cmp);
+        for (a, b) in r.iter().zip(&expected) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+        }
+
+        // T50' = 50 U49 = 100 (T1 + T3 + ... + T49), and integrating returns T50 up to its
+        // value at zero.
+        let der = t50.deriv(1);
+        for (k, &c) in der.coef().iter().enumerate() {
+            assert_abs_diff_eq!(c, if k % 2 == 1 { 100.0 } else { 0.0 }, epsilon = 1e-12);
+        }
+        let back = der.integ(1);
+        assert_abs_diff_eq!(back.coef()[50], 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            back.eval(0.3) - back.eval(-0.7),
+            t50.eval(0.3) - t50.eval(-0.7),
+            epsilon = 1e-12
+        );
+
+        // Division undoes multiplication.
+        let a = Chebyshev::new((0..30).map(|k| 1.0 / (k + 1) as f64).collect());
+        let b = Legendre::new(vec![0.5, -1.0, 0.25, 2.0]);
+        let b_cheb = Chebyshev::new(b.coef().to_vec());
+        let (q, r) = (&a * &b_cheb).divmod(&b_cheb).unwrap();
+        assert_coef_eq(q.coef(), a.coef());
+        assert!(r.coef().iter().all(|c| c.abs() < 1e-12));
+        let (q, r) = (&b * &b).divmod(&b).unwrap();
+        assert_coef_eq(q.coef(), b.coef());
+        assert!(r.coef().iter().all(|c| c.abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_series_domain_and_window() {
+        let s = Chebyshev::with_domain(vec![1.0, 2.0, 3.0], [0.0, 4.0], [-1.0, 1.0]).unwrap();
+        assert_eq!(s.map_parameters(), (-1.0, 0.5));
+        let plain = Chebyshev::new(vec![1.0, 2.0, 3.0]);
+        assert_abs_diff_eq!(s.eval(3.0), plain.eval(0.5), epsilon = 1e-12);
+        assert_abs_diff_eq!(s.to_polynomial().eval(3.0), s.eval(3.0), epsilon = 1e-12);
+
+        // Calculus is with respect to x: the chain rule brings in the scale of the mapping.
+        let h = 1e-6;
+        let slope = (s.eval(3.0 + h) - s.eval(3.0 - h)) / (2.0 * h);
+        assert_abs_diff_eq!(s.deriv(1).eval(3.0), slope, epsilon = 1e-6);
+        let antiderivative = s.integ(1);
+        assert_abs_diff_eq!(antiderivative.eval(2.0), 0.0, epsilon = 1e-12);
+        assert_coef_eq(antiderivative.deriv(1).coef(), s.coef());
+
+        // Roots come back in the domain.
+        let line = Legendre::with_domain(vec![0.0, 1.0], [10.0, 20.0], [-1.0, 1.0]).unwrap();
+        assert_abs_diff_eq!(line.roots().unwrap()[0].re, 15.0, epsilon = 1e-12);
+
+        assert!(Chebyshev::with_domain(vec![1.0], [1.0, 1.0], [-1.0, 1.0]).is_err());
+        assert!(s.divmod(&plain).is_err());
+        let result = std::panic::catch_unwind(|| &s + &plain);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,548 @@
+use std::fmt;
+use std::sync::RwLock;
+
+use ndarray::{ArrayBase, ArrayViewD, Axis, Data, Dimension};
+use num_traits::Float;
+
+/// Options controlling how arrays are printed, following `numpy.set_printoptions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// The maximum number of digits after the decimal point of floating-point values.
+    pub precision: usize,
+    /// Arrays with more elements than this are summarized with `...`.
+    pub threshold: usize,
+    /// The number of items shown at the start and end of each summarized axis.
+    pub edgeitems: usize,
+    /// The number of characters per line before wrapping.
+    pub linewidth: usize,
+    /// If `true`, never use scientific notation and print values smaller than the precision as
+    /// zero.
+    pub suppress: bool,
+}
+
+/// NumPy's default print options.
+const DEFAULT_PRINT_OPTIONS: PrintOptions = PrintOptions {
+    precision: 8,
+    threshold: 1000,
+    edgeitems: 3,
+    linewidth: 75,
+    suppress: false,
+};
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        DEFAULT_PRINT_OPTIONS
+    }
+}
+
+static PRINT_OPTIONS: RwLock<PrintOptions> = RwLock::new(DEFAULT_PRINT_OPTIONS);
+
+/// Sets the print options used by [`display`] on every thread of the process.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::printing::{display, get_printoptions, set_printoptions, PrintOptions};
+///
+/// set_printoptions(PrintOptions { precision: 3, ..Default::default() });
+/// assert_eq!(display(&array![1.0 / 3.0, 2.0]).to_string(), "[0.333 2.   ]");
+/// set_printoptions(PrintOptions::default());
+/// assert_eq!(get_printoptions().precision, 8);
+/// ```
+pub fn set_printoptions(options: PrintOptions) {
+    // The options are plain data, so a panic elsewhere cannot leave them half-written.
+    *PRINT_OPTIONS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = options;
+}
+
+/// Returns the print options of the process.
+pub fn get_printoptions() -> PrintOptions {
+    *PRINT_OPTIONS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Restores the previous print options when dropped, even if the scope panics.
+struct RestoreOptions(PrintOptions);
+
+impl Drop for RestoreOptions {
+    fn drop(&mut self) {
+        set_printoptions(self.0);
+    }
+}
+
+/// Runs `f` with `options` as the print options, restoring the previous options afterwards,
+/// like NumPy's `printoptions` context manager.
+///
+/// The override is process-wide, so arrays formatted on other threads while `f` runs also use
+/// `options`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::printing::{display, with_printoptions, PrintOptions};
+///
+/// let a = array![1e-10, 1.5];
+/// let suppressed = PrintOptions { suppress: true, ..Default::default() };
+/// assert_eq!(with_printoptions(suppressed, || display(&a).to_string()), "[0.  1.5]");
+/// assert_eq!(display(&a).to_string(), "[1.0e-10 1.5e+00]");
+/// ```
+pub fn with_printoptions<R>(options: PrintOptions, f: impl FnOnce() -> R) -> R {
+    let _restore = RestoreOptions(get_printoptions());
+    set_printoptions(options);
+    f()
+}
+
+/// An element type that can be printed by [`display`].
+///
+/// Implemented for `bool` and the primitive integer and floating-point types.
+pub trait FormatElement: Copy {
+    /// Formats all displayed elements of an array so that they have a common width.
+    fn format_elements(values: &[Self], options: &PrintOptions) -> Vec<String>;
+}
+
+impl FormatElement for bool {
+    fn format_elements(values: &[Self], _options: &PrintOptions) -> Vec<String> {
+        values
+            .iter()
+            .map(|&b| if b { " True" } else { "False" }.to_string())
+            .collect()
+    }
+}
+
+macro_rules! impl_format_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl FormatElement for $ty {
+                fn format_elements(values: &[Self], _options: &PrintOptions) -> Vec<String> {
+                    let strings: Vec<String> = values.iter().map(|x| x.to_string()).collect();
+                    let width = strings.iter().map(String::len).max().unwrap_or(0);
+                    strings.iter().map(|s| format!("{:>1$}", s, width)).collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_format_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_format_float {
+    ($($ty:ty),*) => {
+        $(
+            impl FormatElement for $ty {
+                fn format_elements(values: &[Self], options: &PrintOptions) -> Vec<String> {
+                    format_floats(values, options)
+                }
+            }
+        )*
+    };
+}
+
+impl_format_float!(f32, f64);
+
+/// Returns the shortest round-trip digits of `shortest` if they have at most `precision`
+/// fractional digits and `rounded` otherwise, with trailing zeros trimmed but the decimal point
+/// kept. Both strings may carry an exponent.
+fn trim_digits(
+    shortest: String,
+    rounded: String,
+    precision: usize,
+) -> (String, String, Option<i32>) {
+    let split = |s: &str| -> (String, Option<i32>) {
+        match s.split_once('e') {
+            Some((m, e)) => (m.to_string(), Some(e.parse().expect("integer exponent"))),
+            None => (s.to_string(), None),
+        }
+    };
+    let (mantissa, exponent) = split(&shortest);
+    let fraction = mantissa.split_once('.').map_or(0, |(_, f)| f.len());
+    let (mantissa, exponent) = if fraction <= precision {
+        (mantissa, exponent)
+    } else {
+        split(&rounded)
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((&mantissa, ""));
+    (
+        int.to_string(),
+        frac.trim_end_matches('0').to_string(),
+        exponent,
+    )
+}
+
+/// Formats floats like NumPy's `FloatingFormat` in its default `maxprec` mode: every value uses
+/// as few digits as it needs up to the precision, and the decimal points line up.
+fn format_floats<F>(values: &[F], options: &PrintOptions) -> Vec<String>
+where
+    F: Float + fmt::Display + fmt::LowerExp,
+{
+    let finite: Vec<F> = values.iter().copied().filter(|x| x.is_finite()).collect();
+    let magnitudes = || finite.iter().map(|x| x.abs()).filter(|x| !x.is_zero());
+    let exponential = match (magnitudes().reduce(F::max), magnitudes().reduce(F::min)) {
+        (Some(max), Some(min)) => {
+            let max = max.to_f64().unwrap_or(f64::INFINITY);
+            let min = min.to_f64().unwrap_or(0.0);
+            max >= 1e8 || (!options.suppress && (min < 1e-4 || max / min > 1e3))
+        }
+        _ => false,
+    };
+    let precision = options.precision;
+    let parts: Vec<(String, String, Option<i32>)> = finite
+        .iter()
+        .map(|&x| {
+            if exponential {
+                trim_digits(
+                    format!("{:e}", x),
+                    format!("{:.*e}", precision, x),
+                    precision,
+                )
+            } else {
+                trim_digits(format!("{}", x), format!("{:.*}", precision, x), precision)
+            }
+        })
+        .collect();
+
+    let mut pad_left = parts.iter().map(|p| p.0.len()).max().unwrap_or(0);
+    let frac_width = parts.iter().map(|p| p.1.len()).max().unwrap_or(0);
+    let exp_digits = parts
+        .iter()
+        .filter_map(|p| p.2)
+        .map(|e| e.unsigned_abs().to_string().len().max(2))
+        .max()
+        .unwrap_or(0);
+    let pad_right = if exponential {
+        frac_width + 2 + exp_digits
+    } else {
+        frac_width
+    };
+    if finite.len() != values.len() {
+        let negative_inf = values.iter().any(|&x| x == F::neg_infinity());
+        let offset = pad_right + 1;
+        pad_left = pad_left.max((3 + negative_inf as usize).saturating_sub(offset));
+    }
+    let width = pad_left + pad_right + 1;
+
+    let mut parts = parts.into_iter();
+    values
+        .iter()
+        .map(|&x| {
+            let s = if x.is_nan() {
+                "nan".to_string()
+            } else if x.is_infinite() {
+                if x < F::zero() { "-inf" } else { "inf" }.to_string()
+            } else {
+                let (int, frac, exponent) = parts.next().expect("one part per finite value");
+                match exponent {
+                    Some(e) => format!(
+                        "{:>pad_left$}.{:0<frac_width$}e{}{:0>exp_digits$}",
+                        int,
+                        frac,
+                        if e < 0 { '-' } else { '+' },
+                        e.unsigned_abs(),
+                    ),
+                    None => format!("{:>pad_left$}.{:<frac_width$}", int, frac),
+                }
+            };
+            format!("{:>width$}", s)
+        })
+        .collect()
+}
+
+/// A wrapper that prints an array in NumPy's layout. Created by [`display`].
+pub struct ArrayDisplay<'a, A, S, D>
+where
+    S: Data<Elem = A>,
+{
+    array: &'a ArrayBase<S, D>,
+    options: Option<PrintOptions>,
+}
+
+impl<A, S, D> ArrayDisplay<'_, A, S, D>
+where
+    S: Data<Elem = A>,
+{
+    /// Uses `options` instead of the global print options.
+    pub fn with_options(mut self, options: PrintOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+}
+
+/// Wraps an array so that it prints like NumPy's `str()` of the array: columns are aligned,
+/// nested axes are bracketed and arrays larger than the threshold are summarized with `...`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{array, Array};
+/// use numrust::printing::display;
+///
+/// let a = array![[1.0, -2.5], [3.25, 4.0]];
+/// assert_eq!(display(&a).to_string(), "[[ 1.   -2.5 ]\n [ 3.25  4.  ]]");
+///
+/// let b = Array::from_iter(0..2000);
+/// assert_eq!(display(&b).to_string(), "[   0    1    2 ... 1997 1998 1999]");
+/// ```
+pub fn display<A, S, D>(a: &ArrayBase<S, D>) -> ArrayDisplay<'_, A, S, D>
+where
+    A: FormatElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    ArrayDisplay {
+        array: a,
+        options: None,
+    }
+}
+
+/// Collects the elements that are shown when printing, in printing order.
+fn shown_elements<A: Copy>(a: &ArrayViewD<A>, summarize: bool, edgeitems: usize, out: &mut Vec<A>) {
+    if a.ndim() == 0 {
+        out.push(*a.first().expect("a 0-d array has one element"));
+        return;
+    }
+    let len = a.len_of(Axis(0));
+    for i in shown_indices(len, summarize, edgeitems)
+        .into_iter()
+        .flatten()
+    {
+        shown_elements(&a.index_axis(Axis(0), i), summarize, edgeitems, out);
+    }
+}
+
+/// Returns the indices shown along an axis of length `len`, with `None` marking `...`.
+fn shown_indices(len: usize, summarize: bool, edgeitems: usize) -> Vec<Option<usize>> {
+    if summarize && 2 * edgeitems < len {
+        (0..edgeitems)
+            .map(Some)
+            .chain(std::iter::once(None))
+            .chain((len - edgeitems..len).map(Some))
+            .collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+/// Appends `word` to `line`, first moving `line` into `s` if the word does not fit.
+fn extend_line(s: &mut String, line: &mut String, word: &str, width: usize, indent: &str) {
+    let needs_wrap = line.chars().count() + word.chars().count() > width
+        && line.chars().count() > indent.chars().count();
+    if needs_wrap {
+        s.push_str(line.trim_end());
+        s.push('\n');
+        *line = indent.to_string();
+    }
+    line.push_str(word);
+}
+
+/// Renders the sub-array of shape `shape[depth..]`, following NumPy's `_formatArray`.
+fn format_nested(
+    shape: &[usize],
+    depth: usize,
+    words: &mut std::vec::IntoIter<String>,
+    indent: &str,
+    width: usize,
+    summarize: bool,
+    edgeitems: usize,
+) -> String {
+    if depth == shape.len() {
+        return words.next().expect("one string per shown element");
+    }
+    let next_indent = format!("{} ", indent);
+    let next_width = width.saturating_sub(1);
+    let indices = shown_indices(shape[depth], summarize, edgeitems);
+    let last = indices.len().saturating_sub(1);
+    let mut s = String::new();
+    if depth + 1 == shape.len() {
+        let mut line = indent.to_string();
+        for (i, index) in indices.iter().enumerate() {
+            let word = match index {
+                Some(_) => format_nested(
+                    shape,
+                    depth + 1,
+                    words,
+                    &next_indent,
+                    next_width,
+                    summarize,
+                    edgeitems,
+                ),
+                None => "...".to_string(),
+            };
+            let limit = if i == last { next_width } else { width };
+            extend_line(&mut s, &mut line, &word, limit, indent);
+            if i != last {
+                line.push(' ');
+            }
+        }
+        s.push_str(&line);
+    } else {
+        let separator = "\n".repeat(shape.len() - depth - 1);
+        for (i, index) in indices.iter().enumerate() {
+            s.push_str(indent);
+            match index {
+                Some(_) => s.push_str(&format_nested(
+                    shape,
+                    depth + 1,
+                    words,
+                    &next_indent,
+                    next_width,
+                    summarize,
+                    edgeitems,
+                )),
+                None => s.push_str("..."),
+            }
+            if i != last {
+                s.push_str(&separator);
+            }
+        }
+    }
+    format!("[{}]", &s[indent.len()..])
+}
+
+impl<A, S, D> fmt::Display for ArrayDisplay<'_, A, S, D>
+where
+    A: FormatElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = self.options.unwrap_or_else(get_printoptions);
+        let view = self.array.view().into_dyn();
+        if view.is_empty() && view.ndim() > 0 {
+            return f.write_str("[]");
+        }
+        let summarize = view.len() > options.threshold;
+        let mut shown = Vec::new();
+        shown_elements(&view, summarize, options.edgeitems, &mut shown);
+        let words = A::format_elements(&shown, &options);
+        if view.ndim() == 0 {
+            return f.write_str(words[0].trim_start());
+        }
+        f.write_str(&format_nested(
+            view.shape(),
+            0,
+            &mut words.into_iter(),
+            " ",
+            options.linewidth,
+            summarize,
+            options.edgeitems,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod numrust_printing_tests {
+    use super::*;
+    use ndarray::{arr0, array, Array};
+    use std::sync::Mutex;
+
+    /// Serializes the tests that change the process-wide print options.
+    static OPTIONS_LOCK: Mutex<()> = Mutex::new(());
+
+    fn show<A: FormatElement, S: Data<Elem = A>, D: Dimension>(a: &ArrayBase<S, D>) -> String {
+        display(a).with_options(PrintOptions::default()).to_string()
+    }
+
+    #[test]
+    fn test_float_formats_match_numpy() {
+        assert_eq!(show(&array![1.0, 2.5, 3.0]), "[1.  2.5 3. ]");
+        assert_eq!(show(&array![1.0, 2.0]), "[1. 2.]");
+        assert_eq!(show(&array![0.1, 1.0 / 3.0]), "[0.1        0.33333333]");
+        assert_eq!(show(&array![1e-5, 1e10]), "[1.e-05 1.e+10]");
+        assert_eq!(show(&array![1.5e-5, -1e10]), "[ 1.5e-05 -1.0e+10]");
+        assert_eq!(show(&array![1e-100, 1.0]), "[1.e-100 1.e+000]");
+        assert_eq!(
+            show(&array![f64::NAN, 1.0, f64::NEG_INFINITY]),
+            "[ nan   1. -inf]"
+        );
+        assert_eq!(show(&array![f64::INFINITY, 100.5]), "[  inf 100.5]");
+        assert_eq!(show(&array![0.1f32, 2.0f32]), "[0.1 2. ]");
+        assert_eq!(show(&array![-0.0, 0.0]), "[-0.  0.]");
+        assert_eq!(show(&arr0(2.5)), "2.5");
+
+        let suppress = PrintOptions {
+            suppress: true,
+            precision: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            display(&array![1e-10, 12345.6789])
+                .with_options(suppress)
+                .to_string(),
+            "[    0.    12345.679]"
+        );
+    }
+
+    #[test]
+    fn test_integer_bool_and_nested_layout() {
+        assert_eq!(show(&array![[1, -20], [300, 4]]), "[[  1 -20]\n [300   4]]");
+        assert_eq!(show(&array![true, false]), "[ True False]");
+        assert_eq!(
+            show(&Array::from_iter(0..8).into_shape((2, 2, 2)).unwrap()),
+            "[[[0 1]\n  [2 3]]\n\n [[4 5]\n  [6 7]]]"
+        );
+        assert_eq!(show(&Array::<f64, _>::zeros((2, 0))), "[]");
+    }
+
+    #[test]
+    fn test_line_wrapping_and_summary() {
+        let a = Array::from_iter(0..30);
+        assert_eq!(
+            show(&a),
+            "[ 0  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24\n 25 26 27 28 29]"
+        );
+        let b = Array::from_iter(0..1010).into_shape((101, 10)).unwrap();
+        let expected = "[[   0    1    2 ...    7    8    9]\n [  10   11   12 ...   17   18   19]\n [  20   21   22 ...   27   28   29]\n ...\n [ 980  981  982 ...  987  988  989]\n [ 990  991  992 ...  997  998  999]\n [1000 1001 1002 ... 1007 1008 1009]]";
+        assert_eq!(show(&b), expected);
+    }
+
+    #[test]
+    fn test_scoped_options_restore_after_panic() {
+        let _guard = OPTIONS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let narrow = PrintOptions {
+            linewidth: 10,
+            ..Default::default()
+        };
+        let result = std::panic::catch_unwind(|| {
+            with_printoptions(narrow, || panic!("inside the scope"));
+        });
+        assert!(result.is_err());
+        assert_eq!(get_printoptions(), PrintOptions::default());
+        assert_eq!(
+            with_printoptions(narrow, || display(&array![1, 2, 3, 4]).to_string()),
+            "[1 2 3 4]"
+        );
+        assert_eq!(
+            with_printoptions(narrow, || display(&array![10, 20, 30, 40]).to_string()),
+            "[10 20 30\n 40]"
+        );
+    }
+
+    #[test]
+    fn test_options_apply_to_other_threads() {
+        let _guard = OPTIONS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        set_printoptions(PrintOptions {
+            precision: 2,
+            ..Default::default()
+        });
+        let formatted = std::thread::spawn(|| display(&array![1.0 / 3.0, 2.0]).to_string())
+            .join()
+            .unwrap();
+        set_printoptions(PrintOptions::default());
+        assert_eq!(formatted, "[0.33 2.  ]");
+
+        let narrow = PrintOptions {
+            linewidth: 10,
+            ..Default::default()
+        };
+        let scoped = with_printoptions(narrow, || {
+            std::thread::spawn(|| display(&array![10, 20, 30, 40]).to_string())
+                .join()
+                .unwrap()
+        });
+        assert_eq!(scoped, "[10 20 30\n 40]");
+        assert_eq!(get_printoptions(), PrintOptions::default());
+    }
+}
@@ -1,6 +1,31 @@
+use std::f64::consts::PI;
+
+use ndarray::{
+    Array, Array1, Array2, ArrayBase, Axis, Data, DataMut, Dimension, Ix2, IxDyn, RemoveAxis,
+};
 use rand::prelude::*;
+use rand::rngs::ThreadRng;
 use rand::Rng;
-use rand_distr::{Binomial, BinomialError, Normal, NormalError, WeightedIndex};
+use rand_distr::{
+    Beta, Binomial, ChiSquared, Exp1, FisherF, Gamma, Geometric, Hypergeometric, Poisson,
+    StandardNormal, StudentT,
+};
+
+use crate::linalg::{cholesky, eigh, svd};
+use crate::logic::allclose;
+use crate::{normalize_axis, NdArray, NumrustError};
+
+mod bit_generator;
+mod bounded;
+pub mod qmc;
+mod seed_sequence;
+mod ziggurat;
+
+pub use bit_generator::{
+    BitGenerator, Jumpable, Pcg64, Pcg64Dxsm, Philox, Sfc64, Xoshiro256StarStar,
+};
+pub use bounded::UniformInteger;
+pub use seed_sequence::{worker_generators, SeedSequence, SeedableBitGenerator};
 
 /// Returns a vector of `size` elements randomly chosen from the array `a`.
 ///
@@ -15,10 +40,15 @@ use rand_distr::{Binomial, BinomialError, Normal, NormalError, WeightedIndex};
 ///
 /// A vector of elements randomly chosen from `a`.
 ///
+/// Without replacement, weighted draws follow NumPy's semantics: each draw picks among the
+/// remaining elements with probability proportional to their weights.
+///
 /// # Panics
 ///
 /// * If `size` is greater than the length of `a` and `replace` is `false`.
 /// * If `p` is `Some` and the length of `p` is not equal to the length of `a`.
+/// * If `p` is `Some` and contains negative or non-finite values, or sums to zero.
+/// * If `p` is `Some`, `replace` is `false`, and fewer than `size` entries of `p` are non-zero.
 ///
 /// # Examples
 ///
@@ -30,160 +60,1978 @@ use rand_distr::{Binomial, BinomialError, Normal, NormalError, WeightedIndex};
 /// // Draw 5 elements with replacement
 /// let choices = choice(&colors, 5, true, None);
 /// assert_eq!(choices.len(), 5);
+///
+/// // Draw 2 distinct elements, never picking the one with zero weight
+/// let choices = choice(&colors, 2, false, Some(&[0.5, 0.0, 0.5]));
+/// assert!(choices.contains(&"blue") && choices.contains(&"red"));
 /// ```
 pub fn choice<T: Clone>(a: &[T], size: usize, replace: bool, p: Option<&[f64]>) -> Vec<T> {
-    if !replace & (size > a.len()) {
-        panic!("`size` cannot be greater than the length of `a` if `replace` is false");
+    thread_generator().choice(a, size, replace, p)
+}
+
+/// Generates samples from a binomial distribution with parameters `n` and `p`.
+///
+/// The binomial distribution models the number of successes in a fixed number of independent
+/// Bernoulli trials, where each trial has a probability `p` of success. The `n` parameter specifies
+/// the number of trials, and `p` specifies the probability of success.
+///
+/// # Arguments
+///
+/// * `n` - The number of Bernoulli trials.
+/// * `p` - The probability of success in each Bernoulli trial.
+/// * `size` - The number of samples to generate.
+///
+/// # Returns
+///
+/// A `Vec` containing `size` samples drawn from the binomial distribution with parameters `n` and `p`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::binomial;
+/// use rand_distr::Binomial;
+/// use rand::thread_rng;
+///
+/// fn main() {
+///     let n = 10;
+///     let p = 0.5;
+///     let size = 100;
+///
+///     let data = binomial(n, p, size).unwrap();
+///
+///     assert_eq!(data.len(), size);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `p` is not between 0 and 1.
+pub fn binomial(n: u64, p: f64, size: usize) -> Result<Vec<u64>, NumrustError> {
+    thread_generator().binomial(n, p, size)
+}
+
+/// Generates a vector of `n` random samples from a normal (Gaussian) distribution
+/// with the specified `mean` and `standard deviation`.
+///
+/// # Arguments
+///
+/// * `mean` - the mean of the normal distribution
+/// * `std` - the standard deviation of the normal distribution
+/// * `n` - the number of samples to generate
+///
+/// # Returns
+///
+/// A `Vec` of `n` random samples from a normal distribution with the specified mean
+/// and standard deviation.
+///
+/// # Example
+///
+/// ```
+/// use numrust::random::normal;
+/// use rand_distr::Normal;
+/// use rand::prelude::*;
+///
+/// let mean = 0.0;
+/// let std = 1.0;
+/// let n = 100;
+///
+/// let data = normal(mean, std, n);
+///
+/// println!("{:?}", data);
+/// ```
+///
+/// This example generates 100 random samples from a standard normal distribution (i.e.
+/// a normal distribution with mean 0 and standard deviation 1) and prints them to the console.
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `std` is negative or not finite.
+pub fn normal<T: Into<f64> + Copy>(mean: T, std: T, n: usize) -> Result<Vec<f64>, NumrustError> {
+    thread_generator().normal(mean, std, n)
+}
+
+/// Draws integers of any primitive integer type uniformly from `[low, high)`, or from
+/// `[low, high]` if `endpoint` is `true`.
+///
+/// Every integer in the range is equally likely, including over the full range of the type,
+/// and draws use Lemire's multiply-and-reject method rather than a biased modulo.
+///
+/// # Arguments
+///
+/// * `low` - The lowest value that can be drawn.
+/// * `high` - One above the highest value that can be drawn, or the highest value itself if
+///   `endpoint` is `true`.
+/// * `size` - The shape of the output array.
+/// * `endpoint` - Whether `high` can be drawn.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::integers;
+///
+/// let dice = integers(1u8, 6, &[2, 3], true).unwrap();
+/// assert_eq!(dice.shape(), &[2, 3]);
+/// assert!(dice.iter().all(|d| (1..=6).contains(d)));
+///
+/// let words = integers(u64::MIN, u64::MAX, &[4], true).unwrap();
+/// assert_eq!(words.len(), 4);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `low >= high`, or `low > high` when `endpoint` is
+/// `true`.
+pub fn integers<T: UniformInteger>(
+    low: T,
+    high: T,
+    size: &[usize],
+    endpoint: bool,
+) -> Result<NdArray<T>, NumrustError> {
+    thread_generator().integers(low, high, size, endpoint)
+}
+
+/// Generate a list of `n` random integers between `min` (inclusive) and `max` (exclusive).
+///
+/// See [`integers`] for other integer types, shapes and inclusive bounds without panicking.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::randint;
+///
+/// let values = randint(0, 10, 5);
+/// assert_eq!(values.len(), 5);
+/// assert!(values.iter().all(|&x| x >= 0 && x < 10));
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `min >= max`.
+///
+pub fn randint(min: i32, max: i32, n: usize) -> Vec<i32> {
+    thread_generator().randint(min, max, n)
+}
+
+/// Returns a `NumrustError::ValueError` with `msg` unless `valid` holds. NaN parameters fail
+/// every comparison, so they are rejected as well.
+fn check_param(valid: bool, msg: &str) -> Result<(), NumrustError> {
+    if valid {
+        Ok(())
+    } else {
+        Err(NumrustError::ValueError(msg.to_string()))
+    }
+}
+
+/// Draws from the open interval (0, 1), as the inverse-CDF samplers below need.
+fn open_unit<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    loop {
+        let u: f64 = rng.gen();
+        if u > 0.0 {
+            return u;
+        }
+    }
+}
+
+/// Draws samples uniformly from the half-open interval `[low, high)`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::uniform;
+///
+/// let samples = uniform(-1.0, 1.0, &[2, 3]).unwrap();
+/// assert_eq!(samples.shape(), &[2, 3]);
+/// assert!(samples.iter().all(|&x| (-1.0..1.0).contains(&x)));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `high - low` is not finite.
+pub fn uniform(low: f64, high: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().uniform(low, high, size)
+}
+
+/// Draws samples from an exponential distribution with the given `scale` (the inverse of the
+/// rate).
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::exponential;
+///
+/// let samples = exponential(2.0, &[1000]).unwrap();
+/// assert!(samples.iter().all(|&x| x >= 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `scale < 0`.
+pub fn exponential(scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().exponential(scale, size)
+}
+
+/// Draws samples from a gamma distribution with the given `shape` (k) and `scale` (θ).
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::gamma;
+///
+/// let samples = gamma(2.0, 0.5, &[4, 4]).unwrap();
+/// assert_eq!(samples.shape(), &[4, 4]);
+/// assert!(samples.iter().all(|&x| x >= 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `shape < 0` or `scale < 0`.
+pub fn gamma(shape: f64, scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().gamma(shape, scale, size)
+}
+
+/// Draws samples from a beta distribution on `[0, 1]` with shape parameters `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::beta;
+///
+/// let samples = beta(2.0, 5.0, &[100]).unwrap();
+/// assert!(samples.iter().all(|&x| (0.0..=1.0).contains(&x)));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `a <= 0` or `b <= 0`.
+pub fn beta(a: f64, b: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().beta(a, b, size)
+}
+
+/// Draws samples from a chi-square distribution with `df` degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::chisquare;
+///
+/// let samples = chisquare(3.0, &[10]).unwrap();
+/// assert!(samples.iter().all(|&x| x >= 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `df <= 0`.
+pub fn chisquare(df: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().chisquare(df, size)
+}
+
+/// Draws samples from a noncentral chi-square distribution with `df` degrees of freedom and
+/// noncentrality `nonc`, using the same decomposition as NumPy.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::noncentral_chisquare;
+///
+/// let samples = noncentral_chisquare(0.5, 2.0, &[10]).unwrap();
+/// assert!(samples.iter().all(|&x| x >= 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `df <= 0`, if `nonc` is negative or not finite, or if
+/// `df <= 1` and `nonc / 2` is too large for the Poisson sampler.
+pub fn noncentral_chisquare(
+    df: f64,
+    nonc: f64,
+    size: &[usize],
+) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().noncentral_chisquare(df, nonc, size)
+}
+
+/// Draws samples from Student's t distribution with `df` degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::standard_t;
+///
+/// let samples = standard_t(10.0, &[3, 2]).unwrap();
+/// assert_eq!(samples.shape(), &[3, 2]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `df <= 0`.
+pub fn standard_t(df: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().standard_t(df, size)
+}
+
+/// Draws samples from an F (Fisher–Snedecor) distribution with `dfnum` and `dfden` degrees of
+/// freedom.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::f;
+///
+/// let samples = f(5.0, 10.0, &[10]).unwrap();
+/// assert!(samples.iter().all(|&x| x >= 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `dfnum <= 0` or `dfden <= 0`.
+pub fn f(dfnum: f64, dfden: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().f(dfnum, dfden, size)
+}
+
+/// Draws samples from a log-normal distribution, whose logarithm is normal with the given
+/// `mean` and standard deviation `sigma`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::lognormal;
+///
+/// let samples = lognormal(0.0, 0.5, &[10]).unwrap();
+/// assert!(samples.iter().all(|&x| x > 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `sigma < 0`.
+pub fn lognormal(mean: f64, sigma: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().lognormal(mean, sigma, size)
+}
+
+/// Draws samples from a Laplace (double exponential) distribution with location `loc` and
+/// scale `scale`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::laplace;
+///
+/// let samples = laplace(1.0, 0.0, &[3]).unwrap();
+/// assert!(samples.iter().all(|&x| x == 1.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `scale < 0`.
+pub fn laplace(loc: f64, scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().laplace(loc, scale, size)
+}
+
+/// Draws samples from a logistic distribution with location `loc` and scale `scale`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::logistic;
+///
+/// let samples = logistic(0.0, 1.0, &[2, 2]).unwrap();
+/// assert!(samples.iter().all(|x| x.is_finite()));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `scale < 0`.
+pub fn logistic(loc: f64, scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().logistic(loc, scale, size)
+}
+
+/// Draws samples from a Gumbel (type I extreme value) distribution with location `loc` and
+/// scale `scale`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::gumbel;
+///
+/// let samples = gumbel(0.0, 1.0, &[10]).unwrap();
+/// assert!(samples.iter().all(|x| x.is_finite()));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `scale < 0`.
+pub fn gumbel(loc: f64, scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().gumbel(loc, scale, size)
+}
+
+/// Draws samples from a one-parameter Weibull distribution with shape `a`, as NumPy's
+/// `weibull` does. Multiply by λ for a two-parameter Weibull.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::weibull;
+///
+/// let samples = weibull(1.5, &[10]).unwrap();
+/// assert!(samples.iter().all(|&x| x >= 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `a < 0`.
+pub fn weibull(a: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().weibull(a, size)
+}
+
+/// Draws samples from a Pareto II (Lomax) distribution with shape `a`, as NumPy's `pareto`
+/// does. Add 1 and multiply by the mode for a classical Pareto distribution.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::pareto;
+///
+/// let samples = pareto(3.0, &[10]).unwrap();
+/// assert!(samples.iter().all(|&x| x >= 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `a <= 0`.
+pub fn pareto(a: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().pareto(a, size)
+}
+
+/// Draws samples from a Rayleigh distribution with the given `scale` (mode).
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::rayleigh;
+///
+/// let samples = rayleigh(2.0, &[10]).unwrap();
+/// assert!(samples.iter().all(|&x| x >= 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `scale < 0`.
+pub fn rayleigh(scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().rayleigh(scale, size)
+}
+
+/// Draws samples from a Cauchy (Lorentz) distribution with location `loc` and scale `scale`.
+/// `cauchy(0.0, 1.0, size)` is NumPy's `standard_cauchy(size)`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::cauchy;
+///
+/// let samples = cauchy(0.0, 1.0, &[5]).unwrap();
+/// assert_eq!(samples.len(), 5);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `scale < 0` or `loc` is not finite.
+pub fn cauchy(loc: f64, scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().cauchy(loc, scale, size)
+}
+
+/// Draws samples from a triangular distribution on `[left, right]` with peak at `mode`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::triangular;
+///
+/// let samples = triangular(-3.0, 0.0, 8.0, &[100]).unwrap();
+/// assert!(samples.iter().all(|&x| (-3.0..=8.0).contains(&x)));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `left > mode`, `mode > right` or `left == right`.
+pub fn triangular(
+    left: f64,
+    mode: f64,
+    right: f64,
+    size: &[usize],
+) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().triangular(left, mode, right, size)
+}
+
+/// Draws samples from a von Mises distribution on `[-π, π]` with mode `mu` and concentration
+/// `kappa`, using the Best–Fisher algorithm as NumPy does.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::vonmises;
+/// use std::f64::consts::PI;
+///
+/// let samples = vonmises(0.0, 4.0, &[100]).unwrap();
+/// assert!(samples.iter().all(|&x| (-PI..=PI).contains(&x)));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `kappa < 0` or `mu` is not finite.
+pub fn vonmises(mu: f64, kappa: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().vonmises(mu, kappa, size)
+}
+
+fn vonmises_one<R: Rng + ?Sized>(rng: &mut R, mu: f64, kappa: f64) -> f64 {
+    if kappa < 1e-8 {
+        return PI * (2.0 * rng.gen::<f64>() - 1.0);
+    }
+    let s = if kappa < 1e-5 {
+        // Second-order Taylor expansion around kappa = 0.
+        1.0 / kappa + kappa
+    } else if kappa <= 1e6 {
+        let r = 1.0 + (1.0 + 4.0 * kappa * kappa).sqrt();
+        let rho = (r - (2.0 * r).sqrt()) / (2.0 * kappa);
+        0.5 * (1.0 + rho * rho) / rho
+    } else {
+        // Wrapped normal approximation for very concentrated distributions.
+        let x = mu + rng.sample::<f64, _>(StandardNormal) / kappa.sqrt();
+        return (x + PI).rem_euclid(2.0 * PI) - PI;
+    };
+    let w = loop {
+        let u: f64 = rng.gen();
+        let z = (PI * u).cos();
+        let w = (1.0 + s * z) / (s + z);
+        let y = kappa * (s - w);
+        let v: f64 = rng.gen();
+        if y * (2.0 - y) - v >= 0.0 || (y / v).ln() + 1.0 - y >= 0.0 {
+            break w;
+        }
+    };
+    let u: f64 = rng.gen();
+    let mut result = w.clamp(-1.0, 1.0).acos();
+    if u < 0.5 {
+        result = -result;
+    }
+    (result + mu + PI).rem_euclid(2.0 * PI) - PI
+}
+
+/// Draws samples from a Wald (inverse Gaussian) distribution with the given `mean` and `scale`
+/// (shape λ).
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::wald;
+///
+/// let samples = wald(3.0, 2.0, &[10]).unwrap();
+/// assert!(samples.iter().all(|&x| x > 0.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `mean <= 0` or `scale <= 0`.
+pub fn wald(mean: f64, scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().wald(mean, scale, size)
+}
+
+/// The largest `lam` accepted by [`poisson`], as in NumPy: larger means would overflow `i64`.
+const POISSON_LAM_MAX: f64 = 9.223_372_006_484_771e18;
+
+/// Draws samples from a Poisson distribution with expected number of events `lam`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::poisson;
+///
+/// let samples = poisson(4.0, &[2, 5]).unwrap();
+/// assert_eq!(samples.shape(), &[2, 5]);
+/// assert!(samples.iter().all(|&k| k >= 0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `lam < 0` or `lam` is too large for the counts to fit
+/// in an `i64`.
+pub fn poisson(lam: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+    thread_generator().poisson(lam, size)
+}
+
+/// Draws samples from a geometric distribution: the number of Bernoulli trials with success
+/// probability `p` up to and including the first success.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::geometric;
+///
+/// let samples = geometric(0.35, &[100]).unwrap();
+/// assert!(samples.iter().all(|&k| k >= 1));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` unless `0 < p <= 1`.
+pub fn geometric(p: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+    thread_generator().geometric(p, size)
+}
+
+/// Draws samples from a negative binomial distribution: the number of failures before `n`
+/// successes in Bernoulli trials with success probability `p`. `n` may be non-integral.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::negative_binomial;
+///
+/// let samples = negative_binomial(3.0, 0.5, &[100]).unwrap();
+/// assert!(samples.iter().all(|&k| k >= 0));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `n <= 0`, unless `0 < p <= 1`, or if `n` is so large
+/// or `p` so small that the gamma-distributed Poisson means could exceed what the Poisson
+/// sampler supports.
+pub fn negative_binomial(n: f64, p: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+    thread_generator().negative_binomial(n, p, size)
+}
+
+/// Draws samples from a hypergeometric distribution: the number of good items among `nsample`
+/// items drawn without replacement from `ngood` good and `nbad` bad items.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::hypergeometric;
+///
+/// let samples = hypergeometric(15, 15, 10, &[100]).unwrap();
+/// assert!(samples.iter().all(|&k| (0..=10).contains(&k)));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `nsample > ngood + nbad`.
+pub fn hypergeometric(
+    ngood: u64,
+    nbad: u64,
+    nsample: u64,
+    size: &[usize],
+) -> Result<NdArray<i64>, NumrustError> {
+    thread_generator().hypergeometric(ngood, nbad, nsample, size)
+}
+
+/// Draws samples from a logarithmic series distribution with shape `p`, using Kemp's algorithm
+/// as NumPy does.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::logseries;
+///
+/// let samples = logseries(0.6, &[100]).unwrap();
+/// assert!(samples.iter().all(|&k| k >= 1));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` unless `0 <= p < 1`.
+pub fn logseries(p: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+    thread_generator().logseries(p, size)
+}
+
+/// Draws samples from a Zipf distribution with exponent `a`, using NumPy's rejection
+/// algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::zipf;
+///
+/// let samples = zipf(2.0, &[100]).unwrap();
+/// assert!(samples.iter().all(|&k| k >= 1));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `a <= 1`.
+pub fn zipf(a: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+    thread_generator().zipf(a, size)
+}
+
+/// Draws Bernoulli trials that are `true` with probability `p`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::bernoulli;
+///
+/// let samples = bernoulli(1.0, &[3]).unwrap();
+/// assert!(samples.iter().all(|&b| b));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` unless `0 <= p <= 1`.
+pub fn bernoulli(p: f64, size: &[usize]) -> Result<NdArray<bool>, NumrustError> {
+    thread_generator().bernoulli(p, size)
+}
+
+/// The factorization `multivariate_normal` uses to draw correlated samples from a covariance
+/// matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CovMethod {
+    /// Singular value decomposition. The slowest method, but the most robust for covariance
+    /// matrices that are only positive semi-definite.
+    #[default]
+    Svd,
+    /// Symmetric eigendecomposition. Faster than `Svd` and also handles semi-definite matrices.
+    Eigh,
+    /// Cholesky decomposition. The fastest method, but it requires a strictly positive-definite
+    /// covariance matrix.
+    Cholesky,
+}
+
+/// How `multivariate_normal` treats a covariance matrix that is not positive semi-definite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckValid {
+    /// Return a `NumrustError::ValueError`.
+    #[default]
+    Raise,
+    /// Draw samples from the factorized matrix anyway.
+    Ignore,
+}
+
+/// Draws samples from a multivariate normal distribution.
+///
+/// Each sample is `mean + z.dot(factor)`, where `z` holds independent standard normal draws and
+/// `factor` is computed from `cov` by `method`, as in `numpy.random.Generator.multivariate_normal`.
+///
+/// # Arguments
+///
+/// * `mean` - The mean of each of the `n` dimensions.
+/// * `cov` - The `n x n` covariance matrix, which should be symmetric positive semi-definite.
+/// * `size` - The shape of the batch of samples. The output has shape `size + [n]`.
+/// * `method` - The factorization of `cov` to use.
+/// * `check_valid` - Whether a covariance matrix that is not positive semi-definite is an error.
+/// * `tol` - The tolerance of the positive semi-definite check. NumPy uses `1e-8`.
+///
+/// # Examples
+///
+/// The covariance of two observed series can be used to simulate more of them:
+///
+/// ```
+/// use ndarray::arr2;
+/// use numrust::covariance;
+/// use numrust::random::{multivariate_normal, CheckValid, CovMethod};
+///
+/// let cov = arr2(&covariance(&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.5, 5.5, 8.0]));
+/// let samples =
+///     multivariate_normal(&[0.0, 1.0], &cov, &[100], CovMethod::Cholesky, CheckValid::Raise, 1e-8)
+///         .unwrap();
+/// assert_eq!(samples.shape(), &[100, 2]);
+/// ```
+///
+/// # Errors
+///
+/// * Returns a `NumrustError::ValueError` if `cov` is not square or its size differs from the
+///   length of `mean`.
+/// * Returns a `NumrustError::ValueError` if `check_valid` is `CheckValid::Raise` and `cov` is not
+///   positive semi-definite within `tol`.
+/// * Returns a `NumrustError::LinAlgError` if `cov` contains non-finite values, or if `method` is
+///   `CovMethod::Cholesky` and `cov` is not positive definite.
+pub fn multivariate_normal<S>(
+    mean: &[f64],
+    cov: &ArrayBase<S, Ix2>,
+    size: &[usize],
+    method: CovMethod,
+    check_valid: CheckValid,
+    tol: f64,
+) -> Result<NdArray<f64>, NumrustError>
+where
+    S: Data<Elem = f64>,
+{
+    thread_generator().multivariate_normal(mean, cov, size, method, check_valid, tol)
+}
+
+/// Draws samples from a multinomial distribution: the counts of each of `pvals.len()` outcomes
+/// over `n` independent trials.
+///
+/// The last entry of `pvals` is ignored and replaced by whatever probability the others leave,
+/// so the counts in each sample always sum to `n`.
+///
+/// # Arguments
+///
+/// * `n` - The number of trials.
+/// * `pvals` - The probability of each outcome.
+/// * `size` - The shape of the batch of samples. The output has shape `size + [pvals.len()]`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::multinomial;
+///
+/// let rolls = multinomial(20, &[1.0 / 6.0; 6], &[3]).unwrap();
+/// assert_eq!(rolls.shape(), &[3, 6]);
+/// assert!(rolls.rows().into_iter().all(|row| row.sum() == 20));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `pvals` is empty, if any probability is outside
+/// `[0, 1]` or NaN, or if the probabilities other than the last sum to more than one.
+pub fn multinomial(n: u64, pvals: &[f64], size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+    thread_generator().multinomial(n, pvals, size)
+}
+
+/// Draws samples from a Dirichlet distribution with concentration parameters `alpha`.
+///
+/// Samples are normalized gamma variates. When every `alpha` is below 0.1 those variates
+/// underflow to zero too often, so stick-breaking with beta variates is used instead, as NumPy
+/// does.
+///
+/// # Arguments
+///
+/// * `alpha` - The concentration parameter of each of the `k` components.
+/// * `size` - The shape of the batch of samples. The output has shape `size + [k]`.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use numrust::random::dirichlet;
+///
+/// let weights = dirichlet(&[2.0, 3.0, 5.0], &[4]).unwrap();
+/// assert_eq!(weights.shape(), &[4, 3]);
+/// for row in weights.rows() {
+///     assert_abs_diff_eq!(row.sum(), 1.0, epsilon = 1e-12);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `alpha` is empty or any `alpha` is not positive.
+pub fn dirichlet(alpha: &[f64], size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+    thread_generator().dirichlet(alpha, size)
+}
+
+/// Shuffles `a` in place along `axis`, reordering its subarrays but not their contents.
+///
+/// Use [`Generator::shuffle`] for reproducible shuffles.
+///
+/// # Arguments
+///
+/// * `a` - The array to shuffle.
+/// * `axis` - The axis to shuffle along. Negative values count from the last axis.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::random::shuffle;
+///
+/// let mut a = array![[1, 2], [3, 4], [5, 6]];
+/// shuffle(&mut a, 0).unwrap();
+/// // Rows move as a whole.
+/// assert!(a.rows().into_iter().all(|row| row[1] == row[0] + 1));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn shuffle<A, S, D>(a: &mut ArrayBase<S, D>, axis: isize) -> Result<(), NumrustError>
+where
+    A: Clone,
+    S: DataMut<Elem = A>,
+    D: RemoveAxis,
+{
+    thread_generator().shuffle(a, axis)
+}
+
+/// Returns a random permutation of `0..n`.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::permutation;
+///
+/// let mut p = permutation(5).to_vec();
+/// p.sort();
+/// assert_eq!(p, vec![0, 1, 2, 3, 4]);
+/// ```
+pub fn permutation(n: usize) -> Array1<usize> {
+    thread_generator().permutation(n)
+}
+
+/// Returns a copy of `a` with its subarrays along `axis` randomly reordered, leaving `a`
+/// untouched.
+///
+/// This is the array form of NumPy's `permutation`, which accepts either an integer or an array.
+/// Rust has no overloading on argument types, so the integer form is [`permutation`] and the
+/// array form gets its own name.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::random::permutation_of;
+///
+/// let a = array![[1, 2, 3], [4, 5, 6]];
+/// let p = permutation_of(&a, 1).unwrap();
+/// // Columns move as a whole.
+/// assert!(p.columns().into_iter().all(|column| column[1] == column[0] + 3));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn permutation_of<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: isize,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    thread_generator().permutation_of(a, axis)
+}
+
+/// Returns a copy of `a` in which every one-dimensional slice along `axis` is shuffled
+/// independently of the others. With `axis` of `None` all elements are shuffled together.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::random::permuted;
+///
+/// let a = array![[1, 2, 3], [4, 5, 6]];
+/// let p = permuted(&a, Some(1)).unwrap();
+/// // Each row keeps its own elements.
+/// let mut first = p.row(0).to_vec();
+/// first.sort();
+/// assert_eq!(first, vec![1, 2, 3]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn permuted<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: Option<isize>,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    thread_generator().permuted(a, axis)
+}
+
+/// Returns a random permutation of `0..n` drawn from `rng`, as `Generator::permutation` does.
+pub(crate) fn permutation_from<R: RngCore + ?Sized>(rng: &mut R, n: usize) -> Array1<usize> {
+    let mut order: Vec<usize> = (0..n).collect();
+    bounded::shuffle_tail(rng, &mut order, 1);
+    Array1::from(order)
+}
+
+/// Returns the cumulative sums of `weights` divided by their total, as NumPy's `choice` uses.
+fn normalized_cdf(weights: &[f64]) -> Vec<f64> {
+    let mut cdf: Vec<f64> = weights
+        .iter()
+        .scan(0.0, |total, &w| {
+            *total += w;
+            Some(*total)
+        })
+        .collect();
+    let total = cdf[cdf.len() - 1];
+    for c in cdf.iter_mut() {
+        *c /= total;
+    }
+    cdf
+}
+
+/// Returns the index of the first entry of `cdf` above `x`, like `searchsorted(side="right")`.
+fn search_right(cdf: &[f64], x: f64) -> usize {
+    cdf.partition_point(|&c| c <= x)
+}
+
+/// Draws samples from the distributions of this module using a chosen bit generator.
+///
+/// The free functions of this module draw from the thread-local generator of the `rand` crate.
+/// A `Generator` offers the same samplers as methods, so results can be reproduced exactly by
+/// seeding one of the bit generators.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::{Generator, Pcg64, Philox};
+///
+/// let mut a = Generator::new(Pcg64::new(42, 54));
+/// let mut b = Generator::new(Pcg64::new(42, 54));
+/// assert_eq!(a.normal(0.0, 1.0, 5).unwrap(), b.normal(0.0, 1.0, 5).unwrap());
+///
+/// let mut c = Generator::new(Philox::new([0; 4], [1, 2]));
+/// let rolls = c.randint(1, 7, 10);
+/// assert!(rolls.iter().all(|r| (1..7).contains(r)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generator<B: BitGenerator> {
+    bit_generator: B,
+}
+
+/// Returns a generator drawing from the thread-local generator of the `rand` crate.
+fn thread_generator() -> Generator<ThreadRng> {
+    Generator::new(thread_rng())
+}
+
+/// Returns a `Pcg64` generator seeded through `SeedSequence`, like NumPy's
+/// `numpy.random.default_rng(seed)`. For the same seed, `random`, `integers` and `choice` produce
+/// the same values as NumPy's methods of the same names. `standard_normal` and `normal` follow
+/// NumPy's ziggurat method too, but a few of its table entries are rounded differently, so
+/// some draws differ from NumPy's in the last bits.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::default_rng;
+///
+/// let mut rng = default_rng(42);
+/// assert_eq!(rng.random(&[1])[0], 0.7739560485559633);
+/// ```
+pub fn default_rng(seed: u128) -> Generator<Pcg64> {
+    Generator::new(Pcg64::from_seed_sequence(&SeedSequence::new(seed)))
+}
+
+impl<B: BitGenerator> Generator<B> {
+    /// Creates a generator drawing from `bit_generator`.
+    pub fn new(bit_generator: B) -> Self {
+        Generator { bit_generator }
+    }
+
+    /// Returns a reference to the underlying bit generator.
+    pub fn bit_generator(&self) -> &B {
+        &self.bit_generator
+    }
+
+    /// Returns a mutable reference to the underlying bit generator.
+    pub fn bit_generator_mut(&mut self) -> &mut B {
+        &mut self.bit_generator
+    }
+
+    /// Returns the underlying bit generator.
+    pub fn into_bit_generator(self) -> B {
+        self.bit_generator
+    }
+
+    /// Draws an array of the given shape, calling `f` once per element.
+    fn sample_array<T, F>(&mut self, size: &[usize], mut f: F) -> NdArray<T>
+    where
+        F: FnMut(&mut B) -> T,
+    {
+        let rng = &mut self.bit_generator;
+        Array::from_shape_simple_fn(IxDyn(size), || f(rng))
+    }
+
+    /// Draws doubles uniformly from `[0, 1)`, using the top 53 bits of each word of the bit
+    /// generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::{Generator, Sfc64};
+    ///
+    /// let mut rng = Generator::new(Sfc64::new([1, 2, 3]));
+    /// let samples = rng.random(&[2, 2]);
+    /// assert!(samples.iter().all(|&x| (0.0..1.0).contains(&x)));
+    /// ```
+    pub fn random(&mut self, size: &[usize]) -> NdArray<f64> {
+        self.sample_array(size, |rng| rng.next_double())
+    }
+
+    /// Returns a vector of `size` elements randomly chosen from the array `a`.
+    ///
+    /// See [`choice`](fn@choice) for details.
+    pub fn choice<T: Clone>(
+        &mut self,
+        a: &[T],
+        size: usize,
+        replace: bool,
+        p: Option<&[f64]>,
+    ) -> Vec<T> {
+        if !replace & (size > a.len()) {
+            panic!("`size` cannot be greater than the length of `a` if `replace` is false");
+        }
+        if a.is_empty() && size > 0 {
+            panic!("`a` cannot be empty unless no samples are taken");
+        }
+
+        if let Some(probs) = p {
+            if probs.len() != a.len() {
+                panic!("`a` must be the same length as `p`");
+            }
+            if !probs.iter().all(|w| w.is_finite() && *w >= 0.0) {
+                panic!("probabilities are not non-negative");
+            }
+            if probs.iter().all(|&w| w == 0.0) {
+                panic!("probabilities must not sum to zero");
+            }
+        }
+
+        let indices: Vec<usize> = match (p, replace) {
+            (None, true) => (0..size)
+                .map(|_| bounded::bounded_u64(&mut self.bit_generator, a.len() as u64 - 1) as usize)
+                .collect(),
+            (None, false) => self.uniform_indices_without_replacement(a.len(), size),
+            (Some(probs), true) => {
+                let cdf = normalized_cdf(probs);
+                (0..size)
+                    .map(|_| search_right(&cdf, self.bit_generator.next_double()))
+                    .collect()
+            }
+            (Some(probs), false) => self.weighted_indices_without_replacement(probs, size),
+        };
+        indices.into_iter().map(|i| a[i].clone()).collect()
+    }
+
+    /// Draws `size` distinct indices below `pop_size` with NumPy's algorithms: a partial
+    /// shuffle when many indices are wanted from a large population, and otherwise Floyd's
+    /// algorithm with a hash set, which takes O(size) time, followed by a shuffle. Both shuffles
+    /// draw with Lemire's method, as NumPy's `_shuffle_int` does.
+    fn uniform_indices_without_replacement(&mut self, pop_size: usize, size: usize) -> Vec<usize> {
+        let rng = &mut self.bit_generator;
+        if pop_size > 10000 && size > pop_size / 50 {
+            let mut indices: Vec<usize> = (0..pop_size).collect();
+            bounded::shuffle_int(rng, &mut indices, (pop_size - size).max(1));
+            return indices.split_off(pop_size - size);
+        }
+
+        // An open-addressing hash set of at least 1.2 * size slots, with u64::MAX as empty.
+        let mask = u64::MAX
+            .checked_shr(((1.2 * size as f64) as u64).leading_zeros())
+            .unwrap_or(0);
+        let mut slots = vec![u64::MAX; mask as usize + 1];
+        let mut indices = Vec::with_capacity(size);
+        for j in (pop_size - size) as u64..pop_size as u64 {
+            let value = bounded::bounded_u64(rng, j);
+            let mut loc = value & mask;
+            while slots[loc as usize] != u64::MAX && slots[loc as usize] != value {
+                loc = (loc + 1) & mask;
+            }
+            // Insert `value` if it is new, and otherwise `j`, which cannot have been drawn yet.
+            let chosen = if slots[loc as usize] == u64::MAX {
+                value
+            } else {
+                loc = j & mask;
+                while slots[loc as usize] != u64::MAX {
+                    loc = (loc + 1) & mask;
+                }
+                j
+            };
+            slots[loc as usize] = chosen;
+            indices.push(chosen as usize);
+        }
+        bounded::shuffle_int(rng, &mut indices, 1);
+        indices
+    }
+
+    /// Draws `size` distinct indices with probabilities proportional to `weights` the way NumPy
+    /// does: draw a batch with replacement, keep the first occurrence of each new index, zero
+    /// the weights of the indices kept so far, and repeat for the rest.
+    fn weighted_indices_without_replacement(&mut self, weights: &[f64], size: usize) -> Vec<usize> {
+        if weights.iter().filter(|&&w| w > 0.0).count() < size {
+            panic!("Fewer non-zero entries in p than size");
+        }
+        let mut weights = weights.to_vec();
+        let mut selected = vec![false; weights.len()];
+        let mut found: Vec<usize> = Vec::with_capacity(size);
+        while found.len() < size {
+            let draws: Vec<f64> = (0..size - found.len())
+                .map(|_| self.bit_generator.next_double())
+                .collect();
+            let cdf = normalized_cdf(&weights);
+            // Keep the first occurrence of each index, as NumPy's `unique` with `return_index`
+            // does, and drop its weight from the next round.
+            for x in draws {
+                let i = search_right(&cdf, x);
+                if !selected[i] {
+                    selected[i] = true;
+                    weights[i] = 0.0;
+                    found.push(i);
+                }
+            }
+        }
+        found
+    }
+
+    /// Generates samples from a binomial distribution with parameters `n` and `p`.
+    ///
+    /// See [`binomial`](fn@binomial) for details.
+    pub fn binomial(&mut self, n: u64, p: f64, size: usize) -> Result<Vec<u64>, NumrustError> {
+        let rng = &mut self.bit_generator;
+        let binom = Binomial::new(n, p).map_err(|e| NumrustError::ValueError(e.to_string()))?;
+        let mut nums = Vec::with_capacity(size);
+        for _ in 0..size {
+            let num = binom.sample(rng);
+            nums.push(num);
+        }
+        Ok(nums)
+    }
+
+    /// Generates a vector of `n` random samples from a normal (Gaussian) distribution
+    /// with the specified `mean` and `standard deviation`.
+    ///
+    /// See [`normal`](fn@normal) for details.
+    pub fn normal<T: Into<f64> + Copy>(
+        &mut self,
+        mean: T,
+        std: T,
+        n: usize,
+    ) -> Result<Vec<f64>, NumrustError> {
+        let (mean, std) = (mean.into(), std.into());
+        check_param(std >= 0.0, "scale < 0")?;
+        Ok((0..n)
+            .map(|_| mean + std * ziggurat::standard_normal(&mut self.bit_generator))
+            .collect())
+    }
+
+    /// Draws samples from the standard normal distribution with NumPy's ziggurat method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::default_rng;
+    ///
+    /// let samples = default_rng(42).standard_normal(&[2]);
+    /// assert_eq!(samples[0], 0.30471707975443135);
+    /// assert_eq!(samples[1], -1.0399841062404955);
+    /// ```
+    pub fn standard_normal(&mut self, size: &[usize]) -> NdArray<f64> {
+        self.sample_array(size, |rng| ziggurat::standard_normal(rng))
+    }
+
+    /// Draws integers uniformly from `[low, high)`, or from `[low, high]` if `endpoint` is
+    /// `true`.
+    ///
+    /// See [`integers`](fn@integers) for details. 32- and 64-bit draws reproduce NumPy's
+    /// `Generator.integers` exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::default_rng;
+    ///
+    /// let rolls = default_rng(42).integers(0i64, 10, &[5], false).unwrap();
+    /// assert_eq!(rolls.into_raw_vec(), vec![0, 7, 6, 4, 4]);
+    /// ```
+    pub fn integers<T: UniformInteger>(
+        &mut self,
+        low: T,
+        high: T,
+        size: &[usize],
+        endpoint: bool,
+    ) -> Result<NdArray<T>, NumrustError> {
+        let high = if endpoint {
+            check_param(low <= high, "low > high")?;
+            high
+        } else {
+            check_param(low < high, "low >= high")?;
+            high.predecessor()
+        };
+        let n = size.iter().product();
+        let values = T::sample_inclusive(&mut self.bit_generator, low, high, n);
+        Ok(Array::from_shape_vec(IxDyn(size), values).expect("one value per element"))
+    }
+
+    /// Generate a list of `n` random integers between `min` (inclusive) and `max` (exclusive).
+    ///
+    /// See [`randint`](fn@randint) for details.
+    pub fn randint(&mut self, min: i32, max: i32, n: usize) -> Vec<i32> {
+        let rng = &mut self.bit_generator;
+        (0..n).map(|_| rng.gen_range(min..max)).collect()
+    }
+
+    /// Draws samples uniformly from the half-open interval `[low, high)`.
+    ///
+    /// See [`uniform`](fn@uniform) for details.
+    pub fn uniform(
+        &mut self,
+        low: f64,
+        high: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param((high - low).is_finite(), "high - low must be finite")?;
+        Ok(self.sample_array(size, |rng| low + (high - low) * rng.gen::<f64>()))
+    }
+
+    /// Draws samples from an exponential distribution with the given `scale` (the inverse of the
+    /// rate).
+    ///
+    /// See [`exponential`](fn@exponential) for details.
+    pub fn exponential(
+        &mut self,
+        scale: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(scale >= 0.0, "scale < 0")?;
+        Ok(self.sample_array(size, |rng| scale * rng.sample::<f64, _>(Exp1)))
+    }
+
+    /// Draws samples from a gamma distribution with the given `shape` (k) and `scale` (θ).
+    ///
+    /// See [`gamma`](fn@gamma) for details.
+    pub fn gamma(
+        &mut self,
+        shape: f64,
+        scale: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(shape >= 0.0, "shape < 0")?;
+        check_param(scale >= 0.0, "scale < 0")?;
+        if shape == 0.0 || scale == 0.0 {
+            return Ok(self.sample_array(size, |_| 0.0));
+        }
+        let dist = Gamma::new(shape, scale).expect("parameters are positive");
+        Ok(self.sample_array(size, |rng| dist.sample(rng)))
+    }
+
+    /// Draws samples from a beta distribution on `[0, 1]` with shape parameters `a` and `b`.
+    ///
+    /// See [`beta`](fn@beta) for details.
+    pub fn beta(&mut self, a: f64, b: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+        check_param(a > 0.0, "a <= 0")?;
+        check_param(b > 0.0, "b <= 0")?;
+        let dist = Beta::new(a, b).expect("parameters are positive");
+        Ok(self.sample_array(size, |rng| dist.sample(rng)))
+    }
+
+    /// Draws samples from a chi-square distribution with `df` degrees of freedom.
+    ///
+    /// See [`chisquare`](fn@chisquare) for details.
+    pub fn chisquare(&mut self, df: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+        check_param(df > 0.0, "df <= 0")?;
+        let dist = ChiSquared::new(df).expect("df is positive");
+        Ok(self.sample_array(size, |rng| dist.sample(rng)))
+    }
+
+    /// Draws samples from a noncentral chi-square distribution with `df` degrees of freedom and
+    /// noncentrality `nonc`, using the same decomposition as NumPy.
+    ///
+    /// See [`noncentral_chisquare`](fn@noncentral_chisquare) for details.
+    pub fn noncentral_chisquare(
+        &mut self,
+        df: f64,
+        nonc: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(df > 0.0, "df <= 0")?;
+        check_param(nonc >= 0.0, "nonc < 0")?;
+        check_param(nonc.is_finite(), "nonc must be finite")?;
+        if nonc == 0.0 {
+            return self.chisquare(df, size);
+        }
+        if df > 1.0 {
+            // A chi-square with df - 1 degrees of freedom plus one squared normal shifted by
+            // sqrt(nonc).
+            let central = ChiSquared::new(df - 1.0).expect("df - 1 is positive");
+            Ok(self.sample_array(size, |rng| {
+                let n = rng.sample::<f64, _>(StandardNormal) + nonc.sqrt();
+                central.sample(rng) + n * n
+            }))
+        } else {
+            // A Poisson mixture of central chi-squares with df + 2i degrees of freedom.
+            check_param(nonc / 2.0 <= POISSON_LAM_MAX, "nonc value too large")?;
+            let poisson = rand_distr::Poisson::new(nonc / 2.0).expect("nonc is positive");
+            Ok(self.sample_array(size, |rng| {
+                let i: f64 = poisson.sample(rng);
+                ChiSquared::new(df + 2.0 * i)
+                    .expect("df is positive")
+                    .sample(rng)
+            }))
+        }
+    }
+
+    /// Draws samples from Student's t distribution with `df` degrees of freedom.
+    ///
+    /// See [`standard_t`](fn@standard_t) for details.
+    pub fn standard_t(&mut self, df: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+        check_param(df > 0.0, "df <= 0")?;
+        let dist = StudentT::new(df).expect("df is positive");
+        Ok(self.sample_array(size, |rng| dist.sample(rng)))
+    }
+
+    /// Draws samples from an F (Fisher–Snedecor) distribution with `dfnum` and `dfden` degrees of
+    /// freedom.
+    ///
+    /// See [`f`](fn@f) for details.
+    pub fn f(
+        &mut self,
+        dfnum: f64,
+        dfden: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(dfnum > 0.0, "dfnum <= 0")?;
+        check_param(dfden > 0.0, "dfden <= 0")?;
+        let dist = FisherF::new(dfnum, dfden).expect("parameters are positive");
+        Ok(self.sample_array(size, |rng| dist.sample(rng)))
+    }
+
+    /// Draws samples from a log-normal distribution, whose logarithm is normal with the given
+    /// `mean` and standard deviation `sigma`.
+    ///
+    /// See [`lognormal`](fn@lognormal) for details.
+    pub fn lognormal(
+        &mut self,
+        mean: f64,
+        sigma: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(sigma >= 0.0, "sigma < 0")?;
+        Ok(self.sample_array(size, |rng| {
+            (mean + sigma * rng.sample::<f64, _>(StandardNormal)).exp()
+        }))
+    }
+
+    /// Draws samples from a Laplace (double exponential) distribution with location `loc` and
+    /// scale `scale`.
+    ///
+    /// See [`laplace`](fn@laplace) for details.
+    pub fn laplace(
+        &mut self,
+        loc: f64,
+        scale: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(scale >= 0.0, "scale < 0")?;
+        Ok(self.sample_array(size, |rng| {
+            let u = open_unit(rng);
+            if u >= 0.5 {
+                loc - scale * (2.0 - 2.0 * u).ln()
+            } else {
+                loc + scale * (2.0 * u).ln()
+            }
+        }))
+    }
+
+    /// Draws samples from a logistic distribution with location `loc` and scale `scale`.
+    ///
+    /// See [`logistic`](fn@logistic) for details.
+    pub fn logistic(
+        &mut self,
+        loc: f64,
+        scale: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(scale >= 0.0, "scale < 0")?;
+        Ok(self.sample_array(size, |rng| {
+            let u = open_unit(rng);
+            loc + scale * (u / (1.0 - u)).ln()
+        }))
+    }
+
+    /// Draws samples from a Gumbel (type I extreme value) distribution with location `loc` and
+    /// scale `scale`.
+    ///
+    /// See [`gumbel`](fn@gumbel) for details.
+    pub fn gumbel(
+        &mut self,
+        loc: f64,
+        scale: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(scale >= 0.0, "scale < 0")?;
+        Ok(self.sample_array(size, |rng| loc - scale * (-open_unit(rng).ln()).ln()))
+    }
+
+    /// Draws samples from a one-parameter Weibull distribution with shape `a`, as NumPy's
+    /// `weibull` does. Multiply by λ for a two-parameter Weibull.
+    ///
+    /// See [`weibull`](fn@weibull) for details.
+    pub fn weibull(&mut self, a: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+        check_param(a >= 0.0, "a < 0")?;
+        if a == 0.0 {
+            return Ok(self.sample_array(size, |_| 0.0));
+        }
+        Ok(self.sample_array(size, |rng| rng.sample::<f64, _>(Exp1).powf(1.0 / a)))
+    }
+
+    /// Draws samples from a Pareto II (Lomax) distribution with shape `a`, as NumPy's `pareto`
+    /// does. Add 1 and multiply by the mode for a classical Pareto distribution.
+    ///
+    /// See [`pareto`](fn@pareto) for details.
+    pub fn pareto(&mut self, a: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+        check_param(a > 0.0, "a <= 0")?;
+        Ok(self.sample_array(size, |rng| (rng.sample::<f64, _>(Exp1) / a).exp_m1()))
+    }
+
+    /// Draws samples from a Rayleigh distribution with the given `scale` (mode).
+    ///
+    /// See [`rayleigh`](fn@rayleigh) for details.
+    pub fn rayleigh(&mut self, scale: f64, size: &[usize]) -> Result<NdArray<f64>, NumrustError> {
+        check_param(scale >= 0.0, "scale < 0")?;
+        Ok(self.sample_array(size, |rng| {
+            scale * (2.0 * rng.sample::<f64, _>(Exp1)).sqrt()
+        }))
+    }
+
+    /// Draws samples from a Cauchy (Lorentz) distribution with location `loc` and scale `scale`.
+    /// `cauchy(0.0, 1.0, size)` is NumPy's `standard_cauchy(size)`.
+    ///
+    /// See [`cauchy`](fn@cauchy) for details.
+    pub fn cauchy(
+        &mut self,
+        loc: f64,
+        scale: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(loc.is_finite(), "loc must be finite")?;
+        check_param(scale >= 0.0, "scale < 0")?;
+        Ok(self.sample_array(size, |rng| {
+            loc + scale * (PI * (open_unit(rng) - 0.5)).tan()
+        }))
+    }
+
+    /// Draws samples from a triangular distribution on `[left, right]` with peak at `mode`.
+    ///
+    /// See [`triangular`](fn@triangular) for details.
+    pub fn triangular(
+        &mut self,
+        left: f64,
+        mode: f64,
+        right: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(left <= mode, "left > mode")?;
+        check_param(mode <= right, "mode > right")?;
+        check_param(left != right, "left == right")?;
+        let base = right - left;
+        let left_base = mode - left;
+        let ratio = left_base / base;
+        Ok(self.sample_array(size, |rng| {
+            let u: f64 = rng.gen();
+            if u <= ratio {
+                left + (u * base * left_base).sqrt()
+            } else {
+                right - ((1.0 - u) * base * (right - mode)).sqrt()
+            }
+        }))
+    }
+
+    /// Draws samples from a von Mises distribution on `[-π, π]` with mode `mu` and concentration
+    /// `kappa`, using the Best–Fisher algorithm as NumPy does.
+    ///
+    /// See [`vonmises`](fn@vonmises) for details.
+    pub fn vonmises(
+        &mut self,
+        mu: f64,
+        kappa: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(mu.is_finite(), "mu must be finite")?;
+        check_param(kappa >= 0.0, "kappa < 0")?;
+        Ok(self.sample_array(size, |rng| vonmises_one(rng, mu, kappa)))
     }
 
-    if p.is_some() {
-        if p.unwrap().len() != a.len() {
-            panic!("`a` must be the same length as `p`");
+    /// Draws samples from a Wald (inverse Gaussian) distribution with the given `mean` and `scale`
+    /// (shape λ).
+    ///
+    /// See [`wald`](fn@wald) for details.
+    pub fn wald(
+        &mut self,
+        mean: f64,
+        scale: f64,
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(mean > 0.0, "mean <= 0")?;
+        check_param(scale > 0.0, "scale <= 0")?;
+        let mu_2l = mean / (2.0 * scale);
+        Ok(self.sample_array(size, |rng| {
+            let n: f64 = rng.sample(StandardNormal);
+            let y = mean * n * n;
+            let x = mean + mu_2l * (y - (4.0 * scale * y + y * y).sqrt());
+            if rng.gen::<f64>() <= mean / (mean + x) {
+                x
+            } else {
+                mean * mean / x
+            }
+        }))
+    }
+
+    /// Draws samples from a Poisson distribution with expected number of events `lam`.
+    ///
+    /// See [`poisson`](fn@poisson) for details.
+    pub fn poisson(&mut self, lam: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+        check_param(lam >= 0.0, "lam < 0")?;
+        check_param(lam <= POISSON_LAM_MAX, "lam value too large")?;
+        if lam == 0.0 {
+            return Ok(self.sample_array(size, |_| 0));
         }
+        let dist = Poisson::new(lam).expect("lam is positive");
+        Ok(self.sample_array(size, |rng| dist.sample(rng) as i64))
     }
 
-    let mut rng = rand::thread_rng();
-    let dist = match p {
-        Some(probs) => WeightedIndex::new(probs).unwrap(),
-        None => WeightedIndex::new(vec![1.0 / a.len() as f64; a.len()]).unwrap(),
-    };
-    let mut result = Vec::with_capacity(size);
-    if replace {
-        for _ in 0..size {
-            result.push(a[dist.sample(&mut rng)].clone());
+    /// Draws samples from a geometric distribution: the number of Bernoulli trials with success
+    /// probability `p` up to and including the first success.
+    ///
+    /// See [`geometric`](fn@geometric) for details.
+    pub fn geometric(&mut self, p: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+        check_param(p > 0.0 && p <= 1.0, "p must be in the interval (0, 1]")?;
+        let dist = Geometric::new(p).expect("p is a probability");
+        Ok(self.sample_array(size, |rng| (dist.sample(rng) as i64).saturating_add(1)))
+    }
+
+    /// Draws samples from a negative binomial distribution: the number of failures before `n`
+    /// successes in Bernoulli trials with success probability `p`. `n` may be non-integral.
+    ///
+    /// See [`negative_binomial`](fn@negative_binomial) for details.
+    pub fn negative_binomial(
+        &mut self,
+        n: f64,
+        p: f64,
+        size: &[usize],
+    ) -> Result<NdArray<i64>, NumrustError> {
+        check_param(n > 0.0, "n <= 0")?;
+        check_param(p > 0.0 && p <= 1.0, "p must be in the interval (0, 1]")?;
+        if p == 1.0 {
+            return Ok(self.sample_array(size, |_| 0));
         }
-    } else {
-        let mut indices = (0..a.len()).collect::<Vec<_>>();
-        for _ in 0..size {
-            let i = dist.sample(&mut rng);
-            result.push(a[indices[i]].clone());
-            if !replace {
-                indices.swap_remove(i);
+        // Like NumPy, bound the gamma mean plus ten standard deviations so that every Poisson
+        // draw stays within POISSON_LAM_MAX.
+        let max_lam = (1.0 - p) / p * (n + 10.0 * n.sqrt());
+        check_param(
+            max_lam <= POISSON_LAM_MAX,
+            "n too large or p too small, see Generator::negative_binomial",
+        )?;
+        // A Poisson distribution whose mean is gamma distributed, as NumPy samples it.
+        let gamma = Gamma::new(n, (1.0 - p) / p).expect("parameters are positive");
+        Ok(self.sample_array(size, |rng| {
+            let lam: f64 = gamma.sample(rng);
+            if lam > 0.0 {
+                Poisson::new(lam).expect("lam is positive").sample(rng) as i64
+            } else {
+                0
+            }
+        }))
+    }
+
+    /// Draws samples from a hypergeometric distribution: the number of good items among `nsample`
+    /// items drawn without replacement from `ngood` good and `nbad` bad items.
+    ///
+    /// See [`hypergeometric`](fn@hypergeometric) for details.
+    pub fn hypergeometric(
+        &mut self,
+        ngood: u64,
+        nbad: u64,
+        nsample: u64,
+        size: &[usize],
+    ) -> Result<NdArray<i64>, NumrustError> {
+        let total = ngood
+            .checked_add(nbad)
+            .ok_or_else(|| NumrustError::ValueError("ngood + nbad is too large".to_string()))?;
+        check_param(nsample <= total, "ngood + nbad < nsample")?;
+        let dist = Hypergeometric::new(total, ngood, nsample).expect("nsample <= ngood + nbad");
+        Ok(self.sample_array(size, |rng| dist.sample(rng) as i64))
+    }
+
+    /// Draws samples from a logarithmic series distribution with shape `p`, using Kemp's algorithm
+    /// as NumPy does.
+    ///
+    /// See [`logseries`](fn@logseries) for details.
+    pub fn logseries(&mut self, p: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+        check_param((0.0..1.0).contains(&p), "p must be in the interval [0, 1)")?;
+        let r = (-p).ln_1p();
+        Ok(self.sample_array(size, |rng| loop {
+            let v: f64 = rng.gen();
+            if v >= p {
+                break 1;
+            }
+            let u: f64 = rng.gen();
+            let q = -(r * u).exp_m1();
+            if v <= q * q {
+                let k = (1.0 + v.ln() / q.ln()).floor();
+                if k < 1.0 || v == 0.0 {
+                    continue;
+                }
+                break k as i64;
+            }
+            break if v >= q { 1 } else { 2 };
+        }))
+    }
+
+    /// Draws samples from a Zipf distribution with exponent `a`, using NumPy's rejection
+    /// algorithm.
+    ///
+    /// See [`zipf`](fn@zipf) for details.
+    pub fn zipf(&mut self, a: f64, size: &[usize]) -> Result<NdArray<i64>, NumrustError> {
+        check_param(a > 1.0, "a <= 1")?;
+        let am1 = a - 1.0;
+        let b = 2f64.powf(am1);
+        Ok(self.sample_array(size, |rng| loop {
+            let u = 1.0 - rng.gen::<f64>();
+            let v: f64 = rng.gen();
+            let x = u.powf(-1.0 / am1).floor();
+            // Reject values that would overflow an i64, as well as the NaN from u = 0.
+            if !(1.0..=i64::MAX as f64).contains(&x) {
+                continue;
+            }
+            let t = (1.0 + 1.0 / x).powf(am1);
+            if v * x * (t - 1.0) / (b - 1.0) <= t / b {
+                break x as i64;
+            }
+        }))
+    }
+
+    /// Draws Bernoulli trials that are `true` with probability `p`.
+    ///
+    /// See [`bernoulli`](fn@bernoulli) for details.
+    pub fn bernoulli(&mut self, p: f64, size: &[usize]) -> Result<NdArray<bool>, NumrustError> {
+        check_param((0.0..=1.0).contains(&p), "p must be in the interval [0, 1]")?;
+        Ok(self.sample_array(size, |rng| rng.gen::<f64>() < p))
+    }
+
+    /// Draws samples from a multivariate normal distribution.
+    ///
+    /// See [`multivariate_normal`](fn@multivariate_normal) for details.
+    pub fn multivariate_normal<S>(
+        &mut self,
+        mean: &[f64],
+        cov: &ArrayBase<S, Ix2>,
+        size: &[usize],
+        method: CovMethod,
+        check_valid: CheckValid,
+        tol: f64,
+    ) -> Result<NdArray<f64>, NumrustError>
+    where
+        S: Data<Elem = f64>,
+    {
+        let n = mean.len();
+        check_param(
+            cov.nrows() == cov.ncols(),
+            "cov must be 2 dimensional and square",
+        )?;
+        check_param(cov.nrows() == n, "mean and cov must have same length")?;
+
+        let (factor, psd) = match method {
+            CovMethod::Svd => {
+                let (_, s, vt) = svd(cov)?;
+                let reconstructed = vt.t().dot(&(&vt * &s.view().insert_axis(Axis(1))));
+                let psd = allclose(&reconstructed, cov, tol, tol, false)?;
+                (&vt * &s.mapv(f64::sqrt).insert_axis(Axis(1)), psd)
+            }
+            CovMethod::Eigh => {
+                let (s, u) = eigh(cov)?;
+                let psd = s.iter().all(|&v| v >= -tol);
+                let s = s.mapv(|v| v.max(0.0).sqrt());
+                (&u.t() * &s.insert_axis(Axis(1)), psd)
             }
+            CovMethod::Cholesky => (cholesky(cov)?.reversed_axes(), true),
+        };
+        if !psd && check_valid == CheckValid::Raise {
+            return Err(NumrustError::ValueError(
+                "covariance is not symmetric positive-semidefinite.".to_string(),
+            ));
         }
+
+        let count = size.iter().product::<usize>();
+        let rng = &mut self.bit_generator;
+        let z = Array2::from_shape_simple_fn((count, n), || rng.sample::<f64, _>(StandardNormal));
+        let samples = z.dot(&factor) + ndarray::aview1(mean);
+        let shape: Vec<usize> = size.iter().copied().chain([n]).collect();
+        Ok(samples
+            .into_shape(IxDyn(&shape))
+            .expect("the sample count matches the shape"))
     }
-    result
-}
 
-/// Generates samples from a binomial distribution with parameters `n` and `p`.
-///
-/// The binomial distribution models the number of successes in a fixed number of independent
-/// Bernoulli trials, where each trial has a probability `p` of success. The `n` parameter specifies
-/// the number of trials, and `p` specifies the probability of success.
-///
-/// # Arguments
-///
-/// * `n` - The number of Bernoulli trials.
-/// * `p` - The probability of success in each Bernoulli trial.
-/// * `size` - The number of samples to generate.
-///
-/// # Panics
-///
-/// This function will panic if the `Binomial::new` constructor fails. This can occur if `n` is zero,
-/// if `p` is not between 0 and 1, or if the `Binomial` distribution is not supported by the underlying
-/// random number generator.
-///
-/// # Returns
-///
-/// A `Vec` containing `size` samples drawn from the binomial distribution with parameters `n` and `p`.
-///
-/// # Examples
-///
-/// ```
-/// use numrust::random::binomial;
-/// use rand_distr::Binomial;
-/// use rand::thread_rng;
-///
-/// fn main() {
-///     let n = 10;
-///     let p = 0.5;
-///     let size = 100;
-///
-///     let data = binomial(n, p, size).unwrap();
-///
-///     assert_eq!(data.len(), size);
-/// }
-/// ```
-pub fn binomial(n: u64, p: f64, size: usize) -> Result<Vec<u64>, BinomialError> {
-    let mut rng = thread_rng();
-    let binom = Binomial::new(n, p)?;
-    let mut nums = Vec::with_capacity(size);
-    for _ in 0..size {
-        let num = binom.sample(&mut rng);
-        nums.push(num);
+    /// Draws samples from a multinomial distribution: the counts of each of `pvals.len()` outcomes
+    /// over `n` independent trials.
+    ///
+    /// See [`multinomial`](fn@multinomial) for details.
+    pub fn multinomial(
+        &mut self,
+        n: u64,
+        pvals: &[f64],
+        size: &[usize],
+    ) -> Result<NdArray<i64>, NumrustError> {
+        check_param(!pvals.is_empty(), "pvals must have at least one element")?;
+        check_param(
+            pvals.iter().all(|p| (0.0..=1.0).contains(p)),
+            "pvals < 0, pvals > 1 or pvals contains NaNs",
+        )?;
+        let k = pvals.len();
+        let leading = &pvals[..k - 1];
+        check_param(
+            leading.iter().sum::<f64>() <= 1.0 + 1e-12,
+            "sum(pvals[:-1]) > 1.0",
+        )?;
+
+        let count = size.iter().product::<usize>();
+        let rng = &mut self.bit_generator;
+        let mut out = Array2::<i64>::zeros((count, k));
+        for mut row in out.rows_mut() {
+            // Each count is binomial given the trials and probability mass the earlier ones left.
+            let mut remaining = n;
+            let mut remaining_p = 1.0;
+            for (j, &p) in leading.iter().enumerate() {
+                if remaining == 0 {
+                    break;
+                }
+                let ratio = (p / remaining_p).clamp(0.0, 1.0);
+                let drawn = Binomial::new(remaining, ratio)
+                    .expect("ratio is a probability")
+                    .sample(rng);
+                row[j] = drawn as i64;
+                remaining -= drawn;
+                remaining_p -= p;
+            }
+            row[k - 1] = remaining as i64;
+        }
+        let shape: Vec<usize> = size.iter().copied().chain([k]).collect();
+        Ok(out
+            .into_shape(IxDyn(&shape))
+            .expect("the sample count matches the shape"))
     }
-    Ok(nums)
-}
 
-/// Generates a vector of `n` random samples from a normal (Gaussian) distribution
-/// with the specified `mean` and `standard deviation`.
-///
-/// # Arguments
-///
-/// * `mean` - the mean of the normal distribution
-/// * `std` - the standard deviation of the normal distribution
-/// * `n` - the number of samples to generate
-///
-/// # Returns
-///
-/// A `Vec` of `n` random samples from a normal distribution with the specified mean
-/// and standard deviation.
-///
-/// # Example
-///
-/// ```
-/// use numrust::random::normal;
-/// use rand_distr::Normal;
-/// use rand::prelude::*;
-///
-/// let mean = 0.0;
-/// let std = 1.0;
-/// let n = 100;
-///
-/// let data = normal(mean, std, n);
-///
-/// println!("{:?}", data);
-/// ```
-///
-/// This example generates 100 random samples from a standard normal distribution (i.e.
-/// a normal distribution with mean 0 and standard deviation 1) and prints them to the console.
-///
-/// # Panics
-///
-/// This function will panic if the `Normal::new` constructor fails to create a normal distribution
-/// with the specified mean and standard deviation.
-pub fn normal<T: Into<f64> + Copy>(mean: T, std: T, n: usize) -> Result<Vec<f64>, NormalError> {
-    let normal = Normal::new(mean.into(), std.into())?;
-    let mut rng = thread_rng();
-    let mut data = Vec::with_capacity(n);
+    /// Draws samples from a Dirichlet distribution with concentration parameters `alpha`.
+    ///
+    /// See [`dirichlet`](fn@dirichlet) for details.
+    pub fn dirichlet(
+        &mut self,
+        alpha: &[f64],
+        size: &[usize],
+    ) -> Result<NdArray<f64>, NumrustError> {
+        check_param(!alpha.is_empty(), "alpha must have at least one element")?;
+        check_param(alpha.iter().all(|&a| a > 0.0), "alpha <= 0")?;
+        let k = alpha.len();
+        let count = size.iter().product::<usize>();
+        let rng = &mut self.bit_generator;
+        let mut out = Array2::<f64>::zeros((count, k));
+
+        if alpha.iter().all(|&a| a < 0.1) {
+            // tail[j] is the total concentration of the components after j.
+            let mut tail = vec![0.0; k];
+            for j in (0..k - 1).rev() {
+                tail[j] = tail[j + 1] + alpha[j + 1];
+            }
+            for mut row in out.rows_mut() {
+                let mut remaining = 1.0;
+                for j in 0..k - 1 {
+                    let v: f64 = Beta::new(alpha[j], tail[j])
+                        .expect("parameters are positive")
+                        .sample(rng);
+                    row[j] = remaining * v;
+                    remaining *= 1.0 - v;
+                }
+                row[k - 1] = remaining;
+            }
+        } else {
+            let gammas: Vec<Gamma<f64>> = alpha
+                .iter()
+                .map(|&a| Gamma::new(a, 1.0).expect("alpha is positive"))
+                .collect();
+            for mut row in out.rows_mut() {
+                for (x, dist) in row.iter_mut().zip(&gammas) {
+                    *x = dist.sample(rng);
+                }
+                let total = row.sum();
+                row /= total;
+            }
+        }
+        let shape: Vec<usize> = size.iter().copied().chain([k]).collect();
+        Ok(out
+            .into_shape(IxDyn(&shape))
+            .expect("the sample count matches the shape"))
+    }
+
+    /// Shuffles `a` in place along `axis`. See [`shuffle`](fn@shuffle) for details.
+    pub fn shuffle<A, S, D>(
+        &mut self,
+        a: &mut ArrayBase<S, D>,
+        axis: isize,
+    ) -> Result<(), NumrustError>
+    where
+        A: Clone,
+        S: DataMut<Elem = A>,
+        D: RemoveAxis,
+    {
+        let axis = Axis(normalize_axis(axis, a.ndim())?);
+        let order = self.permutation(a.len_of(axis));
+        let shuffled = a.select(axis, order.as_slice().expect("permutation is contiguous"));
+        a.assign(&shuffled);
+        Ok(())
+    }
+
+    /// Returns a random permutation of `0..n`. See [`permutation`](fn@permutation) for details.
+    pub fn permutation(&mut self, n: usize) -> Array1<usize> {
+        permutation_from(&mut self.bit_generator, n)
+    }
 
-    for _ in 0..n {
-        let sample = normal.sample(&mut rng);
-        data.push(sample);
+    /// Returns a copy of `a` with its subarrays along `axis` randomly reordered. See
+    /// [`permutation_of`](fn@permutation_of) for details.
+    pub fn permutation_of<A, S, D>(
+        &mut self,
+        a: &ArrayBase<S, D>,
+        axis: isize,
+    ) -> Result<Array<A, D>, NumrustError>
+    where
+        A: Clone,
+        S: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        let mut out = a.to_owned();
+        self.shuffle(&mut out, axis)?;
+        Ok(out)
     }
 
-    Ok(data)
+    /// Returns a copy of `a` with every slice along `axis` shuffled independently. See
+    /// [`permuted`](fn@permuted) for details.
+    pub fn permuted<A, S, D>(
+        &mut self,
+        a: &ArrayBase<S, D>,
+        axis: Option<isize>,
+    ) -> Result<Array<A, D>, NumrustError>
+    where
+        A: Clone,
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let rng = &mut self.bit_generator;
+        match axis {
+            Some(axis) => {
+                let axis = Axis(normalize_axis(axis, a.ndim())?);
+                let mut out = a.to_owned();
+                for mut lane in out.lanes_mut(axis) {
+                    for i in (1..lane.len()).rev() {
+                        lane.swap(i, bounded::interval(rng, i as u64) as usize);
+                    }
+                }
+                Ok(out)
+            }
+            None => {
+                let mut values: Vec<A> = a.iter().cloned().collect();
+                bounded::shuffle_tail(rng, &mut values, 1);
+                Ok(Array::from_shape_vec(a.raw_dim(), values).expect("the shape is unchanged"))
+            }
+        }
+    }
 }
 
-/// Generate a list of `n` random integers between `min` (inclusive) and `max` (exclusive).
-///
-/// # Examples
-///
-/// ```
-/// use numrust::random::randint;
-///
-/// let values = randint(0, 10, 5);
-/// assert_eq!(values.len(), 5);
-/// assert!(values.iter().all(|&x| x >= 0 && x < 10));
-/// ```
-///
-/// # Panics
-///
-/// This function will panic if `min >= max`.
-///
-pub fn randint(min: i32, max: i32, n: usize) -> Vec<i32> {
-    let mut rng = rand::thread_rng();
-    (0..n).map(|_| rng.gen_range(min..max)).collect()
+/// A `Generator` is itself a source of random words, so it can drive `shuffle` and the
+/// distributions of the `rand` crate.
+impl<B: BitGenerator> RngCore for Generator<B> {
+    fn next_u32(&mut self) -> u32 {
+        self.bit_generator.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.bit_generator.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.bit_generator.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.bit_generator.try_fill_bytes(dest)
+    }
 }
 
 #[cfg(test)]
@@ -321,6 +2169,77 @@ mod numrust_random_tests {
         assert!((reds > blues) & (blues > greens));
     }
 
+    #[test]
+    fn test_choice_without_replacement_inclusion_probabilities() {
+        let mut rng = default_rng(44);
+        let a = [0, 1, 2, 3];
+        let p = [0.4, 0.3, 0.2, 0.1];
+        let trials = 40000;
+        let mut first = [0usize; 4];
+        let mut included = [0usize; 4];
+        for _ in 0..trials {
+            let sample = rng.choice(&a, 2, false, Some(&p));
+            assert_ne!(sample[0], sample[1]);
+            first[sample[0]] += 1;
+            for &i in &sample {
+                included[i] += 1;
+            }
+        }
+        for i in 0..4 {
+            // Element i is drawn first, or second after some j was drawn first.
+            let second: f64 = (0..4)
+                .filter(|&j| j != i)
+                .map(|j| p[j] * p[i] / (1.0 - p[j]))
+                .sum();
+            let expected = p[i] + second;
+            let observed = included[i] as f64 / trials as f64;
+            assert!((observed - expected).abs() < 0.015, "element {}", i);
+            let observed = first[i] as f64 / trials as f64;
+            assert!((observed - p[i]).abs() < 0.015, "element {}", i);
+        }
+
+        // Uniform sampling includes every element with probability size / len.
+        let a: Vec<usize> = (0..10).collect();
+        let mut included = [0usize; 10];
+        for _ in 0..trials {
+            let sample = rng.choice(&a, 3, false, None);
+            let mut distinct = sample.clone();
+            distinct.sort();
+            distinct.dedup();
+            assert_eq!(distinct.len(), 3);
+            for i in sample {
+                included[i] += 1;
+            }
+        }
+        for count in included {
+            assert!((count as f64 / trials as f64 - 0.3).abs() < 0.015);
+        }
+    }
+
+    #[test]
+    fn test_choice_without_replacement_edge_cases() {
+        let a = [1, 2, 3];
+        let mut all = choice(&a, 3, false, Some(&[0.2, 0.5, 0.3]));
+        all.sort();
+        assert_eq!(all, vec![1, 2, 3]);
+        for _ in 0..100 {
+            assert_eq!(choice(&a, 1, false, Some(&[0.0, 1.0, 0.0])), vec![2]);
+        }
+        assert!(choice(&a, 0, false, None).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Fewer non-zero entries in p than size")]
+    fn test_choice_too_few_nonzero_probabilities() {
+        choice(&[1, 2, 3], 2, false, Some(&[0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "probabilities are not non-negative")]
+    fn test_choice_negative_probabilities() {
+        choice(&[1, 2], 1, true, Some(&[-0.5, 1.5]));
+    }
+
     #[test]
     #[should_panic(
         expected = "`size` cannot be greater than the length of `a` if `replace` is false"
@@ -337,4 +2256,668 @@ mod numrust_random_tests {
         let p = vec![0.1, 0.2, 0.3, 0.2];
         choice(&a, 10, true, Some(&p));
     }
+
+    /// Checks the sample mean and variance of `n` draws against their expected values, with a
+    /// tolerance of five standard errors of the mean.
+    fn check_moments(samples: &NdArray<f64>, mean: f64, var: f64) {
+        let n = samples.len() as f64;
+        let values = samples.iter().copied().collect::<Vec<f64>>();
+        let actual_mean = crate::mean(&values);
+        let actual_var = crate::variance(&values);
+        assert!(
+            (actual_mean - mean).abs() < 5.0 * (var / n).sqrt(),
+            "mean {} expected {}",
+            actual_mean,
+            mean
+        );
+        assert!(
+            (actual_var - var).abs() < 0.1 * var,
+            "variance {} expected {}",
+            actual_var,
+            var
+        );
+    }
+
+    #[test]
+    fn test_continuous_moments() {
+        let mut rng = default_rng(41);
+        let size = [200, 200];
+        check_moments(&rng.uniform(2.0, 5.0, &size).unwrap(), 3.5, 0.75);
+        check_moments(&rng.exponential(2.0, &size).unwrap(), 2.0, 4.0);
+        check_moments(&rng.gamma(3.0, 2.0, &size).unwrap(), 6.0, 12.0);
+        check_moments(&rng.beta(2.0, 3.0, &size).unwrap(), 0.4, 0.04);
+        check_moments(&rng.chisquare(4.0, &size).unwrap(), 4.0, 8.0);
+        check_moments(
+            &rng.noncentral_chisquare(3.0, 2.0, &size).unwrap(),
+            5.0,
+            14.0,
+        );
+        check_moments(
+            &rng.noncentral_chisquare(0.5, 1.0, &size).unwrap(),
+            1.5,
+            5.0,
+        );
+        check_moments(&rng.standard_t(10.0, &size).unwrap(), 0.0, 1.25);
+        check_moments(
+            &rng.f(10.0, 50.0, &size).unwrap(),
+            50.0 / 48.0,
+            290000.0 / 1059840.0,
+        );
+        check_moments(
+            &rng.lognormal(0.0, 0.5, &size).unwrap(),
+            0.125f64.exp(),
+            (0.25f64.exp() - 1.0) * 0.25f64.exp(),
+        );
+        check_moments(&rng.laplace(1.0, 2.0, &size).unwrap(), 1.0, 8.0);
+        check_moments(
+            &rng.logistic(1.0, 2.0, &size).unwrap(),
+            1.0,
+            PI * PI * 4.0 / 3.0,
+        );
+        check_moments(
+            &rng.gumbel(1.0, 2.0, &size).unwrap(),
+            1.0 + 2.0 * 0.5772156649015329,
+            PI * PI * 4.0 / 6.0,
+        );
+        check_moments(
+            &rng.weibull(2.0, &size).unwrap(),
+            PI.sqrt() / 2.0,
+            1.0 - PI / 4.0,
+        );
+        check_moments(
+            &rng.pareto(20.0, &size).unwrap(),
+            1.0 / 19.0,
+            20.0 / (361.0 * 18.0),
+        );
+        check_moments(
+            &rng.rayleigh(2.0, &size).unwrap(),
+            2.0 * (PI / 2.0).sqrt(),
+            (4.0 - PI) / 2.0 * 4.0,
+        );
+        check_moments(
+            &rng.triangular(0.0, 1.0, 4.0, &size).unwrap(),
+            5.0 / 3.0,
+            13.0 / 18.0,
+        );
+        check_moments(&rng.wald(1.0, 4.0, &size).unwrap(), 1.0, 0.25);
+    }
+
+    #[test]
+    fn test_vonmises_concentration() {
+        let mut rng = default_rng(41);
+        // For kappa = 2 the mean resultant length is I1(2) / I0(2).
+        for kappa in [2.0, 1e-6, 1e7] {
+            let samples = rng.vonmises(1.0, kappa, &[20000]).unwrap();
+            let n = samples.len() as f64;
+            let c = samples.iter().map(|x| x.cos()).sum::<f64>() / n;
+            let s = samples.iter().map(|x| x.sin()).sum::<f64>() / n;
+            let expected = match kappa {
+                2.0 => 1.590_636_854_637_329 / 2.279_585_302_336_067,
+                k if k < 1.0 => 0.0,
+                _ => 1.0,
+            };
+            assert!(
+                ((c * c + s * s).sqrt() - expected).abs() < 0.03,
+                "kappa {}",
+                kappa
+            );
+            if kappa >= 1.0 {
+                assert!((s.atan2(c) - 1.0).abs() < 0.05);
+            }
+        }
+    }
+
+    #[test]
+    fn test_continuous_parameter_validation() {
+        assert!(uniform(0.0, f64::INFINITY, &[1]).is_err());
+        assert!(exponential(-1.0, &[1]).is_err());
+        assert!(gamma(f64::NAN, 1.0, &[1]).is_err());
+        assert_eq!(
+            gamma(0.0, 1.0, &[2]).unwrap(),
+            ndarray::arr1(&[0.0, 0.0]).into_dyn()
+        );
+        assert!(beta(0.0, 1.0, &[1]).is_err());
+        assert!(chisquare(0.0, &[1]).is_err());
+        assert!(noncentral_chisquare(1.0, -1.0, &[1]).is_err());
+        assert!(noncentral_chisquare(3.0, f64::INFINITY, &[1]).is_err());
+        assert!(noncentral_chisquare(0.5, f64::NAN, &[1]).is_err());
+        // The Poisson mixture used for df <= 1 cannot sample means past POISSON_LAM_MAX.
+        assert!(noncentral_chisquare(0.5, f64::MAX, &[1]).is_err());
+        assert!(noncentral_chisquare(0.5, 4.0 * POISSON_LAM_MAX, &[1]).is_err());
+        assert!(noncentral_chisquare(3.0, f64::MAX, &[1]).is_ok());
+        assert!(standard_t(-2.0, &[1]).is_err());
+        assert!(f(1.0, 0.0, &[1]).is_err());
+        assert!(lognormal(0.0, -0.1, &[1]).is_err());
+        assert!(laplace(0.0, -1.0, &[1]).is_err());
+        assert!(logistic(0.0, -1.0, &[1]).is_err());
+        assert!(gumbel(0.0, -1.0, &[1]).is_err());
+        assert!(weibull(-1.0, &[1]).is_err());
+        assert!(pareto(0.0, &[1]).is_err());
+        assert!(rayleigh(-1.0, &[1]).is_err());
+        assert!(cauchy(0.0, -1.0, &[1]).is_err());
+        assert!(triangular(1.0, 0.0, 2.0, &[1]).is_err());
+        assert!(triangular(0.0, 3.0, 2.0, &[1]).is_err());
+        assert!(triangular(1.0, 1.0, 1.0, &[1]).is_err());
+        assert!(vonmises(0.0, -1.0, &[1]).is_err());
+        assert!(wald(0.0, 1.0, &[1]).is_err());
+        assert_eq!(exponential(1.0, &[0, 3]).unwrap().shape(), &[0, 3]);
+        assert_eq!(cauchy(0.0, 1.0, &[]).unwrap().ndim(), 0);
+    }
+
+    fn as_f64(samples: NdArray<i64>) -> NdArray<f64> {
+        samples.mapv(|k| k as f64)
+    }
+
+    #[test]
+    fn test_discrete_moments() {
+        let mut rng = default_rng(42);
+        let size = [200, 200];
+        check_moments(&as_f64(rng.poisson(4.0, &size).unwrap()), 4.0, 4.0);
+        check_moments(&as_f64(rng.geometric(0.25, &size).unwrap()), 4.0, 12.0);
+        check_moments(
+            &as_f64(rng.negative_binomial(2.5, 0.4, &size).unwrap()),
+            3.75,
+            9.375,
+        );
+        check_moments(
+            &as_f64(rng.hypergeometric(30, 20, 10, &size).unwrap()),
+            6.0,
+            10.0 * 0.6 * 0.4 * 40.0 / 49.0,
+        );
+        // The log-series distribution with p = 0.5 has mean p / ((1 - p) * -ln(1 - p)).
+        let m = 1.0 / 2f64.ln();
+        check_moments(
+            &as_f64(rng.logseries(0.5, &size).unwrap()),
+            m,
+            m * (2.0 - m),
+        );
+        let hits = rng.bernoulli(0.3, &size).unwrap().mapv(|b| b as i64);
+        check_moments(&as_f64(hits), 0.3, 0.21);
+    }
+
+    #[test]
+    fn test_zipf_frequencies() {
+        let mut rng = default_rng(42);
+        // P(k) = k^-a / zeta(a), and zeta(3) = 1.2020569...
+        let samples = rng.zipf(3.0, &[40000]).unwrap();
+        let n = samples.len() as f64;
+        for k in 1..=3 {
+            let observed = samples.iter().filter(|&&x| x == k).count() as f64 / n;
+            let expected = (k as f64).powi(-3) / 1.202_056_903_159_594;
+            assert!((observed - expected).abs() < 0.015, "k = {}", k);
+        }
+        assert_eq!(
+            rng.logseries(0.0, &[5]).unwrap(),
+            ndarray::arr1(&[1; 5]).into_dyn()
+        );
+    }
+
+    #[test]
+    fn test_discrete_parameter_validation() {
+        assert!(poisson(-1.0, &[1]).is_err());
+        assert!(poisson(1e19, &[1]).is_err());
+        assert_eq!(
+            poisson(0.0, &[2]).unwrap(),
+            ndarray::arr1(&[0, 0]).into_dyn()
+        );
+        assert!(geometric(0.0, &[1]).is_err());
+        assert!(geometric(1.5, &[1]).is_err());
+        assert_eq!(
+            geometric(1.0, &[2]).unwrap(),
+            ndarray::arr1(&[1, 1]).into_dyn()
+        );
+        assert!(negative_binomial(0.0, 0.5, &[1]).is_err());
+        assert!(negative_binomial(1.0, f64::NAN, &[1]).is_err());
+        assert!(negative_binomial(1e300, 1e-300, &[1]).is_err());
+        assert!(negative_binomial(f64::INFINITY, 0.5, &[1]).is_err());
+        assert!(negative_binomial(1e6, 1e-13, &[1]).is_err());
+        assert!(negative_binomial(1e6, 1e-6, &[1]).is_ok());
+        assert!(hypergeometric(1, 2, 4, &[1]).is_err());
+        assert!(hypergeometric(u64::MAX, 1, 1, &[1]).is_err());
+        assert!(logseries(1.0, &[1]).is_err());
+        assert!(zipf(1.0, &[1]).is_err());
+        assert!(bernoulli(-0.1, &[1]).is_err());
+        assert!(matches!(
+            binomial(5, 1.5, 1),
+            Err(NumrustError::ValueError(_))
+        ));
+        assert!(matches!(
+            normal(0.0, -1.0, 1),
+            Err(NumrustError::ValueError(_))
+        ));
+    }
+    #[test]
+    fn test_multivariate_normal_covariance() {
+        let cov = ndarray::arr2(&[[2.0, 0.6, 0.0], [0.6, 1.0, -0.3], [0.0, -0.3, 0.5]]);
+        let mean = [1.0, -2.0, 0.5];
+        for method in [CovMethod::Svd, CovMethod::Eigh, CovMethod::Cholesky] {
+            let samples =
+                multivariate_normal(&mean, &cov, &[200, 200], method, CheckValid::Raise, 1e-8)
+                    .unwrap();
+            assert_eq!(samples.shape(), &[200, 200, 3]);
+            let samples = samples.into_shape((40000, 3)).unwrap();
+            let columns: Vec<Vec<f64>> =
+                samples.columns().into_iter().map(|c| c.to_vec()).collect();
+            for i in 0..3 {
+                assert!((crate::mean(&columns[i]) - mean[i]).abs() < 0.05);
+                for j in 0..3 {
+                    let c = crate::covariance(&columns[i], &columns[j])[0][1];
+                    assert!(
+                        (c - cov[[i, j]]).abs() < 0.05,
+                        "{:?} cov[{}][{}]",
+                        method,
+                        i,
+                        j
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_multivariate_normal_validation() {
+        // Positive semi-definite but singular: fine for svd and eigh, not for cholesky.
+        let singular = ndarray::arr2(&[[1.0, 1.0], [1.0, 1.0]]);
+        for method in [CovMethod::Svd, CovMethod::Eigh] {
+            let samples = multivariate_normal(
+                &[0.0, 0.0],
+                &singular,
+                &[10],
+                method,
+                CheckValid::Raise,
+                1e-8,
+            )
+            .unwrap();
+            for row in samples.rows() {
+                assert!((row[0] - row[1]).abs() < 1e-6);
+            }
+        }
+        assert!(matches!(
+            multivariate_normal(
+                &[0.0, 0.0],
+                &singular,
+                &[1],
+                CovMethod::Cholesky,
+                CheckValid::Raise,
+                1e-8
+            ),
+            Err(NumrustError::LinAlgError(_))
+        ));
+
+        let indefinite = ndarray::arr2(&[[1.0, 2.0], [2.0, 1.0]]);
+        for method in [CovMethod::Svd, CovMethod::Eigh] {
+            assert!(matches!(
+                multivariate_normal(
+                    &[0.0, 0.0],
+                    &indefinite,
+                    &[1],
+                    method,
+                    CheckValid::Raise,
+                    1e-8
+                ),
+                Err(NumrustError::ValueError(_))
+            ));
+            assert!(multivariate_normal(
+                &[0.0, 0.0],
+                &indefinite,
+                &[1],
+                method,
+                CheckValid::Ignore,
+                1e-8
+            )
+            .is_ok());
+        }
+        assert!(multivariate_normal(
+            &[0.0],
+            &singular,
+            &[1],
+            CovMethod::Svd,
+            CheckValid::Raise,
+            1e-8
+        )
+        .is_err());
+        assert!(multivariate_normal(
+            &[0.0, 0.0],
+            &ndarray::Array2::<f64>::zeros((2, 3)),
+            &[1],
+            CovMethod::Svd,
+            CheckValid::Raise,
+            1e-8
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_multinomial_and_dirichlet() {
+        let pvals = [0.2, 0.3, 0.5];
+        let counts = multinomial(10, &pvals, &[200, 200]).unwrap();
+        assert_eq!(counts.shape(), &[200, 200, 3]);
+        let counts = counts.into_shape((40000, 3)).unwrap();
+        assert!(counts.rows().into_iter().all(|row| row.sum() == 10));
+        for (j, &p) in pvals.iter().enumerate() {
+            check_moments(
+                &as_f64(counts.column(j).to_owned().into_dyn()),
+                10.0 * p,
+                10.0 * p * (1.0 - p),
+            );
+        }
+        // The last probability is implied by the others.
+        let counts = multinomial(5, &[1.0, 0.5], &[2]).unwrap();
+        assert_eq!(counts, ndarray::arr2(&[[5, 0], [5, 0]]).into_dyn());
+        assert!(multinomial(5, &[0.7, 0.7, 0.0], &[1]).is_err());
+        assert!(multinomial(5, &[f64::NAN, 0.5], &[1]).is_err());
+        assert!(matches!(
+            multinomial(5, &[], &[2]),
+            Err(NumrustError::ValueError(_))
+        ));
+
+        let alpha = [2.0, 3.0, 5.0];
+        let weights = dirichlet(&alpha, &[200, 200])
+            .unwrap()
+            .into_shape((40000, 3))
+            .unwrap();
+        for (j, &a) in alpha.iter().enumerate() {
+            let m = a / 10.0;
+            check_moments(
+                &weights.column(j).to_owned().into_dyn(),
+                m,
+                m * (1.0 - m) / 11.0,
+            );
+        }
+        // Small concentrations use stick-breaking, which must still sum to one.
+        let sparse = dirichlet(&[0.05, 0.05, 0.05], &[1000]).unwrap();
+        for row in sparse.rows() {
+            assert!((row.sum() - 1.0).abs() < 1e-12);
+            assert!(row.iter().all(|&x| (0.0..=1.0).contains(&x)));
+        }
+        assert!(dirichlet(&[1.0, 0.0], &[1]).is_err());
+        assert!(matches!(
+            dirichlet(&[], &[2]),
+            Err(NumrustError::ValueError(_))
+        ));
+    }
+    #[test]
+    fn test_shuffle_and_permutations() {
+        let mut rng = default_rng(2024);
+        let mut a = ndarray::Array::from_shape_vec((4, 3, 2), (0..24).collect()).unwrap();
+        let original = a.clone();
+        rng.shuffle(&mut a, -2).unwrap();
+        // Shuffling along an axis moves whole subarrays, each exactly once.
+        let mut sources: Vec<usize> = a
+            .axis_iter(Axis(1))
+            .map(|moved| {
+                original
+                    .axis_iter(Axis(1))
+                    .position(|slice| slice == moved)
+                    .unwrap()
+            })
+            .collect();
+        sources.sort();
+        assert_eq!(sources, vec![0, 1, 2]);
+        assert!(rng.shuffle(&mut a, 3).is_err());
+
+        // The same seed gives the same results.
+        let p1 = default_rng(5).permutation(50);
+        let p2 = default_rng(5).permutation(50);
+        assert_eq!(p1, p2);
+        let mut sorted = p1.to_vec();
+        sorted.sort();
+        assert_eq!(sorted, (0..50).collect::<Vec<_>>());
+        assert_eq!(rng.permutation(0).len(), 0);
+
+        let p = rng.permutation_of(&original, 0).unwrap();
+        let mut rows: Vec<Vec<i32>> = p
+            .outer_iter()
+            .map(|m| m.iter().copied().collect())
+            .collect();
+        rows.sort();
+        let expected: Vec<Vec<i32>> = original
+            .outer_iter()
+            .map(|m| m.iter().copied().collect())
+            .collect();
+        assert_eq!(rows, expected);
+
+        // The free functions draw from the thread-local generator.
+        let mut b = original.clone();
+        shuffle(&mut b, 0).unwrap();
+        assert_eq!(b.sum(), original.sum());
+        assert_eq!(permutation(3).len(), 3);
+        assert_eq!(
+            permutation_of(&original, 2).unwrap().shape(),
+            original.shape()
+        );
+        assert_eq!(permuted(&original, None).unwrap().shape(), original.shape());
+    }
+
+    #[test]
+    fn test_permuted_slices_are_independent() {
+        let mut rng = default_rng(11);
+        let a = ndarray::Array::from_shape_fn((50, 10), |(_, j)| j);
+        let p = rng.permuted(&a, Some(1)).unwrap();
+        for row in p.rows() {
+            let mut values = row.to_vec();
+            values.sort();
+            assert_eq!(values, (0..10).collect::<Vec<_>>());
+        }
+        // Independent shuffles give rows in different orders.
+        assert!(p.rows().into_iter().any(|row| row != p.row(0)));
+
+        let flat = rng.permuted(&a, None).unwrap();
+        assert_eq!(flat.shape(), a.shape());
+        let mut values = flat.iter().copied().collect::<Vec<_>>();
+        values.sort();
+        let mut expected = a.iter().copied().collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(values, expected);
+        assert!(rng.permuted(&a, Some(-3)).is_err());
+
+        // Every position of a shuffled range is equally likely to hold each value.
+        let mut counts = [[0usize; 4]; 4];
+        for _ in 0..20000 {
+            let p = rng.permutation(4);
+            for (position, &value) in p.iter().enumerate() {
+                counts[position][value] += 1;
+            }
+        }
+        for row in counts {
+            for count in row {
+                assert!((count as f64 / 20000.0 - 0.25).abs() < 0.02);
+            }
+        }
+    }
+    fn draw_everything<B: BitGenerator>(rng: &mut Generator<B>) -> Vec<f64> {
+        let mut out = rng.random(&[3]).into_raw_vec();
+        out.extend(rng.normal(1.0, 2.0, 3).unwrap());
+        out.extend(rng.binomial(10, 0.3, 3).unwrap().iter().map(|&k| k as f64));
+        out.extend(rng.randint(0, 100, 3).iter().map(|&k| k as f64));
+        out.extend(rng.choice(&[1.0, 2.0, 3.0, 4.0], 2, false, Some(&[0.1, 0.2, 0.3, 0.4])));
+        out.extend(rng.gamma(2.0, 1.0, &[3]).unwrap());
+        out.extend(rng.poisson(4.0, &[3]).unwrap().iter().map(|&k| k as f64));
+        out.extend(rng.permutation(5).iter().map(|&k| k as f64));
+        out.extend(rng.noncentral_chisquare(0.5, 0.0, &[3]).unwrap());
+        out.extend(rng.noncentral_chisquare(0.5, 2.0, &[3]).unwrap());
+        out
+    }
+
+    #[test]
+    fn test_generator_is_reproducible_with_every_bit_generator() {
+        fn check<B: BitGenerator + Clone>(bit_generator: B) {
+            let a = draw_everything(&mut Generator::new(bit_generator.clone()));
+            let b = draw_everything(&mut Generator::new(bit_generator));
+            assert_eq!(a, b);
+        }
+        check(Pcg64::new(1, 2));
+        check(Pcg64Dxsm::new(1, 2));
+        check(Philox::new([0; 4], [1, 2]));
+        check(Sfc64::new([1, 2, 3]));
+        check(Xoshiro256StarStar::new([1, 2, 3, 4]));
+        check(rand::rngs::StdRng::seed_from_u64(3));
+
+        // Different seeds give different streams.
+        let a = draw_everything(&mut Generator::new(Pcg64::new(1, 2)));
+        let b = draw_everything(&mut Generator::new(Pcg64::new(1, 3)));
+        assert_ne!(a, b);
+
+        // Without noncentrality the seeded generator still draws from its own stream.
+        let a = default_rng(46)
+            .noncentral_chisquare(3.0, 0.0, &[5])
+            .unwrap();
+        let b = default_rng(46)
+            .noncentral_chisquare(3.0, 0.0, &[5])
+            .unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, default_rng(46).chisquare(3.0, &[5]).unwrap());
+
+        // random() uses the top 53 bits of each word.
+        let word = Pcg64::new(5, 6).next_u64();
+        let mut rng = Generator::new(Pcg64::new(5, 6));
+        assert_eq!(rng.random(&[1])[0], (word >> 11) as f64 / 2f64.powi(53));
+    }
+
+    #[test]
+    fn test_generator_moments() {
+        let mut rng = Generator::new(Philox::new([0; 4], [11, 12]));
+        check_moments(&rng.random(&[200, 200]), 0.5, 1.0 / 12.0);
+        check_moments(&rng.exponential(2.0, &[200, 200]).unwrap(), 2.0, 4.0);
+        let mut rng = Generator::new(Sfc64::new([7, 8, 9]));
+        let samples = ndarray::Array::from(rng.normal(3.0, 2.0, 40000).unwrap()).into_dyn();
+        check_moments(&samples, 3.0, 4.0);
+    }
+    #[test]
+    fn test_numpy_golden_values() {
+        // Recorded from numpy.random.default_rng(seed).
+        assert_eq!(
+            default_rng(42).random(&[3]).into_raw_vec(),
+            vec![0.7739560485559633, 0.4388784397520523, 0.8585979199113825]
+        );
+        assert_eq!(
+            default_rng(0).random(&[3]).into_raw_vec(),
+            vec![0.6369616873214543, 0.2697867137638703, 0.04097352393619469]
+        );
+        assert_eq!(default_rng(12345).random(&[1])[0], 0.22733602246716966);
+        assert_eq!(
+            default_rng(42)
+                .integers(0, 10, &[5], false)
+                .unwrap()
+                .into_raw_vec(),
+            vec![0, 7, 6, 4, 4]
+        );
+        let digits: Vec<i32> = (0..10).collect();
+        assert_eq!(
+            default_rng(42).choice(&digits, 5, true, None),
+            vec![0, 7, 6, 4, 4]
+        );
+
+        let normals = default_rng(42).standard_normal(&[5]);
+        let expected = [
+            0.30471707975443135,
+            -1.0399841062404955,
+            0.7504511958064572,
+            0.9405647163912139,
+            -1.9510351886538364,
+        ];
+        for (i, (&x, &e)) in normals.iter().zip(&expected).enumerate() {
+            // NumPy's table entry for strip 36, which the third draw uses, was rounded
+            // differently from ours and leaves that draw two ulps away.
+            if i == 2 {
+                assert!((x - e).abs() < 1e-15);
+            } else {
+                assert_eq!(x, e);
+            }
+        }
+        let shifted = default_rng(42).normal(1.0, 2.0, 2).unwrap();
+        assert_eq!(shifted[0], 1.0 + 2.0 * expected[0]);
+    }
+
+    #[test]
+    fn test_integers_bounds_and_errors() {
+        let mut rng = default_rng(7);
+        let dice = rng.integers(1, 6, &[1000], true).unwrap();
+        assert!(dice.iter().all(|d| (1..=6).contains(d)));
+        assert!(dice.iter().any(|&d| d == 6));
+        assert_eq!(
+            rng.integers(5, 5, &[3], true).unwrap().into_raw_vec(),
+            vec![5; 3]
+        );
+        assert!(rng.integers(5, 5, &[3], false).is_err());
+        assert!(rng.integers(6, 5, &[3], true).is_err());
+        let wide = rng.integers(i64::MIN, i64::MAX, &[1000], true).unwrap();
+        assert!(wide.iter().any(|&x| x < 0) && wide.iter().any(|&x| x > 0));
+    }
+
+    #[test]
+    fn test_integers_of_every_type() {
+        let mut rng = default_rng(11);
+        let bytes = rng.integers(-3i8, 3, &[2, 500], false).unwrap();
+        assert_eq!(bytes.shape(), &[2, 500]);
+        assert!(bytes.iter().all(|b| (-3..3).contains(b)));
+        assert!(bytes.iter().any(|&b| b == -3) && bytes.iter().any(|&b| b == 2));
+
+        // Full ranges reach both halves of the type.
+        let all_bytes = rng.integers(u8::MIN, u8::MAX, &[1000], true).unwrap();
+        assert!(all_bytes.iter().any(|&b| b < 128) && all_bytes.iter().any(|&b| b >= 128));
+        let shorts = rng.integers(i16::MIN, i16::MAX, &[1000], true).unwrap();
+        assert!(shorts.iter().any(|&x| x < 0) && shorts.iter().any(|&x| x > 0));
+        let words = rng.integers(u64::MIN, u64::MAX, &[1000], true).unwrap();
+        assert!(words.iter().any(|&x| x > u64::MAX / 2) && words.iter().any(|&x| x < u64::MAX / 2));
+        let huge = rng.integers(i128::MIN, i128::MAX, &[1000], true).unwrap();
+        assert!(huge.iter().any(|&x| x < i128::MIN / 2) && huge.iter().any(|&x| x > i128::MAX / 2));
+        let sizes = rng.integers(10usize, 20, &[], false).unwrap();
+        assert!((10..20).contains(&sizes[[]]));
+
+        // Uniform over a range that does not divide the word size.
+        let values = rng.integers(0u16, 3, &[60000], false).unwrap();
+        for k in 0..3 {
+            let share = values.iter().filter(|&&v| v == k).count() as f64 / 60000.0;
+            assert!((share - 1.0 / 3.0).abs() < 0.01);
+        }
+        let values = rng.integers(0u128, 3 << 100, &[60000], false).unwrap();
+        let low_third = values.iter().filter(|&&v| v < 1 << 100).count() as f64 / 60000.0;
+        assert!((low_third - 1.0 / 3.0).abs() < 0.01);
+
+        // 32-bit draws follow the same stream as 64-bit ones.
+        assert_eq!(
+            default_rng(42)
+                .integers(0i32, 10, &[5], false)
+                .unwrap()
+                .into_raw_vec(),
+            vec![0, 7, 6, 4, 4]
+        );
+        assert!(rng.integers(u8::MAX, u8::MAX, &[3], false).is_err());
+        assert!(rng.integers(1u32, 0, &[3], true).is_err());
+        assert!(integers(-5i64, -5, &[1], true)
+            .unwrap()
+            .iter()
+            .all(|&x| x == -5));
+    }
+
+    #[test]
+    fn test_numpy_choice_without_replacement() {
+        // Both of NumPy's uniform algorithms give distinct indices.
+        for (pop_size, size) in [(100, 30), (20000, 1000), (20000, 20000)] {
+            let population: Vec<usize> = (0..pop_size).collect();
+            let mut sample = default_rng(3).choice(&population, size, false, None);
+            sample.sort();
+            sample.dedup();
+            assert_eq!(sample.len(), size);
+        }
+        // Weighted draws follow NumPy's rounds of searchsorted over the remaining weights. The
+        // first uniforms of default_rng(42) are 0.774, 0.439 and 0.859, so with p = [0.2, 0.8]
+        // both draws of the first round hit index 1 and the second round can only pick index 0.
+        assert_eq!(
+            default_rng(42).choice(&[0, 1, 2, 3], 2, false, Some(&[0.1, 0.2, 0.3, 0.4])),
+            vec![3, 2]
+        );
+        assert_eq!(
+            default_rng(42).choice(&[0, 1], 2, false, Some(&[0.2, 0.8])),
+            vec![1, 0]
+        );
+        // The same seed reproduces the same draw.
+        let population: Vec<usize> = (0..50).collect();
+        let p: Vec<f64> = (1..=50).map(|i| i as f64 / 1275.0).collect();
+        assert_eq!(
+            default_rng(9).choice(&population, 10, false, Some(&p)),
+            default_rng(9).choice(&population, 10, false, Some(&p))
+        );
+    }
 }
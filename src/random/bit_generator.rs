@@ -0,0 +1,779 @@
+use rand::rngs::{StdRng, ThreadRng};
+use rand::{Error, RngCore, SeedableRng};
+
+/// A source of random 64-bit words that `Generator` turns into samples.
+///
+/// `Pcg64`, `Pcg64Dxsm`, `Philox` and `Sfc64` reproduce the output of the NumPy bit generators of
+/// the same names given the same state, on every platform.
+pub trait BitGenerator: RngCore {
+    /// Returns a double in `[0, 1)` built from the top 53 bits of the next word, as NumPy does.
+    fn next_double(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / 9_007_199_254_740_992.0)
+    }
+}
+
+impl BitGenerator for ThreadRng {}
+
+impl BitGenerator for StdRng {}
+
+impl<B: BitGenerator + ?Sized> BitGenerator for &mut B {}
+
+/// A bit generator that can skip far ahead in its stream without drawing the skipped words, so
+/// that one seed can provide many non-overlapping streams.
+pub trait Jumpable: BitGenerator + Clone {
+    /// Jumps ahead by the generator's jump size: about `0.618 * 2^128` words for the PCG
+    /// generators, `2^128` blocks of four words for `Philox` and `2^128` words for
+    /// `Xoshiro256StarStar`.
+    fn jump(&mut self);
+
+    /// Returns a copy of the generator jumped ahead `jumps` times, leaving `self` unchanged, like
+    /// NumPy's `jumped`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::{Jumpable, Pcg64};
+    /// use rand::RngCore;
+    ///
+    /// let rng = Pcg64::new(1, 2);
+    /// let mut once_twice = rng.jumped(1).jumped(1);
+    /// assert_eq!(once_twice.next_u64(), rng.jumped(2).next_u64());
+    /// ```
+    fn jumped(&self, jumps: u32) -> Self {
+        let mut jumped = self.clone();
+        for _ in 0..jumps {
+            jumped.jump();
+        }
+        jumped
+    }
+}
+
+/// Advances a linear congruential state by `delta` steps in O(log delta) time, using Brown's
+/// algorithm.
+fn lcg_advance(state: u128, mut delta: u128, multiplier: u128, increment: u128) -> u128 {
+    let (mut acc_mult, mut acc_plus) = (1u128, 0u128);
+    let (mut cur_mult, mut cur_plus) = (multiplier, increment);
+    while delta > 0 {
+        if delta & 1 == 1 {
+            acc_mult = acc_mult.wrapping_mul(cur_mult);
+            acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+        }
+        cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+        cur_mult = cur_mult.wrapping_mul(cur_mult);
+        delta >>= 1;
+    }
+    acc_mult.wrapping_mul(state).wrapping_add(acc_plus)
+}
+
+/// The step NumPy's PCG generators jump by: the golden ratio times 2^128, made odd.
+const PCG64_JUMP: u128 = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835;
+
+/// Splits 64-bit words into two 32-bit draws, low half first, as NumPy's bit generators do.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct HalfWord(Option<u32>);
+
+impl HalfWord {
+    fn next_u32(&mut self, next_u64: impl FnOnce() -> u64) -> u32 {
+        match self.0.take() {
+            Some(high) => high,
+            None => {
+                let word = next_u64();
+                self.0 = Some((word >> 32) as u32);
+                word as u32
+            }
+        }
+    }
+}
+
+/// Fills `dest` with little-endian 64-bit words.
+fn fill_bytes_via_u64<R: RngCore + ?Sized>(rng: &mut R, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+        let bytes = rng.next_u64().to_le_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
+
+/// Reads the little-endian words of a seed.
+fn seed_words<const N: usize>(seed: &[u8]) -> [u64; N] {
+    let mut words = [0; N];
+    for (word, bytes) in words.iter_mut().zip(seed.chunks_exact(8)) {
+        *word = u64::from_le_bytes(bytes.try_into().expect("chunks have eight bytes"));
+    }
+    words
+}
+
+macro_rules! impl_rng_core {
+    ($name:ident) => {
+        impl RngCore for $name {
+            fn next_u32(&mut self) -> u32 {
+                let mut half = std::mem::take(&mut self.half);
+                let value = half.next_u32(|| self.next_word());
+                self.half = half;
+                value
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.next_word()
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                fill_bytes_via_u64(self, dest)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        impl BitGenerator for $name {}
+    };
+}
+
+const PCG64_MULTIPLIER: u128 = 0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645;
+const PCG64_CHEAP_MULTIPLIER: u64 = 0xda94_2042_e4dd_58b5;
+
+/// The 128-bit permuted congruential generator with the XSL-RR output function, NumPy's default
+/// bit generator.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::Pcg64;
+/// use rand::RngCore;
+///
+/// let mut rng = Pcg64::new(42, 54);
+/// assert_eq!(rng.next_u64(), 0x86b1da1d72062b68);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcg64 {
+    state: u128,
+    increment: u128,
+    half: HalfWord,
+}
+
+impl Pcg64 {
+    /// Creates a generator from an initial state and a stream selector, as
+    /// `pcg64_srandom_r(initstate, initseq)` does in NumPy and the PCG reference implementation.
+    pub fn new(initstate: u128, initseq: u128) -> Self {
+        let increment = (initseq << 1) | 1;
+        let mut pcg = Pcg64 {
+            state: initstate.wrapping_add(increment),
+            increment,
+            half: HalfWord::default(),
+        };
+        pcg.step();
+        pcg
+    }
+
+    /// Advances the stream as if `delta` words had been drawn, modulo 2^128, and discards any
+    /// buffered 32-bit half word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::Pcg64;
+    /// use rand::RngCore;
+    ///
+    /// let mut a = Pcg64::new(3, 4);
+    /// let mut b = a.clone();
+    /// for _ in 0..10 {
+    ///     a.next_u64();
+    /// }
+    /// b.advance(10);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn advance(&mut self, delta: u128) {
+        self.state = lcg_advance(self.state, delta, PCG64_MULTIPLIER, self.increment);
+        self.half = HalfWord::default();
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(PCG64_MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+
+    fn next_word(&mut self) -> u64 {
+        self.step();
+        let rotation = (self.state >> 122) as u32;
+        (((self.state >> 64) as u64) ^ (self.state as u64)).rotate_right(rotation)
+    }
+}
+
+impl SeedableRng for Pcg64 {
+    type Seed = [u8; 32];
+
+    /// Uses the first 16 bytes as the initial state and the last 16 as the stream selector.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [s0, s1, s2, s3] = seed_words::<4>(&seed);
+        Pcg64::new(
+            (s0 as u128) | ((s1 as u128) << 64),
+            (s2 as u128) | ((s3 as u128) << 64),
+        )
+    }
+}
+
+impl_rng_core!(Pcg64);
+
+impl Jumpable for Pcg64 {
+    fn jump(&mut self) {
+        self.advance(PCG64_JUMP);
+    }
+}
+
+/// The 128-bit permuted congruential generator with a 64-bit multiplier and the DXSM output
+/// function, which NumPy recommends over `Pcg64` for massively parallel use.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::Pcg64Dxsm;
+/// use rand::RngCore;
+///
+/// let mut rng = Pcg64Dxsm::new(42, 54);
+/// assert_eq!(rng.next_u64(), 17331114245835578256);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcg64Dxsm {
+    state: u128,
+    increment: u128,
+    half: HalfWord,
+}
+
+impl Pcg64Dxsm {
+    /// Creates a generator from an initial state and a stream selector, as
+    /// `pcg_cm_srandom_r(initstate, initseq)` does in NumPy.
+    pub fn new(initstate: u128, initseq: u128) -> Self {
+        let increment = (initseq << 1) | 1;
+        let mut pcg = Pcg64Dxsm {
+            state: initstate.wrapping_add(increment),
+            increment,
+            half: HalfWord::default(),
+        };
+        pcg.step();
+        pcg
+    }
+
+    /// Advances the stream as if `delta` words had been drawn, modulo 2^128, and discards any
+    /// buffered 32-bit half word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::Pcg64Dxsm;
+    /// use rand::RngCore;
+    ///
+    /// let mut a = Pcg64Dxsm::new(3, 4);
+    /// let mut b = a.clone();
+    /// for _ in 0..10 {
+    ///     a.next_u64();
+    /// }
+    /// b.advance(10);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn advance(&mut self, delta: u128) {
+        self.state = lcg_advance(
+            self.state,
+            delta,
+            PCG64_CHEAP_MULTIPLIER as u128,
+            self.increment,
+        );
+        self.half = HalfWord::default();
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(PCG64_CHEAP_MULTIPLIER as u128)
+            .wrapping_add(self.increment);
+    }
+
+    fn next_word(&mut self) -> u64 {
+        // The output is computed from the state before it advances.
+        let mut hi = (self.state >> 64) as u64;
+        let lo = self.state as u64 | 1;
+        hi ^= hi >> 32;
+        hi = hi.wrapping_mul(PCG64_CHEAP_MULTIPLIER);
+        hi ^= hi >> 48;
+        hi = hi.wrapping_mul(lo);
+        self.step();
+        hi
+    }
+}
+
+impl SeedableRng for Pcg64Dxsm {
+    type Seed = [u8; 32];
+
+    /// Uses the first 16 bytes as the initial state and the last 16 as the stream selector.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [s0, s1, s2, s3] = seed_words::<4>(&seed);
+        Pcg64Dxsm::new(
+            (s0 as u128) | ((s1 as u128) << 64),
+            (s2 as u128) | ((s3 as u128) << 64),
+        )
+    }
+}
+
+impl_rng_core!(Pcg64Dxsm);
+
+impl Jumpable for Pcg64Dxsm {
+    fn jump(&mut self) {
+        self.advance(PCG64_JUMP);
+    }
+}
+
+const PHILOX_M0: u64 = 0xd2e7_470e_e14c_6c93;
+const PHILOX_M1: u64 = 0xca5a_8263_9512_1157;
+const PHILOX_W0: u64 = 0x9e37_79b9_7f4a_7c15;
+const PHILOX_W1: u64 = 0xbb67_ae85_84ca_a73b;
+
+/// Computes the Philox4x64-10 block function of `counter` under `key`.
+fn philox4x64_10(mut counter: [u64; 4], mut key: [u64; 2]) -> [u64; 4] {
+    fn mulhilo(a: u64, b: u64) -> (u64, u64) {
+        let product = a as u128 * b as u128;
+        ((product >> 64) as u64, product as u64)
+    }
+    for round in 0..10 {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(PHILOX_W0);
+            key[1] = key[1].wrapping_add(PHILOX_W1);
+        }
+        let (hi0, lo0) = mulhilo(PHILOX_M0, counter[0]);
+        let (hi1, lo1) = mulhilo(PHILOX_M1, counter[2]);
+        counter = [
+            hi1 ^ counter[1] ^ key[0],
+            lo1,
+            hi0 ^ counter[3] ^ key[1],
+            lo0,
+        ];
+    }
+    counter
+}
+
+/// The counter-based Philox4x64-10 generator. Each block of four words is the encryption of a
+/// 256-bit counter under a 128-bit key, so distinct keys give independent streams.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::Philox;
+/// use rand::RngCore;
+///
+/// let mut a = Philox::new([0; 4], [1, 2]);
+/// let mut b = Philox::new([0; 4], [1, 3]);
+/// assert_ne!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Philox {
+    counter: [u64; 4],
+    key: [u64; 2],
+    buffer: [u64; 4],
+    buffer_pos: usize,
+    half: HalfWord,
+}
+
+impl Philox {
+    /// Creates a generator from a counter and a key. The first block is computed from
+    /// `counter + 1`, as in NumPy.
+    pub fn new(counter: [u64; 4], key: [u64; 2]) -> Self {
+        Philox {
+            counter,
+            key,
+            buffer: [0; 4],
+            buffer_pos: 4,
+            half: HalfWord::default(),
+        }
+    }
+
+    /// Advances the counter by `delta` blocks, skipping `4 * delta` words, and discards any
+    /// buffered words, as NumPy's `Philox.advance` does.
+    pub fn advance(&mut self, delta: u128) {
+        let low = (self.counter[0] as u128) | ((self.counter[1] as u128) << 64);
+        let (sum, carry) = low.overflowing_add(delta);
+        self.counter[0] = sum as u64;
+        self.counter[1] = (sum >> 64) as u64;
+        if carry {
+            self.add_to_high_counter(1);
+        }
+        self.buffer_pos = 4;
+        self.half = HalfWord::default();
+    }
+
+    fn add_to_high_counter(&mut self, delta: u128) {
+        let high = (self.counter[2] as u128) | ((self.counter[3] as u128) << 64);
+        let sum = high.wrapping_add(delta);
+        self.counter[2] = sum as u64;
+        self.counter[3] = (sum >> 64) as u64;
+    }
+
+    fn next_word(&mut self) -> u64 {
+        if self.buffer_pos == 4 {
+            for word in self.counter.iter_mut() {
+                *word = word.wrapping_add(1);
+                if *word != 0 {
+                    break;
+                }
+            }
+            self.buffer = philox4x64_10(self.counter, self.key);
+            self.buffer_pos = 0;
+        }
+        self.buffer_pos += 1;
+        self.buffer[self.buffer_pos - 1]
+    }
+}
+
+impl SeedableRng for Philox {
+    type Seed = [u8; 16];
+
+    /// Uses the seed as the key, starting from a zero counter.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Philox::new([0; 4], seed_words::<2>(&seed))
+    }
+}
+
+impl_rng_core!(Philox);
+
+impl Jumpable for Philox {
+    fn jump(&mut self) {
+        self.add_to_high_counter(1);
+        self.buffer_pos = 4;
+        self.half = HalfWord::default();
+    }
+}
+
+/// Chris Doty-Humphrey's Small Fast Chaotic generator with a 64-bit counter.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::Sfc64;
+/// use rand::RngCore;
+///
+/// let mut a = Sfc64::new([1, 2, 3]);
+/// let mut b = Sfc64::new([1, 2, 3]);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sfc64 {
+    a: u64,
+    b: u64,
+    c: u64,
+    counter: u64,
+    half: HalfWord,
+}
+
+impl Sfc64 {
+    /// Creates a generator from three seed words, discarding the first 12 outputs as NumPy
+    /// does.
+    pub fn new(seed: [u64; 3]) -> Self {
+        let mut sfc = Sfc64 {
+            a: seed[0],
+            b: seed[1],
+            c: seed[2],
+            counter: 1,
+            half: HalfWord::default(),
+        };
+        for _ in 0..12 {
+            sfc.next_word();
+        }
+        sfc
+    }
+
+    fn next_word(&mut self) -> u64 {
+        let tmp = self.a.wrapping_add(self.b).wrapping_add(self.counter);
+        self.counter = self.counter.wrapping_add(1);
+        self.a = self.b ^ (self.b >> 11);
+        self.b = self.c.wrapping_add(self.c << 3);
+        self.c = self.c.rotate_left(24).wrapping_add(tmp);
+        tmp
+    }
+}
+
+impl SeedableRng for Sfc64 {
+    type Seed = [u8; 24];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Sfc64::new(seed_words::<3>(&seed))
+    }
+}
+
+impl_rng_core!(Sfc64);
+
+/// Blackman and Vigna's xoshiro256** generator. It is not one of NumPy's bit generators, but it
+/// is a common choice in other ecosystems.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::Xoshiro256StarStar;
+/// use rand::RngCore;
+///
+/// let mut rng = Xoshiro256StarStar::new([1, 2, 3, 4]);
+/// assert_eq!(rng.next_u64(), 11520);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro256StarStar {
+    state: [u64; 4],
+    half: HalfWord,
+}
+
+impl Xoshiro256StarStar {
+    /// Creates a generator from its state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every word of `state` is zero, since the generator would then only produce
+    /// zeros.
+    pub fn new(state: [u64; 4]) -> Self {
+        assert!(
+            state != [0; 4],
+            "the xoshiro256** state must not be all zero"
+        );
+        Xoshiro256StarStar {
+            state,
+            half: HalfWord::default(),
+        }
+    }
+
+    fn next_word(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+impl SeedableRng for Xoshiro256StarStar {
+    type Seed = [u8; 32];
+
+    /// Uses the seed as the state. An all-zero seed is replaced by `seed_from_u64(0)`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let state = seed_words::<4>(&seed);
+        if state == [0; 4] {
+            Self::seed_from_u64(0)
+        } else {
+            Xoshiro256StarStar::new(state)
+        }
+    }
+}
+
+impl_rng_core!(Xoshiro256StarStar);
+
+impl Jumpable for Xoshiro256StarStar {
+    fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180e_c6d3_3cfd_0aba,
+            0xd5a6_1266_f0c9_392c,
+            0xa958_2618_e03f_c9aa,
+            0x39ab_dc45_29b1_661c,
+        ];
+        let mut jumped = [0u64; 4];
+        for word in JUMP {
+            for bit in 0..64 {
+                if (word >> bit) & 1 == 1 {
+                    for (j, s) in jumped.iter_mut().zip(self.state) {
+                        *j ^= s;
+                    }
+                }
+                self.next_word();
+            }
+        }
+        self.state = jumped;
+        self.half = HalfWord::default();
+    }
+}
+
+#[cfg(test)]
+mod numrust_bit_generator_tests {
+    use super::*;
+
+    fn words<R: RngCore>(rng: &mut R, n: usize) -> Vec<u64> {
+        (0..n).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn test_pcg_reference_values() {
+        // From the test suites of the C++ PCG reference implementation.
+        assert_eq!(
+            words(&mut Pcg64::new(42, 54), 6),
+            vec![
+                0x86b1da1d72062b68,
+                0x1304aa46c9853d39,
+                0xa3670e9e0dd50358,
+                0xf9090e529a7dae00,
+                0xc85b9fd837996f2c,
+                0x606121f8e3919196,
+            ]
+        );
+        assert_eq!(
+            words(&mut Pcg64Dxsm::new(42, 54), 6),
+            vec![
+                17331114245835578256,
+                10267467544499227306,
+                9726600296081716989,
+                10165951391103677450,
+                12131334649314727261,
+                10134094537930450875,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_philox_known_answers() {
+        // Known-answer vectors from the Random123 distribution.
+        assert_eq!(
+            philox4x64_10([0; 4], [0; 2]),
+            [
+                0x16554d9eca36314c,
+                0xdb20fe9d672d0fdc,
+                0xd7e772cee186176b,
+                0x7e68b68aec7ba23b,
+            ]
+        );
+        assert_eq!(
+            philox4x64_10([u64::MAX; 4], [u64::MAX; 2]),
+            [
+                0x87b092c3013fe90b,
+                0x438c3c67be8d0224,
+                0x9cc7d7c69cd777b6,
+                0xa09caebf594f0ba0,
+            ]
+        );
+        assert_eq!(
+            philox4x64_10(
+                [
+                    0x243f6a8885a308d3,
+                    0x13198a2e03707344,
+                    0xa4093822299f31d0,
+                    0x082efa98ec4e6c89,
+                ],
+                [0x452821e638d01377, 0xbe5466cf34e90c6c],
+            ),
+            [
+                0xa528f45403e61d95,
+                0x38c72dbd566e9788,
+                0xa5a1610e72fd18b5,
+                0x57bd43b5e52b7fe6,
+            ]
+        );
+
+        // The stream starts at counter + 1 and carries into the higher counter words.
+        let mut rng = Philox::new([u64::MAX, 0, 0, 0], [0; 2]);
+        assert_eq!(words(&mut rng, 4), philox4x64_10([0, 1, 0, 0], [0; 2]));
+        assert_eq!(
+            words(&mut rng, 1)[0],
+            philox4x64_10([1, 1, 0, 0], [0; 2])[0]
+        );
+    }
+
+    #[test]
+    fn test_sfc64_and_xoshiro_values() {
+        // Computed with an independent Python transcription of the reference C code.
+        assert_eq!(
+            words(&mut Sfc64::new([1, 2, 3]), 3),
+            vec![4895842857741926726, 176895602379161741, 8114574854211248503]
+        );
+        assert_eq!(
+            words(&mut Xoshiro256StarStar::new([1, 2, 3, 4]), 10),
+            vec![
+                11520,
+                0,
+                1509978240,
+                1215971899390074240,
+                1216172134540287360,
+                607988272756665600,
+                16172922978634559625,
+                8476171486693032832,
+                10595114339597558777,
+                2904607092377533576,
+            ]
+        );
+        assert_eq!(
+            Xoshiro256StarStar::from_seed([0; 32]),
+            Xoshiro256StarStar::seed_from_u64(0)
+        );
+    }
+
+    #[test]
+    fn test_u32_and_double_draws() {
+        // 32-bit draws split each word, low half first.
+        let word = Pcg64::new(1, 2).next_u64();
+        let mut rng = Pcg64::new(1, 2);
+        assert_eq!(rng.next_u32(), word as u32);
+        assert_eq!(rng.next_u32(), (word >> 32) as u32);
+
+        let mut rng = Sfc64::new([4, 5, 6]);
+        let word = rng.clone().next_u64();
+        assert_eq!(rng.next_double(), (word >> 11) as f64 / 2f64.powi(53));
+
+        let mut bytes = [0u8; 12];
+        let mut rng = Philox::new([0; 4], [7, 8]);
+        let expected = rng.clone().next_u64().to_le_bytes();
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(bytes[..8], expected);
+    }
+    #[test]
+    fn test_advance_and_jump() {
+        fn check_advance<R: RngCore + Clone>(
+            rng: R,
+            advance: impl Fn(&mut R, u128),
+            delta: u128,
+            words: usize,
+        ) {
+            let mut drawn = rng.clone();
+            for _ in 0..words {
+                drawn.next_u64();
+            }
+            let mut skipped = rng;
+            advance(&mut skipped, delta);
+            assert_eq!(drawn.next_u64(), skipped.next_u64());
+        }
+        check_advance(Pcg64::new(1, 2), Pcg64::advance, 1001, 1001);
+        check_advance(Pcg64Dxsm::new(1, 2), Pcg64Dxsm::advance, 777, 777);
+        // Philox advances in blocks of four words, and carries across counter words.
+        check_advance(
+            Philox::new([u64::MAX, 0, 0, 0], [5, 6]),
+            Philox::advance,
+            100,
+            400,
+        );
+
+        // Advancing by the full period returns to the same state.
+        let mut rng = Pcg64::new(9, 9);
+        let start = rng.clone();
+        rng.advance(u128::MAX);
+        rng.next_u64();
+        assert_eq!(rng.state, start.state);
+
+        // Jumping a PCG generator is advancing by NumPy's jump step.
+        let rng = Pcg64::new(1, 2);
+        let mut advanced = rng.clone();
+        advanced.advance(PCG64_JUMP.wrapping_mul(3));
+        assert_eq!(rng.jumped(3), advanced);
+
+        let philox = Philox::new([1, 2, u64::MAX, 4], [0; 2]).jumped(1);
+        assert_eq!(philox.counter, [1, 2, 0, 5]);
+
+        // Computed with an independent Python transcription of the reference C code.
+        let mut rng = Xoshiro256StarStar::new([1, 2, 3, 4]).jumped(1);
+        assert_eq!(
+            rng.state,
+            [
+                10122426448480695249,
+                8079205330032121950,
+                7289065458748526725,
+                9477464255293849680
+            ]
+        );
+        let mut unjumped = Xoshiro256StarStar::new([1, 2, 3, 4]);
+        assert_ne!(rng.next_u64(), unjumped.next_u64());
+    }
+}
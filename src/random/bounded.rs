@@ -0,0 +1,352 @@
+//! NumPy's algorithms for drawing bounded integers, which `integers`, `choice` and the shuffles
+//! share so that their streams match NumPy's exactly, extended to 128-bit integers.
+
+use rand::RngCore;
+
+/// Draws from `[0, range]` with Lemire's multiply-and-reject method on 32-bit words.
+/// `range` must be below `u32::MAX`.
+fn bounded_lemire_u32<R: RngCore + ?Sized>(rng: &mut R, range: u32) -> u32 {
+    let range_excl = range + 1;
+    let mut m = rng.next_u32() as u64 * range_excl as u64;
+    let mut leftover = m as u32;
+    if leftover < range_excl {
+        let threshold = (u32::MAX - range) % range_excl;
+        while leftover < threshold {
+            m = rng.next_u32() as u64 * range_excl as u64;
+            leftover = m as u32;
+        }
+    }
+    (m >> 32) as u32
+}
+
+/// Draws from `[0, range]` with Lemire's multiply-and-reject method on 64-bit words.
+/// `range` must be below `u64::MAX`.
+fn bounded_lemire_u64<R: RngCore + ?Sized>(rng: &mut R, range: u64) -> u64 {
+    let range_excl = range + 1;
+    let mut m = rng.next_u64() as u128 * range_excl as u128;
+    let mut leftover = m as u64;
+    if leftover < range_excl {
+        let threshold = (u64::MAX - range) % range_excl;
+        while leftover < threshold {
+            m = rng.next_u64() as u128 * range_excl as u128;
+            leftover = m as u64;
+        }
+    }
+    (m >> 64) as u64
+}
+
+/// Draws uniformly from `[0, range]`, using 32-bit words whenever the range fits in them, as
+/// NumPy's `random_bounded_uint64` does.
+pub(super) fn bounded_u64<R: RngCore + ?Sized>(rng: &mut R, range: u64) -> u64 {
+    if range == 0 {
+        0
+    } else if range < u32::MAX as u64 {
+        bounded_lemire_u32(rng, range as u32) as u64
+    } else if range == u32::MAX as u64 {
+        rng.next_u32() as u64
+    } else if range == u64::MAX {
+        rng.next_u64()
+    } else {
+        bounded_lemire_u64(rng, range)
+    }
+}
+
+/// Draws uniformly from `[0, range]` for ranges of 128-bit integers, falling back on
+/// [`bounded_u64`] whenever the range fits in 64 bits.
+fn bounded_u128<R: RngCore + ?Sized>(rng: &mut R, range: u128) -> u128 {
+    if range <= u64::MAX as u128 {
+        return bounded_u64(rng, range as u64) as u128;
+    }
+    let mut next = || rng.next_u64() as u128 | (rng.next_u64() as u128) << 64;
+    if range == u128::MAX {
+        return next();
+    }
+    let range_excl = range + 1;
+    let (mut high, mut leftover) = widening_mul_u128(next(), range_excl);
+    if leftover < range_excl {
+        let threshold = (u128::MAX - range) % range_excl;
+        while leftover < threshold {
+            (high, leftover) = widening_mul_u128(next(), range_excl);
+        }
+    }
+    high
+}
+
+/// Returns the high and low halves of the 256-bit product `a * b`.
+fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const LOW: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & LOW);
+    let (b_hi, b_lo) = (b >> 64, b & LOW);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+    let middle = (lo_lo >> 64) + (hi_lo & LOW) + (lo_hi & LOW);
+    let low = (middle << 64) | (lo_lo & LOW);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Hands out 8 or 16 bits at a time from 32-bit words, low bits first, as NumPy's buffered
+/// draws for small integer types do.
+#[derive(Default)]
+struct WordBuffer {
+    word: u32,
+    remaining: u32,
+}
+
+impl WordBuffer {
+    fn next_bits<R: RngCore + ?Sized>(&mut self, rng: &mut R, bits: u32) -> u32 {
+        if self.remaining == 0 {
+            self.word = rng.next_u32();
+            self.remaining = 32 / bits - 1;
+        } else {
+            self.word >>= bits;
+            self.remaining -= 1;
+        }
+        self.word & (u32::MAX >> (32 - bits))
+    }
+}
+
+/// Draws from `[0, range]` for integers of `bits` bits (8 or 16) with Lemire's method on
+/// buffered words, as NumPy's `buffered_bounded_lemire_uint8` and `..._uint16` do.
+fn bounded_small<R: RngCore + ?Sized>(
+    rng: &mut R,
+    buffer: &mut WordBuffer,
+    range: u32,
+    bits: u32,
+) -> u32 {
+    let mask = u32::MAX >> (32 - bits);
+    if range == 0 {
+        return 0;
+    } else if range == mask {
+        return buffer.next_bits(rng, bits);
+    }
+    let range_excl = range + 1;
+    let mut m = buffer.next_bits(rng, bits) * range_excl;
+    let mut leftover = m & mask;
+    if leftover < range_excl {
+        let threshold = (mask - range) % range_excl;
+        while leftover < threshold {
+            m = buffer.next_bits(rng, bits) * range_excl;
+            leftover = m & mask;
+        }
+    }
+    m >> bits
+}
+
+/// A primitive integer type that [`Generator::integers`](super::Generator::integers) can draw.
+///
+/// Implemented for all the primitive integer types. Draws are unbiased over any range,
+/// including the full range of the type. 8- and 16-bit types split 32-bit words, 32- and
+/// 64-bit types follow NumPy's 64-bit algorithm, and 128-bit types use two 64-bit words when the
+/// range needs them.
+pub trait UniformInteger: Copy + PartialOrd {
+    /// Returns `self - 1`, which must not overflow.
+    fn predecessor(self) -> Self;
+
+    /// Draws `n` integers uniformly from `[low, high]`, where `low <= high`.
+    fn sample_inclusive<R: RngCore + ?Sized>(
+        rng: &mut R,
+        low: Self,
+        high: Self,
+        n: usize,
+    ) -> Vec<Self>;
+}
+
+macro_rules! impl_uniform_integer_small {
+    ($($ty:ty => $unsigned:ty),*) => {
+        $(
+            impl UniformInteger for $ty {
+                fn predecessor(self) -> Self {
+                    self - 1
+                }
+
+                fn sample_inclusive<R: RngCore + ?Sized>(
+                    rng: &mut R,
+                    low: Self,
+                    high: Self,
+                    n: usize,
+                ) -> Vec<Self> {
+                    let range = (high as $unsigned).wrapping_sub(low as $unsigned);
+                    let mut buffer = WordBuffer::default();
+                    (0..n)
+                        .map(|_| {
+                            let draw =
+                                bounded_small(rng, &mut buffer, range as u32, <$unsigned>::BITS);
+                            low.wrapping_add(draw as $ty)
+                        })
+                        .collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_uniform_integer_small!(i8 => u8, u8 => u8, i16 => u16, u16 => u16);
+
+macro_rules! impl_uniform_integer_wide {
+    ($bounded:ident, $word:ty; $($ty:ty => $unsigned:ty),*) => {
+        $(
+            impl UniformInteger for $ty {
+                fn predecessor(self) -> Self {
+                    self - 1
+                }
+
+                fn sample_inclusive<R: RngCore + ?Sized>(
+                    rng: &mut R,
+                    low: Self,
+                    high: Self,
+                    n: usize,
+                ) -> Vec<Self> {
+                    let range = (high as $unsigned).wrapping_sub(low as $unsigned);
+                    (0..n)
+                        .map(|_| low.wrapping_add($bounded(rng, range as $word) as $ty))
+                        .collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_uniform_integer_wide!(
+    bounded_u64, u64;
+    i32 => u32, u32 => u32, i64 => u64, u64 => u64, isize => usize, usize => usize
+);
+impl_uniform_integer_wide!(bounded_u128, u128; i128 => u128, u128 => u128);
+
+/// Draws uniformly from `[0, max]` by masking and rejecting, as NumPy's `random_interval` does
+/// for its shuffles.
+pub(super) fn interval<R: RngCore + ?Sized>(rng: &mut R, max: u64) -> u64 {
+    if max == 0 {
+        return 0;
+    }
+    let mask = u64::MAX >> max.leading_zeros();
+    loop {
+        let value = if max <= u32::MAX as u64 {
+            rng.next_u32() as u64 & mask
+        } else {
+            rng.next_u64() & mask
+        };
+        if value <= max {
+            return value;
+        }
+    }
+}
+
+/// Shuffles `values[first..]` into random positions among `values`, walking down from the end
+/// as NumPy's Fisher–Yates shuffles do. With `first` of one this shuffles the whole slice.
+pub(super) fn shuffle_tail<T, R: RngCore + ?Sized>(rng: &mut R, values: &mut [T], first: usize) {
+    for i in (first.max(1)..values.len()).rev() {
+        let j = interval(rng, i as u64) as usize;
+        values.swap(i, j);
+    }
+}
+
+/// Shuffles `values[first..]` like [`shuffle_tail`], but draws each position with
+/// [`bounded_u64`], as NumPy's `_shuffle_int` does for `choice` without replacement.
+pub(super) fn shuffle_int<T, R: RngCore + ?Sized>(rng: &mut R, values: &mut [T], first: usize) {
+    for i in (first.max(1)..values.len()).rev() {
+        let j = bounded_u64(rng, i as u64) as usize;
+        values.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod numrust_bounded_tests {
+    use super::*;
+    use crate::random::Pcg64;
+
+    #[test]
+    fn test_bounded_ranges() {
+        let mut rng = Pcg64::new(3, 4);
+        for range in [0, 1, 6, 1000, u32::MAX as u64 - 1, u32::MAX as u64, 1 << 40] {
+            for _ in 0..200 {
+                assert!(bounded_u64(&mut rng, range) <= range);
+                assert!(interval(&mut rng, range) <= range);
+            }
+        }
+        // The full range takes whole words.
+        let word = Pcg64::new(3, 4).next_u64();
+        assert_eq!(bounded_u64(&mut Pcg64::new(3, 4), u64::MAX), word);
+
+        // Small ranges are uniform.
+        let mut counts = [0usize; 3];
+        for _ in 0..30000 {
+            counts[bounded_u64(&mut rng, 2) as usize] += 1;
+            counts[interval(&mut rng, 2) as usize] += 1;
+        }
+        for count in counts {
+            assert!((count as f64 / 60000.0 - 1.0 / 3.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_wide_and_small_ranges() {
+        assert_eq!(widening_mul_u128(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(widening_mul_u128(1 << 100, 1 << 100), (1 << 72, 0));
+
+        let mut rng = Pcg64::new(7, 8);
+        for range in [1u128 << 64, u128::MAX / 3, u128::MAX - 1] {
+            for _ in 0..200 {
+                assert!(bounded_u128(&mut rng, range) <= range);
+            }
+        }
+        // Ranges that fit in 64 bits draw the same stream as the 64-bit types.
+        assert_eq!(
+            bounded_u128(&mut Pcg64::new(7, 8), 1000),
+            bounded_u64(&mut Pcg64::new(7, 8), 1000) as u128
+        );
+
+        // Four 8-bit draws share one 32-bit word, low byte first.
+        let word = Pcg64::new(7, 8).next_u32();
+        let mut buffer = WordBuffer::default();
+        let mut rng = Pcg64::new(7, 8);
+        let bytes: Vec<u32> = (0..4)
+            .map(|_| bounded_small(&mut rng, &mut buffer, 255, 8))
+            .collect();
+        assert_eq!(bytes, word.to_le_bytes().map(u32::from).to_vec());
+
+        let mut counts = [0usize; 5];
+        for _ in 0..50000 {
+            counts[bounded_small(&mut rng, &mut buffer, 4, 16) as usize] += 1;
+        }
+        for count in counts {
+            assert!((count as f64 / 50000.0 - 0.2).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_shuffle_tail() {
+        let mut rng = Pcg64::new(5, 6);
+        let mut values: Vec<usize> = (0..20).collect();
+        shuffle_tail(&mut rng, &mut values, 1);
+        let mut sorted = values.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+        assert_ne!(values, sorted);
+
+        // Only the positions from `first` on are drawn for.
+        let mut values: Vec<usize> = (0..20).collect();
+        shuffle_tail(&mut rng, &mut values, 20);
+        assert_eq!(values, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffle_int_draws_with_lemire() {
+        let mut values: Vec<usize> = (0..20).collect();
+        shuffle_int(&mut Pcg64::new(5, 6), &mut values, 1);
+        let mut sorted = values.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+
+        // Each swap partner is the next bounded_u64 draw, walking down from the end.
+        let mut rng = Pcg64::new(5, 6);
+        let mut expected: Vec<usize> = (0..20).collect();
+        for i in (1..20).rev() {
+            let j = bounded_u64(&mut rng, i as u64) as usize;
+            expected.swap(i, j);
+        }
+        assert_eq!(values, expected);
+    }
+}
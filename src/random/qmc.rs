@@ -0,0 +1,795 @@
+//! Quasi-Monte Carlo: low-discrepancy sequences that cover the unit hypercube more evenly than
+//! independent uniform draws, and a measure of how evenly a sample covers it. This mirrors
+//! SciPy's `scipy.stats.qmc`.
+
+use std::sync::OnceLock;
+
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+use rand::{Rng, RngCore};
+
+use super::permutation_from;
+use crate::NumrustError;
+
+/// Number of bits in each Sobol coordinate, which also caps a sequence at `2^32` points.
+const SOBOL_BITS: usize = 32;
+
+/// Joe and Kuo's direction numbers in the layout of their `new-joe-kuo-6.21201` file: a header
+/// line, then one line per Sobol dimension from 2 up holding the dimension, the degree `s` of its
+/// primitive polynomial, the polynomial's inner coefficients `a` and the odd initial numbers
+/// `m_1..m_s`.
+const JOE_KUO: &str = include_str!("joe_kuo.txt");
+
+/// Counts the dimensions in [`JOE_KUO`], one per non-empty line after the header.
+const fn joe_kuo_rows(table: &str) -> usize {
+    let bytes = table.as_bytes();
+    let mut rows = 0;
+    let mut line_start = true;
+    let mut i = 0;
+    while i < bytes.len() {
+        if line_start && !bytes[i].is_ascii_whitespace() {
+            rows += 1;
+        }
+        line_start = bytes[i] == b'\n';
+        i += 1;
+    }
+    rows - 1
+}
+
+/// The largest dimension [`Sobol`] has direction numbers for: the first dimension plus one per
+/// row of Joe and Kuo's table.
+pub const SOBOL_MAX_DIM: usize = joe_kuo_rows(JOE_KUO) + 1;
+
+/// A row of Joe and Kuo's table: the degree `s`, the coefficients `a` and `m_1..m_s`.
+struct JoeKuoRow {
+    s: usize,
+    a: u32,
+    m: Vec<u32>,
+}
+
+/// Returns the rows of [`JOE_KUO`], parsed on first use.
+fn joe_kuo_table() -> &'static [JoeKuoRow] {
+    static TABLE: OnceLock<Vec<JoeKuoRow>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        JOE_KUO
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let numbers: Vec<u32> = line
+                    .split_whitespace()
+                    .map(|field| field.parse().expect("malformed Joe-Kuo table"))
+                    .collect();
+                JoeKuoRow {
+                    s: numbers[1] as usize,
+                    a: numbers[2],
+                    m: numbers[3..].to_vec(),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Returns the direction numbers of Sobol dimension `dim` (zero-based), scaled to 32 bits.
+fn direction_numbers(dim: usize) -> [u32; SOBOL_BITS] {
+    let mut v = [0u32; SOBOL_BITS];
+    if dim == 0 {
+        for (k, value) in v.iter_mut().enumerate() {
+            *value = 1 << (SOBOL_BITS - 1 - k);
+        }
+        return v;
+    }
+    let JoeKuoRow { s, a, m } = &joe_kuo_table()[dim - 1];
+    let (s, a) = (*s, *a);
+    for (k, &m_k) in m.iter().enumerate().take(SOBOL_BITS) {
+        v[k] = m_k << (SOBOL_BITS - 1 - k);
+    }
+    for k in s..SOBOL_BITS {
+        let mut value = v[k - s] ^ (v[k - s] >> s);
+        for j in 1..s {
+            if (a >> (s - 1 - j)) & 1 == 1 {
+                value ^= v[k - j];
+            }
+        }
+        v[k] = value;
+    }
+    v
+}
+
+/// Mixes the bits of `x` with the SplitMix64 finalizer.
+fn mix64(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Applies Owen's nested uniform scramble to the 32 binary digits of `x`.
+///
+/// Digit `j` (counting from the most significant) is flipped by a random bit that depends on
+/// `seed`, `j` and the `j` digits above it, so every prefix of the binary expansion has its own
+/// independent flip for the digit that follows. The random bits are drawn from a hash of the
+/// prefix instead of being stored, which makes the scramble cover all `2^32 - 1` nodes of the
+/// digit tree.
+fn owen_scramble(x: u32, seed: u64) -> u32 {
+    let mut flips = 0u32;
+    for j in 0..SOBOL_BITS {
+        let prefix = if j == 0 { 0 } else { x >> (SOBOL_BITS - j) };
+        let node = ((j as u64) << 32) | prefix as u64;
+        if mix64(seed ^ mix64(node)) & 1 == 1 {
+            flips |= 1 << (SOBOL_BITS - 1 - j);
+        }
+    }
+    x ^ flips
+}
+
+/// A Sobol sequence, built from Joe and Kuo's direction numbers and optionally Owen-scrambled.
+///
+/// Points are drawn in Gray code order, so the first `2^m` points of an unscrambled sequence
+/// place exactly one point in each of the `2^m` equal intervals of every coordinate. Owen's
+/// nested uniform scramble keeps that balance, and the net structure of every projection, while
+/// making each point uniformly distributed over the unit hypercube.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::random::qmc::Sobol;
+///
+/// let mut sobol = Sobol::new(2).unwrap();
+/// let points = sobol.random(4).unwrap();
+/// assert_eq!(points, array![[0.0, 0.0], [0.5, 0.5], [0.75, 0.25], [0.25, 0.75]]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sobol {
+    directions: Vec<[u32; SOBOL_BITS]>,
+    scramble_seeds: Option<Vec<u64>>,
+    index: u64,
+}
+
+impl Sobol {
+    /// Creates an unscrambled Sobol sequence in `d` dimensions, starting at the origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if `d` is zero or above [`SOBOL_MAX_DIM`].
+    pub fn new(d: usize) -> Result<Self, NumrustError> {
+        if d == 0 || d > SOBOL_MAX_DIM {
+            return Err(NumrustError::ValueError(format!(
+                "Sobol sequences support dimensions 1 to {}",
+                SOBOL_MAX_DIM
+            )));
+        }
+        Ok(Sobol {
+            directions: (0..d).map(direction_numbers).collect(),
+            scramble_seeds: None,
+            index: 0,
+        })
+    }
+
+    /// Creates a Sobol sequence in `d` dimensions with an independent Owen scramble for each
+    /// dimension, seeded from `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::{qmc::Sobol, default_rng};
+    ///
+    /// let mut rng = default_rng(42);
+    /// let points = Sobol::scrambled(3, &mut rng).unwrap().random(8).unwrap();
+    /// // Each coordinate still has one point in each eighth of [0, 1).
+    /// for column in points.columns() {
+    ///     let mut cells: Vec<usize> = column.iter().map(|x| (x * 8.0) as usize).collect();
+    ///     cells.sort();
+    ///     assert_eq!(cells, (0..8).collect::<Vec<_>>());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if `d` is zero or above [`SOBOL_MAX_DIM`].
+    pub fn scrambled<R: RngCore + ?Sized>(d: usize, rng: &mut R) -> Result<Self, NumrustError> {
+        let mut sobol = Sobol::new(d)?;
+        sobol.scramble_seeds = Some((0..d).map(|_| rng.next_u64()).collect());
+        Ok(sobol)
+    }
+
+    /// Returns the number of dimensions.
+    pub fn dim(&self) -> usize {
+        self.directions.len()
+    }
+
+    /// Returns the index of the next point to be drawn.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the next `n` points as the rows of an `n` by `d` array.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if the sequence would run past its `2^32` points.
+    pub fn random(&mut self, n: usize) -> Result<Array2<f64>, NumrustError> {
+        let end = self
+            .index
+            .checked_add(n as u64)
+            .filter(|&end| end <= 1 << SOBOL_BITS);
+        let Some(end) = end else {
+            return Err(NumrustError::ValueError(
+                "Sobol sequence cannot produce more than 2^32 points".to_string(),
+            ));
+        };
+        let scale = 1.0 / (1u64 << SOBOL_BITS) as f64;
+        let mut points = Array2::zeros((n, self.dim()));
+        for (mut row, i) in points.rows_mut().into_iter().zip(self.index..end) {
+            let gray = i ^ (i >> 1);
+            for (k, (x, v)) in row.iter_mut().zip(&self.directions).enumerate() {
+                let mut bits = 0u32;
+                for (bit, &direction) in v.iter().enumerate() {
+                    if (gray >> bit) & 1 == 1 {
+                        bits ^= direction;
+                    }
+                }
+                if let Some(seeds) = &self.scramble_seeds {
+                    bits = owen_scramble(bits, seeds[k]);
+                }
+                *x = bits as f64 * scale;
+            }
+        }
+        self.index = end;
+        Ok(points)
+    }
+
+    /// Returns the next `2^m` points, the sample sizes at which Sobol points are balanced.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if the sequence has not drawn a multiple of `2^m`
+    /// points so far, or would run past its `2^32` points.
+    pub fn random_base2(&mut self, m: u32) -> Result<Array2<f64>, NumrustError> {
+        if m as usize > SOBOL_BITS || !self.index.is_multiple_of(1 << m) {
+            return Err(NumrustError::ValueError(
+                "The balance properties of Sobol points require the index to be a multiple of 2^m"
+                    .to_string(),
+            ));
+        }
+        self.random(1 << m)
+    }
+
+    /// Skips the next `n` points.
+    pub fn fast_forward(&mut self, n: u64) {
+        self.index = self.index.saturating_add(n);
+    }
+
+    /// Rewinds the sequence to its first point, keeping any scramble.
+    pub fn reset(&mut self) {
+        self.index = 0;
+    }
+}
+
+/// Returns the first `d` primes.
+fn first_primes(d: usize) -> Vec<u64> {
+    let mut primes: Vec<u64> = Vec::with_capacity(d);
+    let mut candidate = 2;
+    while primes.len() < d {
+        if primes
+            .iter()
+            .take_while(|&&p| p * p <= candidate)
+            .all(|&p| candidate % p != 0)
+        {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+/// A Halton sequence, whose `j`-th coordinate is the radical inverse of the point's index in
+/// the `j`-th prime base, optionally scrambled with random digit permutations.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::random::qmc::Halton;
+///
+/// let mut halton = Halton::new(2).unwrap();
+/// let points = halton.random(3);
+/// assert_eq!(points, array![[0.0, 0.0], [0.5, 1.0 / 3.0], [0.25, 2.0 / 3.0]]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Halton {
+    bases: Vec<u64>,
+    permutations: Option<Vec<Vec<Vec<u64>>>>,
+    index: u64,
+}
+
+impl Halton {
+    /// Creates an unscrambled Halton sequence in `d` dimensions, starting at the origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if `d` is zero.
+    pub fn new(d: usize) -> Result<Self, NumrustError> {
+        if d == 0 {
+            return Err(NumrustError::ValueError(
+                "Halton sequences need at least one dimension".to_string(),
+            ));
+        }
+        Ok(Halton {
+            bases: first_primes(d),
+            permutations: None,
+            index: 0,
+        })
+    }
+
+    /// Creates a Halton sequence in `d` dimensions whose digits are scrambled with random
+    /// permutations drawn from `rng`, one per digit position and base.
+    ///
+    /// Every digit down to double precision is permuted, including the trailing zeros of each
+    /// index, so the points are spread over the whole of `[0, 1)` rather than a coarse grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::{qmc::Halton, default_rng};
+    ///
+    /// let mut rng = default_rng(0);
+    /// let points = Halton::scrambled(4, &mut rng).unwrap().random(16);
+    /// assert!(points.iter().all(|&x| (0.0..1.0).contains(&x)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NumrustError::ValueError` if `d` is zero.
+    pub fn scrambled<R: Rng + ?Sized>(d: usize, rng: &mut R) -> Result<Self, NumrustError> {
+        let mut halton = Halton::new(d)?;
+        let permutations = halton
+            .bases
+            .iter()
+            .map(|&base| {
+                let digits = (54.0 / (base as f64).log2()).ceil() as usize - 1;
+                (0..digits)
+                    .map(|_| {
+                        permutation_from(rng, base as usize)
+                            .iter()
+                            .map(|&digit| digit as u64)
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+        halton.permutations = Some(permutations);
+        Ok(halton)
+    }
+
+    /// Returns the number of dimensions.
+    pub fn dim(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Returns the index of the next point to be drawn.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the next `n` points as the rows of an `n` by `d` array.
+    pub fn random(&mut self, n: usize) -> Array2<f64> {
+        let mut points = Array2::zeros((n, self.dim()));
+        for (mut row, i) in points.rows_mut().into_iter().zip(self.index..) {
+            for (k, (x, &base)) in row.iter_mut().zip(&self.bases).enumerate() {
+                *x = match &self.permutations {
+                    None => radical_inverse(i, base),
+                    Some(permutations) => scrambled_radical_inverse(i, base, &permutations[k]),
+                };
+            }
+        }
+        self.index += n as u64;
+        points
+    }
+
+    /// Skips the next `n` points.
+    pub fn fast_forward(&mut self, n: u64) {
+        self.index = self.index.saturating_add(n);
+    }
+
+    /// Rewinds the sequence to its first point, keeping any scramble.
+    pub fn reset(&mut self) {
+        self.index = 0;
+    }
+}
+
+/// Mirrors the base `base` digits of `index` about the radix point.
+fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    let inv_base = 1.0 / base as f64;
+    let mut scale = inv_base;
+    let mut result = 0.0;
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inv_base;
+    }
+    result
+}
+
+/// Mirrors the digits of `index` like [`radical_inverse`], passing the `k`-th digit through
+/// `permutations[k]`.
+fn scrambled_radical_inverse(mut index: u64, base: u64, permutations: &[Vec<u64>]) -> f64 {
+    let inv_base = 1.0 / base as f64;
+    let mut scale = inv_base;
+    let mut result = 0.0;
+    for digit_permutation in permutations {
+        result += digit_permutation[(index % base) as usize] as f64 * scale;
+        index /= base;
+        scale *= inv_base;
+    }
+    // Rounding can carry an all-(base - 1) expansion up to exactly one.
+    result.min(1.0 - f64::EPSILON / 2.0)
+}
+
+/// Returns a Latin hypercube sample of `n` points in `d` dimensions: every coordinate has
+/// exactly one point in each of the `n` equal intervals of `[0, 1)`, with the intervals paired
+/// up across coordinates at random.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator.
+/// * `n` - The number of points.
+/// * `d` - The number of dimensions.
+/// * `centered` - Whether to place each point at the center of its cell rather than uniformly
+///   within it.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::{qmc::latin_hypercube, default_rng};
+///
+/// let mut rng = default_rng(1);
+/// let sample = latin_hypercube(&mut rng, 5, 2, false);
+/// for column in sample.columns() {
+///     let mut cells: Vec<usize> = column.iter().map(|x| (x * 5.0) as usize).collect();
+///     cells.sort();
+///     assert_eq!(cells, vec![0, 1, 2, 3, 4]);
+/// }
+/// ```
+pub fn latin_hypercube<R: Rng + ?Sized>(
+    rng: &mut R,
+    n: usize,
+    d: usize,
+    centered: bool,
+) -> Array2<f64> {
+    let mut sample = Array2::zeros((n, d));
+    for mut column in sample.columns_mut() {
+        let cells = permutation_from(rng, n);
+        for (x, &cell) in column.iter_mut().zip(&cells) {
+            let offset = if centered { 0.5 } else { rng.gen::<f64>() };
+            *x = (cell as f64 + offset) / n as f64;
+        }
+    }
+    sample
+}
+
+/// How [`discrepancy`] measures the distance between a sample and the uniform distribution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiscrepancyMethod {
+    /// Hickernell's centered L2 discrepancy, which compares boxes anchored at the corners
+    /// closest to each point and does not change when the cube is reflected.
+    #[default]
+    Centered,
+    /// The wrap-around L2 discrepancy, which treats the cube as a torus.
+    WrapAround,
+    /// The mixture L2 discrepancy, a compromise between the centered and wrap-around forms.
+    Mixture,
+    /// The L2-star discrepancy, which compares boxes anchored at the origin.
+    L2Star,
+}
+
+/// Returns the discrepancy of a sample in the unit hypercube, whose rows are points; lower
+/// values mean a more even covering. As in SciPy, the centered, wrap-around and mixture
+/// discrepancies are returned squared, and the L2-star discrepancy is not.
+///
+/// # Arguments
+///
+/// * `sample` - An `n` by `d` array of points in `[0, 1]^d`.
+/// * `method` - The discrepancy to compute.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::random::qmc::{discrepancy, DiscrepancyMethod};
+///
+/// let space = array![[1.0, 3.0], [2.0, 6.0], [3.0, 2.0], [4.0, 5.0], [5.0, 1.0], [6.0, 4.0]];
+/// let space = (space - 0.5) / 6.0;
+/// let cd = discrepancy(&space, DiscrepancyMethod::Centered).unwrap();
+/// assert!((cd - 0.008142039609053464).abs() < 1e-15);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `sample` has no points or a point lies outside the
+/// unit hypercube.
+pub fn discrepancy<S>(
+    sample: &ArrayBase<S, Ix2>,
+    method: DiscrepancyMethod,
+) -> Result<f64, NumrustError>
+where
+    S: Data<Elem = f64>,
+{
+    let (n, d) = sample.dim();
+    if n == 0 {
+        return Err(NumrustError::ValueError(
+            "Sample must contain at least one point".to_string(),
+        ));
+    }
+    if !sample.iter().all(|x| (0.0..=1.0).contains(x)) {
+        return Err(NumrustError::ValueError(
+            "Sample is not in unit hypercube".to_string(),
+        ));
+    }
+    let rows: Vec<_> = sample.rows().into_iter().collect();
+    let n_f = n as f64;
+    let d_i = d as i32;
+    let single = |f: &dyn Fn(f64) -> f64| -> f64 {
+        rows.iter()
+            .map(|row| row.iter().map(|&x| f(x)).product::<f64>())
+            .sum::<f64>()
+    };
+    let pairs = |f: &dyn Fn(f64, f64) -> f64| -> f64 {
+        rows.iter()
+            .flat_map(|a| {
+                rows.iter()
+                    .map(move |b| a.iter().zip(b).map(|(&x, &y)| f(x, y)).product::<f64>())
+            })
+            .sum::<f64>()
+    };
+    let value = match method {
+        DiscrepancyMethod::Centered => {
+            let disc1 = single(&|x| {
+                let c = (x - 0.5).abs();
+                1.0 + 0.5 * c - 0.5 * c * c
+            });
+            let disc2 = pairs(&|x, y| {
+                1.0 + 0.5 * (x - 0.5).abs() + 0.5 * (y - 0.5).abs() - 0.5 * (x - y).abs()
+            });
+            (13.0f64 / 12.0).powi(d_i) - 2.0 / n_f * disc1 + disc2 / (n_f * n_f)
+        }
+        DiscrepancyMethod::WrapAround => {
+            let disc = pairs(&|x, y| {
+                let delta = (x - y).abs();
+                1.5 - delta * (1.0 - delta)
+            });
+            -(4.0f64 / 3.0).powi(d_i) + disc / (n_f * n_f)
+        }
+        DiscrepancyMethod::Mixture => {
+            let disc1 = single(&|x| {
+                let c = (x - 0.5).abs();
+                5.0 / 3.0 - 0.25 * c - 0.25 * c * c
+            });
+            let disc2 = pairs(&|x, y| {
+                let delta = (x - y).abs();
+                15.0 / 8.0 - 0.25 * (x - 0.5).abs() - 0.25 * (y - 0.5).abs() - 0.75 * delta
+                    + 0.5 * delta * delta
+            });
+            (19.0f64 / 12.0).powi(d_i) - 2.0 / n_f * disc1 + disc2 / (n_f * n_f)
+        }
+        DiscrepancyMethod::L2Star => {
+            let disc1 = single(&|x| 1.0 - x * x);
+            let disc2 = pairs(&|x, y| 1.0 - x.max(y));
+            ((1.0f64 / 3.0).powi(d_i) - 2.0f64.powi(1 - d_i) / n_f * disc1 + disc2 / (n_f * n_f))
+                .max(0.0)
+                .sqrt()
+        }
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod numrust_qmc_tests {
+    use super::*;
+    use crate::random::default_rng;
+    use ndarray::array;
+
+    /// Checks that every coordinate of `points` has one point in each of `n` equal intervals.
+    fn assert_stratified(points: &Array2<f64>, n: usize) {
+        for column in points.columns() {
+            let mut cells: Vec<usize> = column.iter().map(|x| (x * n as f64) as usize).collect();
+            cells.sort();
+            assert_eq!(cells, (0..n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_sobol_points() {
+        let mut sobol = Sobol::new(3).unwrap();
+        let points = sobol.random(8).unwrap();
+        let expected = array![
+            [0.0, 0.0, 0.0],
+            [0.5, 0.5, 0.5],
+            [0.75, 0.25, 0.25],
+            [0.25, 0.75, 0.75],
+            [0.375, 0.375, 0.625],
+            [0.875, 0.875, 0.125],
+            [0.625, 0.125, 0.875],
+            [0.125, 0.625, 0.375]
+        ];
+        assert_eq!(points, expected);
+
+        // Drawing in pieces, skipping ahead and rewinding all follow the same sequence.
+        sobol.reset();
+        assert_eq!(
+            sobol.random(3).unwrap(),
+            expected.slice(ndarray::s![..3, ..])
+        );
+        sobol.fast_forward(2);
+        assert_eq!(
+            sobol.random(3).unwrap(),
+            expected.slice(ndarray::s![5.., ..])
+        );
+        assert_eq!(sobol.index(), 8);
+
+        // Every dimension is balanced at powers of two, and so are the first two jointly.
+        let mut sobol = Sobol::new(SOBOL_MAX_DIM).unwrap();
+        let points = sobol.random_base2(10).unwrap();
+        assert_stratified(&points, 1024);
+        let mut cells: Vec<usize> = points
+            .rows()
+            .into_iter()
+            .map(|row| (row[0] * 32.0) as usize * 32 + (row[1] * 32.0) as usize)
+            .collect();
+        cells.sort();
+        assert_eq!(cells, (0..1024).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sobol_errors() {
+        assert!(Sobol::new(0).is_err());
+        assert!(Sobol::new(SOBOL_MAX_DIM + 1).is_err());
+
+        let mut sobol = Sobol::new(2).unwrap();
+        sobol.random(3).unwrap();
+        assert!(sobol.random_base2(2).is_err());
+        sobol.fast_forward(u64::MAX);
+        assert!(sobol.random(1).is_err());
+    }
+
+    #[test]
+    fn test_scrambled_sobol() {
+        let points = Sobol::scrambled(5, &mut default_rng(3))
+            .unwrap()
+            .random_base2(8)
+            .unwrap();
+        assert_stratified(&points, 256);
+        assert_ne!(points, Sobol::new(5).unwrap().random(256).unwrap());
+        let again = Sobol::scrambled(5, &mut default_rng(3))
+            .unwrap()
+            .random(256)
+            .unwrap();
+        assert_eq!(points, again);
+
+        // Scrambling makes each point uniform, so the mean over many scrambles is a half.
+        let mut rng = default_rng(4);
+        let mut total = 0.0;
+        for _ in 0..2000 {
+            let mut sobol = Sobol::scrambled(2, &mut rng).unwrap();
+            sobol.fast_forward(5);
+            total += sobol.random(1).unwrap().sum();
+        }
+        assert!((total / 4000.0 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_owen_scramble_preserves_net_structure() {
+        // The first two Sobol dimensions form a (0, m, 2)-net: every box of area 2^-m whose
+        // sides are powers of two holds exactly one of the first 2^m points. Scrambling each
+        // coordinate's digits by prefix must keep that for every split of m.
+        let m = 8;
+        let points = Sobol::scrambled(2, &mut default_rng(49))
+            .unwrap()
+            .random_base2(m)
+            .unwrap();
+        for a in 0..=m {
+            let (rows, cols) = (1usize << a, 1usize << (m - a));
+            let mut cells: Vec<usize> = points
+                .rows()
+                .into_iter()
+                .map(|p| (p[0] * rows as f64) as usize * cols + (p[1] * cols as f64) as usize)
+                .collect();
+            cells.sort();
+            assert_eq!(cells, (0..1 << m).collect::<Vec<_>>(), "split {}", a);
+        }
+
+        // A nested scramble maps numbers sharing exactly k leading digits to numbers sharing
+        // exactly k leading digits.
+        let mut rng = default_rng(50);
+        for _ in 0..1000 {
+            let seed = rng.next_u64();
+            let (x, y) = (rng.next_u32(), rng.next_u32());
+            let scrambled = owen_scramble(x, seed) ^ owen_scramble(y, seed);
+            assert_eq!(scrambled.leading_zeros(), (x ^ y).leading_zeros());
+        }
+
+        // The numbers below a shared prefix are permuted among themselves, not left in place.
+        let seed = rng.next_u64();
+        let lows: Vec<u32> = (0..64u32)
+            .map(|low| owen_scramble(0x8000_0000 | low, seed) & 0x3f)
+            .collect();
+        let mut sorted = lows.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..64).collect::<Vec<_>>());
+        assert_ne!(lows, (0..64).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_halton() {
+        let mut halton = Halton::new(3).unwrap();
+        let points = halton.random(5);
+        let third = 1.0 / 3.0;
+        let expected = array![
+            [0.0, 0.0, 0.0],
+            [0.5, third, 0.2],
+            [0.25, 2.0 * third, 0.4],
+            [0.75, third / 3.0, 0.6],
+            [0.125, 4.0 * third / 3.0, 0.8]
+        ];
+        assert!(points
+            .iter()
+            .zip(&expected)
+            .all(|(x, y)| (x - y).abs() < 1e-15));
+        assert!(Halton::new(0).is_err());
+        assert_eq!(first_primes(6), vec![2, 3, 5, 7, 11, 13]);
+
+        let mut halton = Halton::scrambled(6, &mut default_rng(8)).unwrap();
+        let points = halton.random(2 * 3 * 5 * 7);
+        assert!(points.iter().all(|&x| (0.0..1.0).contains(&x)));
+        // A scrambled base b coordinate still has one point per interval over b^k points.
+        assert_stratified(&points.slice(ndarray::s![..16, ..1]).to_owned(), 16);
+        assert_stratified(&points.slice(ndarray::s![..27, 1..2]).to_owned(), 27);
+        let centered = discrepancy(&points, DiscrepancyMethod::Centered).unwrap();
+        let mut rng = default_rng(9);
+        let random = Array2::from_shape_fn(points.dim(), |_| rng.gen::<f64>());
+        assert!(centered < discrepancy(&random, DiscrepancyMethod::Centered).unwrap());
+    }
+
+    #[test]
+    fn test_latin_hypercube() {
+        let mut rng = default_rng(5);
+        let sample = latin_hypercube(&mut rng, 50, 4, false);
+        assert_stratified(&sample, 50);
+        let centered = latin_hypercube(&mut rng, 10, 3, true);
+        assert_stratified(&centered, 10);
+        assert!(centered
+            .iter()
+            .all(|&x| ((x * 10.0 - 0.5).round() - (x * 10.0 - 0.5)).abs() < 1e-12));
+        assert_eq!(latin_hypercube(&mut rng, 0, 3, false).dim(), (0, 3));
+    }
+
+    #[test]
+    fn test_discrepancy() {
+        // A single point at the center of the unit interval, worked by hand.
+        let center = array![[0.5]];
+        let value = |method| discrepancy(&center, method).unwrap();
+        assert!((value(DiscrepancyMethod::Centered) - (13.0 / 12.0 - 2.0 + 1.0)).abs() < 1e-15);
+        assert!((value(DiscrepancyMethod::WrapAround) - (1.5 - 4.0 / 3.0)).abs() < 1e-15);
+        assert!(
+            (value(DiscrepancyMethod::Mixture) - (19.0 / 12.0 - 10.0 / 3.0 + 15.0 / 8.0)).abs()
+                < 1e-15
+        );
+        let l2_star = (1.0f64 / 3.0 - 0.75 + 0.5).sqrt();
+        assert!((value(DiscrepancyMethod::L2Star) - l2_star).abs() < 1e-15);
+
+        // Sobol points cover the square more evenly than uniform draws.
+        let sobol = Sobol::new(2).unwrap().random(256).unwrap();
+        let mut rng = default_rng(6);
+        let random = Array2::from_shape_fn((256, 2), |_| rng.gen::<f64>());
+        for method in [
+            DiscrepancyMethod::Centered,
+            DiscrepancyMethod::WrapAround,
+            DiscrepancyMethod::Mixture,
+            DiscrepancyMethod::L2Star,
+        ] {
+            assert!(discrepancy(&sobol, method).unwrap() < discrepancy(&random, method).unwrap());
+        }
+
+        assert!(discrepancy(&array![[0.5, 1.5]], DiscrepancyMethod::Centered).is_err());
+        assert!(discrepancy(&Array2::<f64>::zeros((0, 2)), DiscrepancyMethod::Centered).is_err());
+    }
+}
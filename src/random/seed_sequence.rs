@@ -0,0 +1,350 @@
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use super::{BitGenerator, Generator, Pcg64, Pcg64Dxsm, Philox, Sfc64, Xoshiro256StarStar};
+
+const DEFAULT_POOL_SIZE: usize = 4;
+const INIT_A: u32 = 0x43b0_d7e5;
+const MULT_A: u32 = 0x931e_8875;
+const INIT_B: u32 = 0x8b51_f9dd;
+const MULT_B: u32 = 0x58f3_8ded;
+const MIX_MULT_L: u32 = 0xca01_f9dd;
+const MIX_MULT_R: u32 = 0x4973_f715;
+const XSHIFT: u32 = 16;
+
+/// Hashes `value` with the running constant `hash_const`, advancing the constant.
+fn hashmix(value: u32, hash_const: &mut u32) -> u32 {
+    let mut value = value ^ *hash_const;
+    *hash_const = hash_const.wrapping_mul(MULT_A);
+    value = value.wrapping_mul(*hash_const);
+    value ^ (value >> XSHIFT)
+}
+
+fn mix(x: u32, y: u32) -> u32 {
+    let result = MIX_MULT_L
+        .wrapping_mul(x)
+        .wrapping_sub(MIX_MULT_R.wrapping_mul(y));
+    result ^ (result >> XSHIFT)
+}
+
+/// Splits `value` into little-endian 32-bit words, with zero as a single zero word.
+fn int_to_words(mut value: u128) -> Vec<u32> {
+    let mut words = vec![value as u32];
+    value >>= 32;
+    while value > 0 {
+        words.push(value as u32);
+        value >>= 32;
+    }
+    words
+}
+
+/// Turns entropy of any quality into well-mixed seeds for bit generators, using the algorithm
+/// of NumPy's `SeedSequence`.
+///
+/// Children created by `spawn` extend the spawn key of their parent, so every child, grandchild
+/// and so on yields a different, non-overlapping stream for the same root entropy.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::{Generator, Pcg64, SeedSequence, SeedableBitGenerator};
+///
+/// let mut root = SeedSequence::new(12345);
+/// let children = root.spawn(2);
+/// let mut a = Generator::new(Pcg64::from_seed_sequence(&children[0]));
+/// let mut b = Generator::new(Pcg64::from_seed_sequence(&children[1]));
+/// assert_ne!(a.random(&[1]), b.random(&[1]));
+///
+/// // The same entropy and spawn key always give the same seeds.
+/// let mut again = SeedSequence::new(12345);
+/// assert_eq!(again.spawn(2), children);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedSequence {
+    entropy: Vec<u32>,
+    spawn_key: Vec<u32>,
+    pool: [u32; DEFAULT_POOL_SIZE],
+    n_children_spawned: u32,
+}
+
+impl SeedSequence {
+    /// Creates a seed sequence from an integer seed, as `SeedSequence(entropy)` does in NumPy.
+    pub fn new(entropy: u128) -> Self {
+        Self::from_words(&int_to_words(entropy), &[])
+    }
+
+    /// Creates a seed sequence from 128 bits of operating system entropy. Use `entropy` to
+    /// record the seed so that the run can be reproduced.
+    pub fn from_os_entropy() -> Self {
+        let mut bytes = [0u8; 16];
+        OsRng.fill_bytes(&mut bytes);
+        Self::new(u128::from_le_bytes(bytes))
+    }
+
+    /// Creates a seed sequence from entropy given as 32-bit words and a spawn key, matching
+    /// NumPy's `SeedSequence(entropy, spawn_key=spawn_key)` for sequences of 32-bit integers.
+    pub fn from_words(entropy: &[u32], spawn_key: &[u32]) -> Self {
+        let mut assembled = entropy.to_vec();
+        // Pad the entropy so that it cannot collide with a spawn key.
+        if !spawn_key.is_empty() && assembled.len() < DEFAULT_POOL_SIZE {
+            assembled.resize(DEFAULT_POOL_SIZE, 0);
+        }
+        assembled.extend_from_slice(spawn_key);
+
+        let mut pool = [0u32; DEFAULT_POOL_SIZE];
+        let mut hash_const = INIT_A;
+        for (i, slot) in pool.iter_mut().enumerate() {
+            *slot = hashmix(assembled.get(i).copied().unwrap_or(0), &mut hash_const);
+        }
+        for src in 0..DEFAULT_POOL_SIZE {
+            for dst in 0..DEFAULT_POOL_SIZE {
+                if src != dst {
+                    pool[dst] = mix(pool[dst], hashmix(pool[src], &mut hash_const));
+                }
+            }
+        }
+        for &word in assembled.iter().skip(DEFAULT_POOL_SIZE) {
+            for slot in pool.iter_mut() {
+                *slot = mix(*slot, hashmix(word, &mut hash_const));
+            }
+        }
+
+        SeedSequence {
+            entropy: entropy.to_vec(),
+            spawn_key: spawn_key.to_vec(),
+            pool,
+            n_children_spawned: 0,
+        }
+    }
+
+    /// Returns the root entropy as 32-bit words.
+    pub fn entropy(&self) -> &[u32] {
+        &self.entropy
+    }
+
+    /// Returns the spawn key that distinguishes this sequence from its siblings.
+    pub fn spawn_key(&self) -> &[u32] {
+        &self.spawn_key
+    }
+
+    /// Returns the number of children spawned so far.
+    pub fn n_children_spawned(&self) -> u32 {
+        self.n_children_spawned
+    }
+
+    /// Creates `n` child sequences. Later calls continue the numbering, so children are never
+    /// repeated.
+    pub fn spawn(&mut self, n: u32) -> Vec<SeedSequence> {
+        let start = self.n_children_spawned;
+        self.n_children_spawned += n;
+        (start..start + n)
+            .map(|i| {
+                let mut key = self.spawn_key.clone();
+                key.push(i);
+                SeedSequence::from_words(&self.entropy, &key)
+            })
+            .collect()
+    }
+
+    /// Returns `n` 32-bit words of seed material.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numrust::random::SeedSequence;
+    ///
+    /// let seq = SeedSequence::new(0);
+    /// assert_eq!(seq.generate_state_u32(8)[..4], seq.generate_state_u32(4));
+    /// ```
+    pub fn generate_state_u32(&self, n: usize) -> Vec<u32> {
+        let mut hash_const = INIT_B;
+        self.pool
+            .iter()
+            .cycle()
+            .take(n)
+            .map(|&word| {
+                let mut value = word ^ hash_const;
+                hash_const = hash_const.wrapping_mul(MULT_B);
+                value = value.wrapping_mul(hash_const);
+                value ^ (value >> XSHIFT)
+            })
+            .collect()
+    }
+
+    /// Returns `n` 64-bit words of seed material, each made of two 32-bit words with the low
+    /// word first.
+    pub fn generate_state_u64(&self, n: usize) -> Vec<u64> {
+        self.generate_state_u32(2 * n)
+            .chunks_exact(2)
+            .map(|pair| pair[0] as u64 | ((pair[1] as u64) << 32))
+            .collect()
+    }
+}
+
+/// A bit generator that can be seeded from a `SeedSequence`.
+pub trait SeedableBitGenerator: BitGenerator + Sized {
+    /// Creates a bit generator seeded by `seed_sequence`, as NumPy does for its bit generator
+    /// of the same name.
+    fn from_seed_sequence(seed_sequence: &SeedSequence) -> Self;
+}
+
+impl SeedableBitGenerator for Pcg64 {
+    fn from_seed_sequence(seed_sequence: &SeedSequence) -> Self {
+        let [s0, s1, s2, s3] = words::<4>(seed_sequence);
+        Pcg64::new(
+            ((s0 as u128) << 64) | s1 as u128,
+            ((s2 as u128) << 64) | s3 as u128,
+        )
+    }
+}
+
+impl SeedableBitGenerator for Pcg64Dxsm {
+    fn from_seed_sequence(seed_sequence: &SeedSequence) -> Self {
+        let [s0, s1, s2, s3] = words::<4>(seed_sequence);
+        Pcg64Dxsm::new(
+            ((s0 as u128) << 64) | s1 as u128,
+            ((s2 as u128) << 64) | s3 as u128,
+        )
+    }
+}
+
+impl SeedableBitGenerator for Philox {
+    fn from_seed_sequence(seed_sequence: &SeedSequence) -> Self {
+        Philox::new([0; 4], words::<2>(seed_sequence))
+    }
+}
+
+impl SeedableBitGenerator for Sfc64 {
+    fn from_seed_sequence(seed_sequence: &SeedSequence) -> Self {
+        Sfc64::new(words::<3>(seed_sequence))
+    }
+}
+
+impl SeedableBitGenerator for Xoshiro256StarStar {
+    fn from_seed_sequence(seed_sequence: &SeedSequence) -> Self {
+        let mut state = words::<4>(seed_sequence);
+        // An all-zero state is invalid. It has probability 2^-256, but avoid it all the same.
+        if state == [0; 4] {
+            state[0] = 1;
+        }
+        Xoshiro256StarStar::new(state)
+    }
+}
+
+fn words<const N: usize>(seed_sequence: &SeedSequence) -> [u64; N] {
+    seed_sequence
+        .generate_state_u64(N)
+        .try_into()
+        .expect("exactly N words were generated")
+}
+
+/// Returns one generator for each thread of the current rayon pool, each seeded from its own
+/// child of `seed_sequence`, so parallel workers draw from independent streams.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::random::{worker_generators, Pcg64, SeedSequence};
+/// use rayon::prelude::*;
+///
+/// let mut seed = SeedSequence::new(2024);
+/// let mut generators = worker_generators::<Pcg64>(&mut seed);
+/// assert_eq!(generators.len(), rayon::current_num_threads());
+///
+/// // Split the work into one chunk per generator.
+/// let per_worker = 1000;
+/// let inside: usize = generators
+///     .par_iter_mut()
+///     .map(|rng| {
+///         let x = rng.random(&[per_worker]);
+///         let y = rng.random(&[per_worker]);
+///         x.iter().zip(&y).filter(|(x, y)| *x * *x + *y * *y < 1.0).count()
+///     })
+///     .sum();
+/// let pi = 4.0 * inside as f64 / (per_worker * generators.len()) as f64;
+/// assert!((pi - std::f64::consts::PI).abs() < 0.2);
+/// ```
+pub fn worker_generators<B: SeedableBitGenerator>(
+    seed_sequence: &mut SeedSequence,
+) -> Vec<Generator<B>> {
+    let workers = rayon::current_num_threads() as u32;
+    seed_sequence
+        .spawn(workers)
+        .iter()
+        .map(|child| Generator::new(B::from_seed_sequence(child)))
+        .collect()
+}
+
+#[cfg(test)]
+mod numrust_seed_sequence_tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_data() {
+        // From NumPy's test suite, which checks against the C++ reference implementation.
+        let seq = SeedSequence::from_words(&[3735928559, 195939070, 229505742, 305419896], &[]);
+        assert_eq!(
+            seq.generate_state_u32(4),
+            vec![3914649087, 576849849, 3593928901, 2229911004]
+        );
+    }
+
+    #[test]
+    fn test_entropy_words_and_spawn_keys() {
+        assert_eq!(int_to_words(0), vec![0]);
+        assert_eq!(int_to_words(1 << 32), vec![0, 1]);
+        assert_eq!(SeedSequence::new(7), SeedSequence::from_words(&[7], &[]));
+
+        let mut root = SeedSequence::new(99);
+        let first = root.spawn(2);
+        let second = root.spawn(1);
+        assert_eq!(root.n_children_spawned(), 3);
+        assert_eq!(first[1].spawn_key(), &[1]);
+        assert_eq!(second[0].spawn_key(), &[2]);
+        assert_eq!(first[0].entropy(), &[99]);
+
+        // Padding keeps a spawned child distinct from a root with the key as extra entropy.
+        let child = &first[0];
+        let lookalike = SeedSequence::from_words(&[99, 0], &[]);
+        assert_ne!(child.generate_state_u32(4), lookalike.generate_state_u32(4));
+
+        let grandchildren = first[0].clone().spawn(1);
+        assert_eq!(grandchildren[0].spawn_key(), &[0, 0]);
+
+        let states: Vec<Vec<u32>> = [&first[0], &first[1], &second[0], &grandchildren[0]]
+            .iter()
+            .map(|s| s.generate_state_u32(4))
+            .collect();
+        for i in 0..states.len() {
+            for j in (i + 1)..states.len() {
+                assert_ne!(states[i], states[j]);
+            }
+        }
+
+        let words = child.generate_state_u32(4);
+        assert_eq!(
+            child.generate_state_u64(2),
+            vec![
+                words[0] as u64 | (words[1] as u64) << 32,
+                words[2] as u64 | (words[3] as u64) << 32
+            ]
+        );
+    }
+
+    #[test]
+    fn test_worker_generators_are_independent() {
+        let mut seed = SeedSequence::new(5);
+        let generators = worker_generators::<Philox>(&mut seed);
+        assert_eq!(generators.len(), rayon::current_num_threads());
+        let mut again = worker_generators::<Philox>(&mut SeedSequence::new(5));
+        let mut generators = generators;
+        for (a, b) in generators.iter_mut().zip(again.iter_mut()) {
+            assert_eq!(a.random(&[4]), b.random(&[4]));
+        }
+        let firsts: Vec<u64> = generators.iter_mut().map(|g| g.next_u64()).collect();
+        let mut distinct = firsts.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), firsts.len());
+    }
+}
@@ -0,0 +1,299 @@
+//! The tables of NumPy's 256-strip ziggurat for the standard normal distribution.
+//!
+//! They were computed in double precision from Marsaglia and Tsang's recursion, with the
+//! rightmost strip starting at `ZIGGURAT_NOR_R` and every strip having area
+//! `0.00492867323397465524494`. `WI[i]` is the right edge of strip `i` scaled by `2^-52`,
+//! `KI[i]` is the ratio of the right edges of strips `i - 1` and `i` scaled by `2^52`, and `FI[i]`
+//! is the density at the right edge of strip `i`. Strip 0 is the base strip and its tail.
+//!
+//! NumPy's own tables come from the same recursion, but some entries differ from these in the
+//! last bits.
+
+use super::BitGenerator;
+
+/// The start of the tail of the base strip.
+pub(super) const ZIGGURAT_NOR_R: f64 = 3.654_152_885_361_009;
+
+/// The reciprocal of `ZIGGURAT_NOR_R`.
+pub(super) const ZIGGURAT_NOR_INV_R: f64 = 0.273_661_237_329_758_3;
+
+#[rustfmt::skip]
+pub(super) const KI: [u64; 256] = [
+    0x000ef33d8025ef67, 0x0000000000000000, 0x000c08be98fbc670, 0x000da354fabd812b,
+    0x000e51f67ec1eedf, 0x000eb255e9d3f777, 0x000eef4b817ecab3, 0x000f19470afa44a7,
+    0x000f37ed61ffcb14, 0x000f4f469561255a, 0x000f61a5e41ba395, 0x000f707a755396a2,
+    0x000f7cb2ec28449a, 0x000f86f10c6357d1, 0x000f8fa6578325dd, 0x000f9724c74dd0da,
+    0x000f9da907dbf508, 0x000fa360f581fa71, 0x000fa86fde5b4bf7, 0x000facf160d354db,
+    0x000fb0fb6718b90e, 0x000fb49f8d5374c5, 0x000fb7ec2366fe77, 0x000fbaece9a1e50c,
+    0x000fbdab9d040bee, 0x000fc03060ff6c57, 0x000fc2821037a248, 0x000fc4a67ae25bd1,
+    0x000fc6a2977aee2f, 0x000fc87aa92896a4, 0x000fca325e4bde85, 0x000fcbcce902231a,
+    0x000fcd4d12f839c4, 0x000fceb54d8fec99, 0x000fd007bf1dc930, 0x000fd1464dd6c4e5,
+    0x000fd272a8e2f450, 0x000fd38e4ff0c91e, 0x000fd49a9990b479, 0x000fd598b8920f53,
+    0x000fd689c08e99ec, 0x000fd76ea9c8e831, 0x000fd848547b08e8, 0x000fd9178bad2c8b,
+    0x000fd9dd07a7add2, 0x000fda9970105e8b, 0x000fdb4d5dc02e1f, 0x000fdbf95c5bfcd1,
+    0x000fdc9debb99a7d, 0x000fdd3b8118729d, 0x000fddd288342f90, 0x000fde6364369f63,
+    0x000fdeee708d514f, 0x000fdf7401a6b42e, 0x000fdff46599ed3f, 0x000fe06fe4bc24f2,
+    0x000fe0e6c225a259, 0x000fe1593c28b84c, 0x000fe1c78cbc3f99, 0x000fe231e9db1ca9,
+    0x000fe29885da1b92, 0x000fe2fb8fb54186, 0x000fe35b33558d4a, 0x000fe3b799d0002a,
+    0x000fe410e99ead7e, 0x000fe46746d47734, 0x000fe4bad34c095b, 0x000fe50baed29524,
+    0x000fe559f74ebc76, 0x000fe5a5c8e41211, 0x000fe5ef3e138689, 0x000fe6366fd91078,
+    0x000fe67b75c6d578, 0x000fe6be661e11aa, 0x000fe6ff55e5f4f2, 0x000fe73e5900a702,
+    0x000fe77b823e9e39, 0x000fe7b6e37070a1, 0x000fe7f08d774243, 0x000fe8289053f08c,
+    0x000fe85efb35173a, 0x000fe893dc840864, 0x000fe8c741f0cebc, 0x000fe8f9387d4ef6,
+    0x000fe929cc879b1d, 0x000fe95909d388eb, 0x000fe986fb939aa1, 0x000fe9b3ac714865,
+    0x000fe9df2694b6d5, 0x000fea0973abe67b, 0x000fea329cf166a4, 0x000fea5aab32952d,
+    0x000fea81a6d57419, 0x000feaa797de1cef, 0x000feacc85f3d91f, 0x000feaf07865e63c,
+    0x000feb13762fec12, 0x000feb3585fe2a4b, 0x000feb56ae3162b4, 0x000feb76f4e284f9,
+    0x000feb965fe62013, 0x000febb4f4cf9d7c, 0x000febd2b8f449cf, 0x000febefb16e2e3d,
+    0x000fec0be31ebde8, 0x000fec2752b15a14, 0x000fec42049dafd3, 0x000fec5bfd29f196,
+    0x000fec75406ceef4, 0x000fec8dd2500cb4, 0x000feca5b6911f10, 0x000fecbcf0c427fe,
+    0x000fecd38454fb15, 0x000fece97488c8b3, 0x000fecfec47f91b7, 0x000fed1377358528,
+    0x000fed278f844903, 0x000fed3b10242f4c, 0x000fed4dfbad586e, 0x000fed605498c3dd,
+    0x000fed721d414fe8, 0x000fed8357e4a982, 0x000fed9406a42cc8, 0x000feda42b85b704,
+    0x000fedb3c8746ab3, 0x000fedc2df416652, 0x000fedd171a46e52, 0x000feddf813c8ad3,
+    0x000feded0f90997f, 0x000fedfa1e0fd414, 0x000fee06ae124bc4, 0x000fee12c0d95a06,
+    0x000fee1e579006e0, 0x000fee29734b6524, 0x000fee34150ae4bb, 0x000fee3e3db89b3c,
+    0x000fee47ee2982f3, 0x000fee51271db086, 0x000fee59e9407f41, 0x000fee623528b42d,
+    0x000fee6a0b5897f1, 0x000fee716c3e077a, 0x000fee7858327b81, 0x000fee7ecf7b06b9,
+    0x000fee84d2484ab2, 0x000fee8a60b66343, 0x000fee8f7accc851, 0x000fee94207e25da,
+    0x000fee9851a829ea, 0x000fee9c0e13485b, 0x000fee9f557273f3, 0x000feea22762ccae,
+    0x000feea4836b42ab, 0x000feea668fc2d71, 0x000feea7d76ed6f9, 0x000feea8ce04fa0a,
+    0x000feea94be8333b, 0x000feea95029640f, 0x000feea8d9c0075d, 0x000feea7e7897654,
+    0x000feea678481d24, 0x000feea48aa29e83, 0x000feea21d22e4d9, 0x000fee9f2e352024,
+    0x000fee9bbc26af2e, 0x000fee97c524f2e4, 0x000fee93473c0a39, 0x000fee8e40557515,
+    0x000fee88ae369c79, 0x000fee828e7f3dfd, 0x000fee7bdea7b887, 0x000fee749bff37ff,
+    0x000fee6cc3a9bd5e, 0x000fee64529e007e, 0x000fee5b45a32889, 0x000fee51994e57b6,
+    0x000fee474a0006cf, 0x000fee3c53e12c4f, 0x000fee30b2e02ad7, 0x000fee2462ad8205,
+    0x000fee175eb83c5a, 0x000fee09a22a1447, 0x000fedfb27e349cc, 0x000fedebea76216c,
+    0x000feddbe422047e, 0x000fedcb0ece39d3, 0x000fedb964042cf4, 0x000feda6dce938c9,
+    0x000fed937237e98d, 0x000fed7f1c38a836, 0x000fed69d2b9c02b, 0x000fed538d06adff,
+    0x000fed3c41dea422, 0x000fed23e76a2fd7, 0x000fed0a732fe643, 0x000fecefda07fe34,
+    0x000fecd4100eb7b8, 0x000fecb708956eb4, 0x000fec98b61230c1, 0x000fec790a0da978,
+    0x000fec57f50f31fe, 0x000fec356686c961, 0x000fec114cb4b335, 0x000febeb948e6fd0,
+    0x000febc429a0b691, 0x000feb9af5ee0cdc, 0x000feb6fe1c98542, 0x000feb42d3ad1f9e,
+    0x000feb13b00b2d4b, 0x000feae2591a02e9, 0x000feaaeae992257, 0x000fea788d8ee326,
+    0x000fea3fcffd73e5, 0x000fea044c8dd9f6, 0x000fe9c5d62f563b, 0x000fe9843ba947a3,
+    0x000fe93f471d4729, 0x000fe8f6bd76c5d6, 0x000fe8aa5dc4e8e6, 0x000fe859e07ab1ea,
+    0x000fe804f690a940, 0x000fe7ab488233bf, 0x000fe74c751f6aa5, 0x000fe6e8102aa202,
+    0x000fe67da0b6abd8, 0x000fe60c9f38307e, 0x000fe5947338f742, 0x000fe51470977280,
+    0x000fe48bd436f458, 0x000fe3f9bffd1e37, 0x000fe35d35eeb19b, 0x000fe2b5122fe4fc,
+    0x000fe20003995557, 0x000fe13c82788314, 0x000fe068c4ee67af, 0x000fdf82b02b71aa,
+    0x000fde87c57efeaa, 0x000fdd7509c63bfd, 0x000fdc46e529bf13, 0x000fdaf8f82e0282,
+    0x000fd985e1b2ba75, 0x000fd7e6ef48cf03, 0x000fd613adbd650b, 0x000fd40149e2f012,
+    0x000fd1a1a7b4c7ac, 0x000fcee204761f9e, 0x000fcba8d85e11b1, 0x000fc7d26ecd2d22,
+    0x000fc32b2f1e22ed, 0x000fbd6581c0b83a, 0x000fb606c4005434, 0x000fac40582a2873,
+    0x000f9e971e014597, 0x000f89fa48a41dfc, 0x000f66c5f7f0302c, 0x000f1a5a4b331c4a,
+];
+
+#[rustfmt::skip]
+pub(super) const WI: [f64; 256] = [
+    8.683627060801312e-16, 4.779330175727584e-17, 6.354352417405164e-17,
+    7.454870481247626e-17, 8.329366815793043e-17, 9.068060405059435e-17,
+    9.714860076567725e-17, 1.0294750314240985e-16, 1.0823430288447656e-16,
+    1.1311470196109004e-16, 1.1766359457022894e-16, 1.2193617278714339e-16,
+    1.2597439914637066e-16, 1.2981099886264007e-16, 1.3347203736824098e-16,
+    1.3697864842571179e-16, 1.4034823001242357e-16, 1.4359529452056923e-16,
+    1.4673208742364402e-16, 1.4976904668391022e-16, 1.5271515003596186e-16,
+    1.5557818169460754e-16, 1.5836494009290876e-16, 1.610814017527492e-16,
+    1.6373285203969843e-16, 1.6632399058420823e-16, 1.6885901708676584e-16,
+    1.7134170176559646e-16, 1.737754436586485e-16, 1.7616331923000989e-16,
+    1.785081231697672e-16, 1.8081240285799142e-16, 1.8307848764826743e-16,
+    1.853085138861801e-16, 1.8750444639373874e-16, 1.8966809700774752e-16,
+    1.9180114064838612e-16, 1.9390512930625096e-16, 1.9598150426628815e-16,
+    1.9803160683128162e-16, 2.0005668776273318e-16, 2.0205791562071642e-16,
+    2.04036384154802e-16, 2.0599311887403696e-16, 2.0792908290414007e-16,
+    2.0984518222370342e-16, 2.1174227035760334e-16, 2.1362115259449858e-16,
+    2.1548258978581448e-16, 2.1732730177564358e-16, 2.191559705042726e-16,
+    2.209692428223531e-16, 2.2276773304789544e-16, 2.2455202529414345e-16,
+    2.263226755928567e-16, 2.280802138345016e-16, 2.298251455442467e-16,
+    2.3155795351040784e-16, 2.3327909928004336e-16, 2.3498902453470935e-16,
+    2.366881523579158e-16, 2.383768884045422e-16, 2.400556219813504e-16,
+    2.4172472704675e-16, 2.433845631371101e-16, 2.4503547622614934e-16,
+    2.4667779952327035e-16, 2.483118542161086e-16, 2.499379501620452e-16,
+    2.5155638653296574e-16, 2.531674524171358e-16, 2.5477142738169437e-16,
+    2.563685819989396e-16, 2.5795917833928662e-16, 2.595434704335169e-16,
+    2.611217047067018e-16, 2.626941203859724e-16, 2.6426094988411885e-16,
+    2.658224191608306e-16, 2.6737874806323623e-16, 2.689301506472615e-16,
+    2.704768354811994e-16, 2.720190059327731e-16, 2.735568604408678e-16,
+    2.7509059277301657e-16, 2.766203922696389e-16, 2.7814644407595426e-16,
+    2.7966892936242286e-16, 2.8118802553450193e-16, 2.8270390643244778e-16,
+    2.8421674252184046e-16, 2.8572670107545995e-16, 2.872339463470978e-16,
+    2.88738639737848e-16, 2.9024093995538404e-16, 2.9174100316669436e-16,
+    2.93238983144718e-16, 2.947350314092933e-16, 2.9622929736280645e-16,
+    2.9772192842090274e-16, 2.9921307013860116e-16, 3.0070286633213296e-16,
+    3.0219145919680605e-16, 3.036789894211801e-16, 3.051655962978218e-16,
+    3.066514178308954e-16, 3.0813659084082967e-16, 3.096212510662922e-16,
+    3.1110553326368925e-16, 3.1258957130439984e-16, 3.140734982699446e-16,
+    3.1555744654528006e-16, 3.1704154791040285e-16, 3.1852593363044065e-16,
+    3.2001073454440114e-16, 3.214960811527447e-16, 3.2298210370394156e-16,
+    3.244689322801698e-16, 3.2595669688230784e-16, 3.2744552751437067e-16,
+    3.2893555426753697e-16, 3.3042690740391284e-16, 3.3191971744017523e-16,
+    3.3341411523123725e-16, 3.3491023205407785e-16, 3.364081996918765e-16,
+    3.37908150518595e-16, 3.394102175841489e-16, 3.409145347003126e-16,
+    3.424212365275018e-16, 3.4393045866258313e-16, 3.454423377278584e-16,
+    3.4695701146137835e-16, 3.4847461880874137e-16, 3.499953000165381e-16,
+    3.5151919672760744e-16, 3.53046452078274e-16, 3.5457721079774357e-16,
+    3.5611161930983884e-16, 3.5764982583726505e-16, 3.59191980508603e-16,
+    3.6073823546823514e-16, 3.6228874498941915e-16, 3.6384366559073444e-16,
+    3.65403156156137e-16, 3.669673780588701e-16, 3.685364952894914e-16,
+    3.7011067458828983e-16, 3.716900855823823e-16, 3.7327490092779435e-16,
+    3.7486529645684887e-16, 3.7646145133120287e-16, 3.7806354820089604e-16,
+    3.7967177336979443e-16, 3.8128631696783774e-16, 3.829073731305243e-16,
+    3.8453514018609596e-16, 3.8616982085091493e-16, 3.878116224335587e-16,
+    3.894607570481926e-16, 3.9111744183782054e-16, 3.9278189920805415e-16,
+    3.944543570720877e-16, 3.9613504910761354e-16, 3.9782421502646826e-16,
+    3.9952210085785645e-16, 4.012289592460629e-16, 4.029450497636328e-16,
+    4.04670639241075e-16, 4.0640600211422504e-16, 4.0815142079049387e-16,
+    4.0990718603532664e-16, 4.1167359738030257e-16, 4.134509635544236e-16,
+    4.152396029402688e-16, 4.1703984405683154e-16, 4.188520260710112e-16,
+    4.206764993399015e-16, 4.2251362598620494e-16, 4.243637805093078e-16,
+    4.262273504347798e-16, 4.2810473700531167e-16, 4.2999635591638323e-16,
+    4.3190263810026294e-16, 4.338240305622791e-16, 4.357609972736849e-16,
+    4.3771402012585875e-16, 4.3968359995105214e-16, 4.4167025761542035e-16,
+    4.4367453519065673e-16, 4.456969972112043e-16, 4.477382320247534e-16,
+    4.49798853244555e-16, 4.518795013130059e-16, 4.539808451870034e-16,
+    4.561035841567422e-16, 4.582484498109567e-16, 4.604162081631153e-16,
+    4.626076619547846e-16, 4.648236531543207e-16, 4.670650656712631e-16,
+    4.693328283093329e-16, 4.716279179838351e-16, 4.739513632325867e-16,
+    4.763042480533137e-16, 4.786877161048723e-16, 4.811029753147417e-16,
+    4.835513029411525e-16, 4.860340511450812e-16, 4.885526531353603e-16,
+    4.91108629959527e-16, 4.937035980240335e-16, 4.963392774403987e-16,
+    4.990175013091822e-16, 5.017402260718089e-16, 5.045095430818727e-16,
+    5.073276915733541e-16, 5.101970732341561e-16, 5.131202686306783e-16,
+    5.161000557743227e-16, 5.191394311757698e-16, 5.222416338000233e-16,
+    5.254101724177596e-16, 5.286488569504943e-16, 5.319618345338398e-16,
+    5.353536311816495e-16, 5.388292001334051e-16, 5.42393978220171e-16,
+    5.460539519074778e-16, 5.498157350892812e-16, 5.536866612467874e-16,
+    5.576748932926575e-16, 5.617895553555416e-16, 5.660408920082422e-16,
+    5.704404621291389e-16, 5.750013768919895e-16, 5.797385945724594e-16,
+    5.846692893455479e-16, 5.898133176477899e-16, 5.951938149641444e-16,
+    6.008379696271908e-16, 6.067780409333449e-16, 6.130527208725282e-16,
+    6.197089894581626e-16, 6.268046963301284e-16, 6.344122407127506e-16,
+    6.426239659548055e-16, 6.515603317344994e-16, 6.613827885097664e-16,
+    6.723150462505587e-16, 6.846803417564259e-16, 6.98971833638762e-16,
+    7.159994934830664e-16, 7.372424301798798e-16, 7.658936370805572e-16,
+    8.113849337656484e-16,
+];
+
+#[rustfmt::skip]
+pub(super) const FI: [f64; 256] = [
+    1.0, 0.977101701267673, 0.9598790918001079,
+    0.9451989534423006, 0.9320600759592313, 0.9199915050393478,
+    0.9087264400521315, 0.8980959218983441, 0.8879846607558339,
+    0.878309655808918, 0.8690086880368576, 0.8600336211963321,
+    0.8513462584586785, 0.8429156531122047, 0.834716292986884,
+    0.8267268339462219, 0.8189291916037029, 0.8113078743126567,
+    0.8038494831709647, 0.7965423304229593, 0.7893761435660249,
+    0.7823418326548027, 0.7754313049811874, 0.7686373157984865,
+    0.7619533468367955, 0.7553735065070964, 0.7488924472191572,
+    0.7425052963401514, 0.736207598126863, 0.7299952645614765,
+    0.7238645334686304, 0.7178119326307222, 0.7118342488782486,
+    0.7059285013327545, 0.7000919181365118, 0.6943219161261169,
+    0.688616083004672, 0.6829721616449951, 0.6773880362187737,
+    0.6718617198970824, 0.6663913439087504, 0.6609751477766634,
+    0.6556114705796976, 0.650298743110817, 0.6450354808208226,
+    0.6398202774530568, 0.6346517992876238, 0.6295287799248369,
+    0.6244500155470267, 0.6194143606058345, 0.6144207238889141,
+    0.6094680649257737, 0.604555390697468, 0.5996817526191256,
+    0.5948462437679877, 0.5900479963328262, 0.5852861792633718,
+    0.5805599961007915, 0.5758686829723543, 0.5712115067352538,
+    0.566587763256165, 0.5619967758145251, 0.5574378936187666,
+    0.552910490425833, 0.5484139632552664, 0.5439477311900267,
+    0.5395112342569526, 0.535103932380458, 0.5307253044036623,
+    0.5263748471716846, 0.522052074672322, 0.5177565172297565,
+    0.5134877207473272, 0.5092452459957482, 0.5050286679434685,
+    0.5008375751261491, 0.4966715690524901, 0.4925302636438688,
+    0.4884132847054583, 0.4843202694266836, 0.48025086590904703,
+    0.47620473271950614, 0.4721815384677304, 0.4681809614056939,
+    0.46420268904817463, 0.4602464178128432, 0.45631185267871677,
+    0.45239870686184896, 0.4485067015072034, 0.4446355653957398,
+    0.4407850346658044, 0.436954852547986, 0.43314476911265276,
+    0.4293545410294419, 0.4255839313380224, 0.42183270922949634,
+    0.4181006498378486, 0.4143875340408916, 0.41069314827018866,
+    0.40701728432947376, 0.40335973922111484, 0.39972031498019756,
+    0.39609881851583273, 0.39249506145931584, 0.38890886001878894,
+    0.38534003484007745, 0.38178841087339377, 0.3782538172456193,
+    0.37473608713789125, 0.37123505766823955, 0.3677505697790326,
+    0.36428246812900406, 0.36083060098964803, 0.3573948201457805,
+    0.3539749808000768, 0.3505709414814061, 0.34718256395679364,
+    0.3438097131468507, 0.34045225704452187, 0.33711006663700605,
+    0.33378301583071845, 0.3304709813791636, 0.3271738428136014,
+    0.3238914823763911, 0.32062378495690536, 0.3173706380299136,
+    0.3141319315963372, 0.3109075581262865, 0.30769741250429206,
+    0.30450139197665, 0.30131939610080305, 0.2981513266966855,
+    0.2949970877999618, 0.2918565856170952, 0.2887297284821829,
+    0.28561642681550176, 0.2825165930837076, 0.27943014176163794,
+    0.2763569892956683, 0.27329705406857707, 0.27025025636587546,
+    0.26721651834356147, 0.2641957639972612, 0.2611879191327212,
+    0.25819291133761924, 0.25521066995466196, 0.2522411260559422,
+    0.24928421241852852, 0.24633986350126383, 0.2434080154227503,
+    0.2404886059405006, 0.2375815744312381, 0.23468686187233,
+    0.23180441082433872, 0.22893416541468034, 0.22607607132238028,
+    0.22323007576391748, 0.220396127480152, 0.21757417672433113,
+    0.21476417525117358, 0.21196607630703018, 0.20917983462112508,
+    0.2064054063978808, 0.2036427493103349, 0.2008918224946566,
+    0.19815258654577517, 0.1954250035141343, 0.19270903690358918,
+    0.19000465167046499, 0.1873118142238003, 0.18463049242679927,
+    0.18196065559952251, 0.17930227452284758, 0.17665532144373486,
+    0.1740197700818386, 0.17139559563750584, 0.16878277480121137,
+    0.1661812857644819, 0.16359110823236558, 0.161012223437511,
+    0.15844461415592428, 0.1558882647244792, 0.15334316106026286,
+    0.15080929068184568, 0.14828664273257455, 0.14577520800599403,
+    0.14327497897351346, 0.1407859498144447, 0.13830811644855073,
+    0.13584147657125376, 0.13338602969166916, 0.13094177717364436,
+    0.12850872227999957, 0.1260868702201859, 0.12367622820159657,
+    0.1212768054847903, 0.11888861344291006, 0.11651166562561087,
+    0.11414597782783849, 0.11179156816383809, 0.1094484571468118,
+    0.1071166677746838, 0.10479622562248707, 0.10248715894193525,
+    0.10018949876881002, 0.09790327903886246, 0.095628536713009,
+    0.09336531191269101, 0.09111364806637376, 0.08887359206827589,
+    0.08664519445055807, 0.08442850957035347, 0.0822235958132029,
+    0.08003051581466307, 0.07784933670209612, 0.07568013035892718,
+    0.07352297371398138, 0.07137794905889047, 0.06924514439700682,
+    0.06712465382778857, 0.06501657797124295, 0.06292102443775822,
+    0.06083810834953996, 0.05876795292093387, 0.056710690106203006,
+    0.054666461324889046, 0.05263541827679231, 0.05061772386094789,
+    0.04861355321586865, 0.046623094901930486, 0.04464655225129456,
+    0.04268414491647452, 0.04073611065594099, 0.03880270740452615,
+    0.036884215688567305, 0.034980941461716125, 0.03309321945857858,
+    0.0312214171919203, 0.02936593975813336, 0.027527235669603113,
+    0.02570580400854891, 0.02390220330579588, 0.02211706270730885,
+    0.02035109623004451, 0.018605121275724622, 0.016880083152543142,
+    0.01517708830793531, 0.013497450601739867, 0.011842757857907879,
+    0.010214971439701459, 0.008616582769398726, 0.007050875471373222,
+    0.0055224032992509916, 0.0040379725933630305, 0.002609072746102164,
+    0.001260285930498598,
+];
+
+/// Draws from the standard normal distribution with NumPy's ziggurat method.
+pub(super) fn standard_normal<R: BitGenerator + ?Sized>(rng: &mut R) -> f64 {
+    loop {
+        // The low byte picks a strip, the next bit the sign and the next 52 bits the position.
+        let r = rng.next_u64();
+        let idx = (r & 0xff) as usize;
+        let r = r >> 8;
+        let negative = r & 1 == 1;
+        let rabs = (r >> 1) & 0x000f_ffff_ffff_ffff;
+        let x = rabs as f64 * WI[idx];
+        let x = if negative { -x } else { x };
+        if rabs < KI[idx] {
+            return x;
+        }
+        if idx == 0 {
+            // Sample the tail beyond ZIGGURAT_NOR_R.
+            loop {
+                let xx = -ZIGGURAT_NOR_INV_R * (-rng.next_double()).ln_1p();
+                let yy = -(-rng.next_double()).ln_1p();
+                if yy + yy > xx * xx {
+                    return if (rabs >> 8) & 1 == 1 {
+                        -(ZIGGURAT_NOR_R + xx)
+                    } else {
+                        ZIGGURAT_NOR_R + xx
+                    };
+                }
+            }
+        } else if (FI[idx - 1] - FI[idx]) * rng.next_double() + FI[idx] < (-0.5 * x * x).exp() {
+            return x;
+        }
+    }
+}
@@ -0,0 +1,484 @@
+use std::cmp::Ordering;
+
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension};
+
+use crate::{apply_along_axis, normalize_axis, NumrustError};
+
+/// The sorting algorithm to use, mirroring the `kind` argument of `numpy.sort`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKind {
+    /// A stable merge sort: equal elements keep their relative order.
+    Stable,
+    /// An unstable pattern-defeating quicksort, which is faster and sorts in place.
+    Unstable,
+}
+
+/// Which insertion point [`searchsorted`] returns when a value equals elements of the array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The first suitable index, before any equal elements.
+    Left,
+    /// The last suitable index, after any equal elements.
+    Right,
+}
+
+fn is_nan<A: PartialOrd>(x: &A) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+/// Orders values like NumPy: NaNs (values that do not compare equal to themselves) sort after
+/// every other value and are equal to each other.
+pub(crate) fn nan_last_cmp<A: PartialOrd>(x: &A, y: &A) -> Ordering {
+    match x.partial_cmp(y) {
+        Some(ordering) => ordering,
+        None => match (is_nan(x), is_nan(y)) {
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            _ => Ordering::Equal,
+        },
+    }
+}
+
+/// Returns the permutation that sorts `values` with NaNs last.
+fn sorting_permutation<A: PartialOrd>(values: &[A], kind: SortKind) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..values.len()).collect();
+    let compare = |&i: &usize, &j: &usize| nan_last_cmp(&values[i], &values[j]);
+    match kind {
+        SortKind::Stable => indices.sort_by(compare),
+        SortKind::Unstable => indices.sort_unstable_by(compare),
+    }
+    indices
+}
+
+/// Returns a permutation that places the elements of rank `kth` of `values` at their sorted
+/// positions, with smaller elements before and larger elements after each of them.
+fn partition_permutation<A: PartialOrd>(
+    values: &[A],
+    kth: &[isize],
+) -> Result<Vec<usize>, NumrustError> {
+    let n = values.len();
+    let mut ranks = Vec::with_capacity(kth.len());
+    for &k in kth {
+        let resolved = if k < 0 { k + n as isize } else { k };
+        if resolved < 0 || resolved >= n as isize {
+            return Err(NumrustError::ValueError(format!(
+                "kth(={}) out of bounds ({})",
+                k, n
+            )));
+        }
+        ranks.push(resolved as usize);
+    }
+    ranks.sort_unstable();
+    ranks.dedup();
+
+    let mut indices: Vec<usize> = (0..n).collect();
+    let mut start = 0;
+    for rank in ranks {
+        indices[start..]
+            .select_nth_unstable_by(rank - start, |&i, &j| nan_last_cmp(&values[i], &values[j]));
+        start = rank + 1;
+    }
+    Ok(indices)
+}
+
+/// Returns a sorted copy of `a` along `axis`.
+///
+/// NaN values are sorted to the end, as in NumPy.
+///
+/// # Arguments
+///
+/// * `a` - The array to sort.
+/// * `axis` - The axis to sort along. Negative values count from the last axis.
+/// * `kind` - Whether the sort must be stable.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::sorting::{sort, SortKind};
+///
+/// let a = array![[3.0, f64::NAN, 1.0], [2.0, 0.0, 5.0]];
+/// let rows = sort(&a, -1, SortKind::Stable).unwrap();
+/// assert_eq!(rows.row(1), array![0.0, 2.0, 5.0]);
+/// assert_eq!(rows.row(0).slice(ndarray::s![..2]), array![1.0, 3.0]);
+/// assert!(rows[[0, 2]].is_nan());
+///
+/// let columns = sort(&a, 0, SortKind::Unstable).unwrap();
+/// assert_eq!(columns.row(0), array![2.0, 0.0, 1.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn sort<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: isize,
+    kind: SortKind,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: PartialOrd + Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    apply_along_axis(a, axis, a.to_owned(), |values| {
+        Ok(sorting_permutation(values, kind)
+            .into_iter()
+            .map(|i| values[i].clone())
+            .collect())
+    })
+}
+
+/// Returns the indices that would sort `a` along `axis`.
+///
+/// # Arguments
+///
+/// * `a` - The array to sort.
+/// * `axis` - The axis to sort along. Negative values count from the last axis.
+/// * `kind` - Whether ties must keep their original order.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::sorting::{argsort, SortKind};
+///
+/// let a = array![3, 1, 2, 1];
+/// assert_eq!(argsort(&a, 0, SortKind::Stable).unwrap(), array![1, 3, 2, 0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn argsort<A, S, D>(
+    a: &ArrayBase<S, D>,
+    axis: isize,
+    kind: SortKind,
+) -> Result<Array<usize, D>, NumrustError>
+where
+    A: PartialOrd + Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    apply_along_axis(a, axis, Array::zeros(a.raw_dim()), |values| {
+        Ok(sorting_permutation(values, kind))
+    })
+}
+
+/// Returns a partially sorted copy of `a` along `axis`.
+///
+/// For every rank `k` in `kth`, the element at position `k` of each lane is the one that would be
+/// there if the lane were sorted; every element before it is no greater and every element after
+/// it is no smaller. The order within those groups is unspecified.
+///
+/// # Arguments
+///
+/// * `a` - The array to partition.
+/// * `kth` - The ranks to place. Negative values count from the end of the lane.
+/// * `axis` - The axis to partition along. Negative values count from the last axis.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::sorting::partition;
+///
+/// let a = array![7, 1, 5, 3, 9];
+/// let p = partition(&a, &[2], 0).unwrap();
+/// assert_eq!(p[2], 5);
+/// assert!(p.iter().take(2).all(|&x| x <= 5));
+/// assert!(p.iter().skip(3).all(|&x| x >= 5));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` or any rank in `kth` is out of bounds.
+pub fn partition<A, S, D>(
+    a: &ArrayBase<S, D>,
+    kth: &[isize],
+    axis: isize,
+) -> Result<Array<A, D>, NumrustError>
+where
+    A: PartialOrd + Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    apply_along_axis(a, axis, a.to_owned(), |values| {
+        Ok(partition_permutation(values, kth)?
+            .into_iter()
+            .map(|i| values[i].clone())
+            .collect())
+    })
+}
+
+/// Returns the indices that would partition `a` along `axis`, with the same guarantees as
+/// [`partition`].
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::sorting::argpartition;
+///
+/// let a = array![7, 1, 5, 3, 9];
+/// let idx = argpartition(&a, &[0, -1], 0).unwrap();
+/// assert_eq!(a[idx[0]], 1);
+/// assert_eq!(a[idx[4]], 9);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError` if `axis` or any rank in `kth` is out of bounds.
+pub fn argpartition<A, S, D>(
+    a: &ArrayBase<S, D>,
+    kth: &[isize],
+    axis: isize,
+) -> Result<Array<usize, D>, NumrustError>
+where
+    A: PartialOrd + Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    apply_along_axis(a, axis, Array::zeros(a.raw_dim()), |values| {
+        partition_permutation(values, kth)
+    })
+}
+
+/// Returns the indices that sort several keys lexicographically along `axis`, with the **last**
+/// key as the primary sort key, as in `numpy.lexsort`. The sort is stable.
+///
+/// # Arguments
+///
+/// * `keys` - The sort keys, all of the same shape. `keys[keys.len() - 1]` is compared first,
+///   ties are broken by the key before it, and so on.
+/// * `axis` - The axis to sort along. Negative values count from the last axis.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{array, aview1};
+/// use numrust::sorting::lexsort;
+///
+/// let first_names = ["Heinrich", "Galileo", "Gustav"];
+/// let surnames = ["Hertz", "Galilei", "Hertz"];
+/// let order = lexsort(&[aview1(&first_names), aview1(&surnames)], -1).unwrap();
+/// assert_eq!(order, array![1, 2, 0]);
+///
+/// // Each column sorted by `b`, ties broken by `a`.
+/// let a = array![[3, 1], [1, 2], [2, 0]];
+/// let b = array![[0, 1], [1, 1], [0, 0]];
+/// assert_eq!(lexsort(&[a, b], 0).unwrap(), array![[2, 2], [0, 0], [1, 1]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if `keys` is empty or the keys have different shapes,
+/// and a `NumrustError::AxisError` if `axis` is out of bounds.
+pub fn lexsort<A, S, D>(
+    keys: &[ArrayBase<S, D>],
+    axis: isize,
+) -> Result<Array<usize, D>, NumrustError>
+where
+    A: PartialOrd + Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let first = keys.first().ok_or_else(|| {
+        NumrustError::ValueError("need sequence of keys with len > 0 in lexsort".to_string())
+    })?;
+    if keys.iter().any(|key| key.shape() != first.shape()) {
+        return Err(NumrustError::ValueError(
+            "all keys need to be the same shape".to_string(),
+        ));
+    }
+    let axis = Axis(normalize_axis(axis, first.ndim())?);
+    let mut key_lanes: Vec<_> = keys.iter().map(|key| key.lanes(axis).into_iter()).collect();
+    let mut out = Array::zeros(first.raw_dim());
+    for mut out_lane in out.lanes_mut(axis) {
+        let lanes: Vec<Vec<A>> = key_lanes
+            .iter_mut()
+            .map(|lanes| lanes.next().map_or_else(Vec::new, |lane| lane.to_vec()))
+            .collect();
+        let mut indices: Vec<usize> = (0..out_lane.len()).collect();
+        indices.sort_by(|&i, &j| {
+            lanes
+                .iter()
+                .rev()
+                .map(|key| nan_last_cmp(&key[i], &key[j]))
+                .find(|&ordering| ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+        for (o, i) in out_lane.iter_mut().zip(indices) {
+            *o = i;
+        }
+    }
+    Ok(out)
+}
+
+/// Returns the index at which `x` would have to be inserted into the sorted slice `a` to keep
+/// it sorted.
+pub(crate) fn insertion_point<T: PartialOrd>(a: &[T], x: &T, side: Side) -> usize {
+    match side {
+        Side::Left => a.partition_point(|y| nan_last_cmp(y, x) == Ordering::Less),
+        Side::Right => a.partition_point(|y| nan_last_cmp(y, x) != Ordering::Greater),
+    }
+}
+
+/// Finds the indices at which the values `v` would have to be inserted into the sorted slice `a`
+/// to keep it sorted.
+///
+/// # Arguments
+///
+/// * `a` - A slice sorted in ascending order, with any NaNs at the end.
+/// * `v` - The values to insert, of any shape.
+/// * `side` - Whether to return the first (`Left`) or last (`Right`) suitable position when a
+///   value equals elements of `a`.
+///
+/// # Returns
+///
+/// An array of the shape of `v` holding the insertion index of each value.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::sorting::{searchsorted, Side};
+///
+/// let a = [1, 2, 3, 3, 5];
+/// assert_eq!(searchsorted(&a, &array![3, 0, 6], Side::Left), array![2, 0, 5]);
+/// assert_eq!(searchsorted(&a, &array![3, 0, 6], Side::Right), array![4, 0, 5]);
+/// assert_eq!(
+///     searchsorted(&a, &array![[3, 4], [5, 1]], Side::Left),
+///     array![[2, 4], [4, 0]]
+/// );
+/// ```
+pub fn searchsorted<T, S, D>(a: &[T], v: &ArrayBase<S, D>, side: Side) -> Array<usize, D>
+where
+    T: PartialOrd,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    v.map(|x| insertion_point(a, x, side))
+}
+
+#[cfg(test)]
+mod numrust_sorting_tests {
+    use super::*;
+    use ndarray::{array, Array3};
+
+    #[test]
+    fn test_sort_nan_last() {
+        let a = array![f64::NAN, 2.0, -1.0, f64::NAN, 0.5];
+        for kind in [SortKind::Stable, SortKind::Unstable] {
+            let sorted = sort(&a, 0, kind).unwrap();
+            assert_eq!(sorted.slice(ndarray::s![..3]), array![-1.0, 0.5, 2.0]);
+            assert!(sorted[3].is_nan() && sorted[4].is_nan());
+        }
+        let idx = argsort(&a, 0, SortKind::Stable).unwrap();
+        assert_eq!(idx, array![2, 4, 1, 0, 3]);
+    }
+
+    #[test]
+    fn test_sort_along_axes_of_3d_array() {
+        let a =
+            Array3::from_shape_vec((2, 2, 3), vec![3, 1, 2, 9, 8, 7, 0, 5, 4, 6, 6, 1]).unwrap();
+        let last = sort(&a, -1, SortKind::Unstable).unwrap();
+        assert_eq!(
+            last.into_raw_vec(),
+            vec![1, 2, 3, 7, 8, 9, 0, 4, 5, 1, 6, 6]
+        );
+        let middle = sort(&a, 1, SortKind::Stable).unwrap();
+        assert_eq!(
+            middle.into_raw_vec(),
+            vec![3, 1, 2, 9, 8, 7, 0, 5, 1, 6, 6, 4]
+        );
+        let first = argsort(&a, 0, SortKind::Stable).unwrap();
+        assert_eq!(
+            first.into_raw_vec(),
+            vec![1, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0]
+        );
+        assert!(sort(&a, 3, SortKind::Stable).is_err());
+    }
+
+    #[test]
+    fn test_argsort_stable_keeps_ties_in_order() {
+        let a = array![2, 1, 2, 1, 2, 1];
+        assert_eq!(
+            argsort(&a, 0, SortKind::Stable).unwrap(),
+            array![1, 3, 5, 0, 2, 4]
+        );
+    }
+
+    #[test]
+    fn test_partition_multiple_kth() {
+        let a = array![[9.0, f64::NAN, 4.0, 1.0, 7.0, 3.0, 8.0]];
+        let p = partition(&a, &[1, 4], 1).unwrap();
+        let row = p.row(0);
+        assert_eq!(row[1], 3.0);
+        assert_eq!(row[4], 8.0);
+        assert!(row[0] <= 3.0);
+        assert!(row
+            .iter()
+            .skip(2)
+            .take(2)
+            .all(|&x| (3.0..=8.0).contains(&x)));
+        assert!(row.iter().skip(5).all(|&x| x >= 8.0 || x.is_nan()));
+
+        let idx = argpartition(&a, &[-1], -1).unwrap();
+        assert_eq!(idx[[0, 6]], 1);
+        assert!(partition(&a, &[7], 1).is_err());
+    }
+
+    #[test]
+    fn test_lexsort() {
+        let a = array![1, 5, 1, 4, 3, 4, 4];
+        let b = array![9, 4, 0, 4, 0, 2, 1];
+        assert_eq!(
+            lexsort(&[b.view(), a.view()], 0).unwrap(),
+            array![2, 0, 4, 6, 5, 3, 1]
+        );
+        let empty: [ndarray::Array1<i32>; 0] = [];
+        assert!(lexsort(&empty, 0).is_err());
+        assert!(lexsort(&[a.slice(ndarray::s![..2]), b.view()], 0).is_err());
+        assert!(lexsort(&[a.view()], 1).is_err());
+    }
+
+    #[test]
+    fn test_lexsort_along_axes_of_2d_keys() {
+        let primary = array![[2, 1, 2], [1, 1, 0]];
+        let secondary = array![[0.5, 3.0, f64::NAN], [2.0, -1.0, 0.0]];
+        let primary = primary.mapv(f64::from);
+        // Along rows: row 0 sorts by primary [2, 1, 2] with the tie broken by [0.5, NaN].
+        assert_eq!(
+            lexsort(&[secondary.view(), primary.view()], -1).unwrap(),
+            array![[1, 0, 2], [2, 1, 0]]
+        );
+        // Along columns: column 0 has primary [2, 1], column 1 ties on 1 and uses [3, -1].
+        assert_eq!(
+            lexsort(&[secondary.view(), primary.view()], 0).unwrap(),
+            array![[1, 1, 1], [0, 0, 0]]
+        );
+    }
+
+    #[test]
+    fn test_searchsorted_with_nan() {
+        let a = [1.0, 2.0, f64::NAN];
+        assert_eq!(searchsorted(&a, &array![f64::NAN], Side::Left), array![2]);
+        assert_eq!(searchsorted(&a, &array![f64::NAN], Side::Right), array![3]);
+        assert_eq!(
+            searchsorted(&a, &array![1.5, 2.0], Side::Right),
+            array![1, 2]
+        );
+        let empty: [f64; 0] = [];
+        assert_eq!(searchsorted(&empty, &array![1.0], Side::Left), array![0]);
+    }
+
+    #[test]
+    fn test_searchsorted_keeps_the_shape_of_v() {
+        let a = [0.0, 1.0, 2.0, 3.0];
+        let v = Array3::from_shape_fn((2, 1, 3), |(i, _, k)| (i * 3 + k) as f64 * 0.5);
+        let idx = searchsorted(&a, &v, Side::Left);
+        assert_eq!(idx.shape(), &[2, 1, 3]);
+        assert_eq!(idx.into_raw_vec(), vec![0, 1, 1, 2, 2, 3]);
+        assert_eq!(searchsorted(&a, &ndarray::arr0(1.0), Side::Right)[()], 2);
+    }
+}
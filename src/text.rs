@@ -0,0 +1,713 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use ndarray::{Array2, ArrayBase, Data, Dimension};
+
+use crate::NumrustError;
+
+/// Parses one field of a text file, returning `None` if the field is not valid.
+pub type Converter<'a> = &'a dyn Fn(&str) -> Option<f64>;
+
+/// Options for [`loadtxt`], following the keyword arguments of `numpy.loadtxt`.
+#[derive(Clone, Copy)]
+pub struct LoadTxtOptions<'a> {
+    /// The string separating values, or `None` to split on any whitespace.
+    pub delimiter: Option<&'a str>,
+    /// The number of lines to skip at the start of the file, including comment lines.
+    pub skiprows: usize,
+    /// The columns to read, or `None` for all. Negative values count from the last column.
+    pub usecols: Option<&'a [isize]>,
+    /// The string marking the start of a comment, or `None` if the file has no comments.
+    pub comments: Option<&'a str>,
+    /// Custom parsers for the columns with the given indices in the file.
+    pub converters: &'a [(usize, Converter<'a>)],
+}
+
+impl Default for LoadTxtOptions<'_> {
+    fn default() -> Self {
+        LoadTxtOptions {
+            delimiter: None,
+            skiprows: 0,
+            usecols: None,
+            comments: Some("#"),
+            converters: &[],
+        }
+    }
+}
+
+/// Options for [`genfromtxt`], following the keyword arguments of `numpy.genfromtxt`.
+#[derive(Debug, Clone, Copy)]
+pub struct GenFromTxtOptions<'a> {
+    /// The string separating values, or `None` to split on any whitespace.
+    pub delimiter: Option<&'a str>,
+    /// The number of lines to skip at the start of the file.
+    pub skip_header: usize,
+    /// The number of lines to skip at the end of the file.
+    pub skip_footer: usize,
+    /// The columns to read, or `None` for all. Negative values count from the last column.
+    pub usecols: Option<&'a [isize]>,
+    /// The string marking the start of a comment, or `None` if the file has no comments.
+    pub comments: Option<&'a str>,
+    /// Strings that mark a value as missing, in addition to empty fields.
+    pub missing_values: &'a [&'a str],
+    /// The value stored in place of missing values.
+    pub filling_value: f64,
+}
+
+impl Default for GenFromTxtOptions<'_> {
+    fn default() -> Self {
+        GenFromTxtOptions {
+            delimiter: None,
+            skip_header: 0,
+            skip_footer: 0,
+            usecols: None,
+            comments: Some("#"),
+            missing_values: &[],
+            filling_value: f64::NAN,
+        }
+    }
+}
+
+/// The data read by [`genfromtxt`] together with the positions of missing values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenFromTxt {
+    /// The values, with missing values replaced by the filling value.
+    pub data: Array2<f64>,
+    /// `true` where the value was missing or could not be parsed.
+    pub mask: Array2<bool>,
+}
+
+/// Options for [`savetxt`], following the keyword arguments of `numpy.savetxt`.
+#[derive(Debug, Clone, Copy)]
+pub struct SaveTxtOptions<'a> {
+    /// A printf-style format: either a single specifier such as `%.3f` applied to every value,
+    /// or a whole row with one specifier per column, in which case `delimiter` is ignored.
+    pub fmt: &'a str,
+    /// The string written between values.
+    pub delimiter: &'a str,
+    /// The string written at the end of every line.
+    pub newline: &'a str,
+    /// Text written before the data, with every line prefixed by `comments`.
+    pub header: &'a str,
+    /// Text written after the data, with every line prefixed by `comments`.
+    pub footer: &'a str,
+    /// The prefix of the header and footer lines.
+    pub comments: &'a str,
+}
+
+impl Default for SaveTxtOptions<'_> {
+    fn default() -> Self {
+        SaveTxtOptions {
+            fmt: "%.18e",
+            delimiter: " ",
+            newline: "\n",
+            header: "",
+            footer: "",
+            comments: "# ",
+        }
+    }
+}
+
+fn io_error(err: std::io::Error) -> NumrustError {
+    NumrustError::IoError(err.to_string())
+}
+
+/// Splits the data lines of a text file into fields, dropping comments and blank lines.
+fn tokenize<R: BufRead>(
+    reader: R,
+    skip: usize,
+    skip_footer: usize,
+    delimiter: Option<&str>,
+    comments: Option<&str>,
+) -> Result<Vec<(usize, Vec<String>)>, NumrustError> {
+    let lines = reader
+        .lines()
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(io_error)?;
+    let end = lines.len().saturating_sub(skip_footer);
+    let mut rows = Vec::new();
+    for (number, line) in lines.iter().enumerate().take(end).skip(skip) {
+        let content = match comments.and_then(|c| line.find(c)) {
+            Some(start) => &line[..start],
+            None => line.as_str(),
+        };
+        if content.trim().is_empty() {
+            continue;
+        }
+        let fields = match delimiter {
+            Some(d) => content.split(d).map(|f| f.trim().to_string()).collect(),
+            None => content.split_whitespace().map(str::to_string).collect(),
+        };
+        rows.push((number + 1, fields));
+    }
+    Ok(rows)
+}
+
+/// Resolves the selected columns of a row with `ncols` fields.
+fn select_columns(
+    usecols: Option<&[isize]>,
+    ncols: usize,
+    line: usize,
+) -> Result<Vec<usize>, NumrustError> {
+    match usecols {
+        None => Ok((0..ncols).collect()),
+        Some(cols) => cols
+            .iter()
+            .map(|&c| {
+                let resolved = if c < 0 { c + ncols as isize } else { c };
+                if resolved < 0 || resolved >= ncols as isize {
+                    Err(NumrustError::ValueError(format!(
+                        "invalid column index {} at line {} with {} columns",
+                        c, line, ncols
+                    )))
+                } else {
+                    Ok(resolved as usize)
+                }
+            })
+            .collect(),
+    }
+}
+
+fn column_count_error(expected: usize, found: usize, line: usize) -> NumrustError {
+    NumrustError::ValueError(format!(
+        "the number of columns changed from {} to {} at line {}",
+        expected, found, line
+    ))
+}
+
+/// Loads numeric data from a delimited text file into a two-dimensional array with one row per
+/// line.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::text::{loadtxt_from, LoadTxtOptions};
+/// use numrust::mean;
+///
+/// let csv = "# x, y\n1, 10\n2, 20 # second row\n\n3, 30\n";
+/// let options = LoadTxtOptions { delimiter: Some(","), ..Default::default() };
+/// let data = loadtxt_from(csv.as_bytes(), &options).unwrap();
+/// assert_eq!(data.shape(), &[3, 2]);
+/// assert_eq!(mean(&data.column(1).to_vec()), 20.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if a value cannot be parsed, a column index is invalid,
+/// or the number of columns changes, and a `NumrustError::IoError` if reading fails.
+pub fn loadtxt_from<R: BufRead>(
+    reader: R,
+    options: &LoadTxtOptions,
+) -> Result<Array2<f64>, NumrustError> {
+    let rows = tokenize(
+        reader,
+        options.skiprows,
+        0,
+        options.delimiter,
+        options.comments,
+    )?;
+    let mut values = Vec::new();
+    let mut width = None;
+    for (line, fields) in &rows {
+        let columns = select_columns(options.usecols, fields.len(), *line)?;
+        if *width.get_or_insert(columns.len()) != columns.len() {
+            return Err(column_count_error(width.unwrap_or(0), columns.len(), *line));
+        }
+        for col in columns {
+            let field = &fields[col];
+            let converter = options.converters.iter().find(|(c, _)| *c == col);
+            let value = match converter {
+                Some((_, convert)) => convert(field),
+                None => field.parse::<f64>().ok(),
+            };
+            values.push(value.ok_or_else(|| {
+                NumrustError::ValueError(format!(
+                    "could not convert string '{}' to float at line {}, column {}",
+                    field, line, col
+                ))
+            })?);
+        }
+    }
+    let shape = (rows.len(), width.unwrap_or(0));
+    Ok(Array2::from_shape_vec(shape, values).expect("one value per row and column"))
+}
+
+/// Loads numeric data from a delimited text file. See [`loadtxt_from`].
+///
+/// # Errors
+///
+/// Returns the errors of [`loadtxt_from`], and a `NumrustError::IoError` if the file cannot be
+/// opened.
+pub fn loadtxt(
+    path: impl AsRef<Path>,
+    options: &LoadTxtOptions,
+) -> Result<Array2<f64>, NumrustError> {
+    let file = File::open(path).map_err(io_error)?;
+    loadtxt_from(BufReader::new(file), options)
+}
+
+/// Loads numeric data from a text file in which values may be missing.
+///
+/// Empty fields, fields listed in `missing_values` and fields that cannot be parsed are replaced
+/// by `filling_value` (NaN by default) and marked in the returned mask.
+///
+/// # Examples
+///
+/// ```
+/// use numrust::text::{genfromtxt_from, GenFromTxtOptions};
+///
+/// let csv = "1,2,3\n4,,6\n7,N/A,9\n";
+/// let options = GenFromTxtOptions {
+///     delimiter: Some(","),
+///     missing_values: &["N/A"],
+///     ..Default::default()
+/// };
+/// let result = genfromtxt_from(csv.as_bytes(), &options).unwrap();
+/// assert!(result.data[[1, 1]].is_nan());
+/// assert_eq!(result.mask.column(1).to_vec(), vec![false, true, true]);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if a column index is invalid or the number of columns
+/// changes, and a `NumrustError::IoError` if reading fails.
+pub fn genfromtxt_from<R: BufRead>(
+    reader: R,
+    options: &GenFromTxtOptions,
+) -> Result<GenFromTxt, NumrustError> {
+    let rows = tokenize(
+        reader,
+        options.skip_header,
+        options.skip_footer,
+        options.delimiter,
+        options.comments,
+    )?;
+    let mut values = Vec::new();
+    let mut missing = Vec::new();
+    let mut width = None;
+    for (line, fields) in &rows {
+        let columns = select_columns(options.usecols, fields.len(), *line)?;
+        if *width.get_or_insert(columns.len()) != columns.len() {
+            return Err(column_count_error(width.unwrap_or(0), columns.len(), *line));
+        }
+        for col in columns {
+            let field = fields[col].as_str();
+            let parsed = if field.is_empty() || options.missing_values.contains(&field) {
+                None
+            } else {
+                field.parse::<f64>().ok()
+            };
+            values.push(parsed.unwrap_or(options.filling_value));
+            missing.push(parsed.is_none());
+        }
+    }
+    let shape = (rows.len(), width.unwrap_or(0));
+    Ok(GenFromTxt {
+        data: Array2::from_shape_vec(shape, values).expect("one value per row and column"),
+        mask: Array2::from_shape_vec(shape, missing).expect("one flag per row and column"),
+    })
+}
+
+/// Loads numeric data with missing values from a text file. See [`genfromtxt_from`].
+///
+/// # Errors
+///
+/// Returns the errors of [`genfromtxt_from`], and a `NumrustError::IoError` if the file cannot
+/// be opened.
+pub fn genfromtxt(
+    path: impl AsRef<Path>,
+    options: &GenFromTxtOptions,
+) -> Result<GenFromTxt, NumrustError> {
+    let file = File::open(path).map_err(io_error)?;
+    genfromtxt_from(BufReader::new(file), options)
+}
+
+/// One printf-style conversion specifier, such as `%-10.3e`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Spec {
+    left: bool,
+    plus: bool,
+    space: bool,
+    zero: bool,
+    alternate: bool,
+    width: usize,
+    precision: Option<usize>,
+    conversion: char,
+}
+
+/// Splits a printf-style format into literal text and conversion specifiers.
+fn parse_format(fmt: &str) -> Result<(Vec<String>, Vec<Spec>), NumrustError> {
+    let invalid = || NumrustError::ValueError(format!("invalid format string '{}'", fmt));
+    let mut literals = vec![String::new()];
+    let mut specs = Vec::new();
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            literals.last_mut().expect("at least one literal").push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            literals.last_mut().expect("at least one literal").push('%');
+            continue;
+        }
+        let mut spec = Spec {
+            left: false,
+            plus: false,
+            space: false,
+            zero: false,
+            alternate: false,
+            width: 0,
+            precision: None,
+            conversion: ' ',
+        };
+        while let Some(&flag) = chars.peek() {
+            match flag {
+                '-' => spec.left = true,
+                '+' => spec.plus = true,
+                ' ' => spec.space = true,
+                '0' => spec.zero = true,
+                '#' => spec.alternate = true,
+                _ => break,
+            }
+            chars.next();
+        }
+        while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+            spec.width = spec.width * 10 + d as usize;
+            chars.next();
+        }
+        if chars.peek() == Some(&'.') {
+            chars.next();
+            let mut precision = 0;
+            while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+                precision = precision * 10 + d as usize;
+                chars.next();
+            }
+            spec.precision = Some(precision);
+        }
+        spec.conversion = chars.next().ok_or_else(invalid)?;
+        if !"eEfFgGdis".contains(spec.conversion) {
+            return Err(invalid());
+        }
+        specs.push(spec);
+        literals.push(String::new());
+    }
+    Ok((literals, specs))
+}
+
+/// Formats a finite magnitude in C's `%e` style, with at least two exponent digits.
+fn format_exponent(value: f64, precision: usize, upper: bool, alternate: bool) -> String {
+    let formatted = format!("{:.*e}", precision, value);
+    let (mantissa, exponent) = formatted.split_once('e').expect("exponent notation");
+    let exponent: i32 = exponent.parse().expect("integer exponent");
+    let point = if alternate && precision == 0 { "." } else { "" };
+    let sign = if exponent < 0 { '-' } else { '+' };
+    let e = if upper { 'E' } else { 'e' };
+    format!("{}{}{}{}{:02}", mantissa, point, e, sign, exponent.abs())
+}
+
+/// Formats a finite magnitude in C's `%g` style.
+fn format_general(value: f64, precision: usize, upper: bool, alternate: bool) -> String {
+    let precision = precision.max(1);
+    if value == 0.0 {
+        return if alternate {
+            format!("{:.*}", precision - 1, 0.0)
+        } else {
+            "0".to_string()
+        };
+    }
+    let rounded = format!("{:.*e}", precision - 1, value);
+    let exponent: i32 = rounded
+        .split_once('e')
+        .and_then(|(_, e)| e.parse().ok())
+        .expect("exponent notation");
+    let formatted = if exponent < -4 || exponent >= precision as i32 {
+        format_exponent(value, precision - 1, upper, alternate)
+    } else {
+        format!("{:.*}", (precision as i32 - 1 - exponent) as usize, value)
+    };
+    if alternate {
+        return formatted;
+    }
+    // Trailing zeros of the fraction are removed, as C does without the '#' flag.
+    let (digits, suffix) = match formatted.find(['e', 'E']) {
+        Some(i) => formatted.split_at(i),
+        None => (formatted.as_str(), ""),
+    };
+    let digits = if digits.contains('.') {
+        digits.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        digits
+    };
+    format!("{}{}", digits, suffix)
+}
+
+fn format_value(spec: &Spec, value: f64) -> String {
+    let upper = spec.conversion.is_ascii_uppercase();
+    let magnitude = value.abs();
+    let body = if value.is_nan() {
+        if upper { "NAN" } else { "nan" }.to_string()
+    } else if value.is_infinite() {
+        if upper { "INF" } else { "inf" }.to_string()
+    } else {
+        match spec.conversion {
+            'e' | 'E' => format_exponent(
+                magnitude,
+                spec.precision.unwrap_or(6),
+                upper,
+                spec.alternate,
+            ),
+            'f' | 'F' => format!("{:.*}", spec.precision.unwrap_or(6), magnitude),
+            'g' | 'G' => format_general(
+                magnitude,
+                spec.precision.unwrap_or(6),
+                upper,
+                spec.alternate,
+            ),
+            'd' | 'i' => format!("{}", magnitude.trunc()),
+            _ => format!("{}", magnitude),
+        }
+    };
+    let negative =
+        value.is_sign_negative() && !value.is_nan() && !(spec.conversion == 'd' && magnitude < 1.0);
+    let sign = if negative {
+        "-"
+    } else if spec.plus {
+        "+"
+    } else if spec.space {
+        " "
+    } else {
+        ""
+    };
+    let len = sign.len() + body.chars().count();
+    let pad = spec.width.saturating_sub(len);
+    if spec.left {
+        format!("{}{}{}", sign, body, " ".repeat(pad))
+    } else if spec.zero && value.is_finite() {
+        format!("{}{}{}", sign, "0".repeat(pad), body)
+    } else {
+        format!("{}{}{}", " ".repeat(pad), sign, body)
+    }
+}
+
+/// Writes a one- or two-dimensional array as delimited text, one row per line.
+///
+/// A one-dimensional array is written as a single column.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use numrust::text::{savetxt_to, SaveTxtOptions};
+///
+/// let mut out = Vec::new();
+/// let options = SaveTxtOptions { fmt: "%.2f", delimiter: ",", header: "x,y", ..Default::default() };
+/// savetxt_to(&mut out, &array![[1.0, 2.5], [-3.25, 4.0]], &options).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "# x,y\n1.00,2.50\n-3.25,4.00\n");
+/// ```
+///
+/// # Errors
+///
+/// Returns a `NumrustError::ValueError` if the array has more than two dimensions or `fmt` is
+/// invalid or has the wrong number of specifiers, and a `NumrustError::IoError` if writing
+/// fails.
+pub fn savetxt_to<A, S, D, W>(
+    mut writer: W,
+    a: &ArrayBase<S, D>,
+    options: &SaveTxtOptions,
+) -> Result<(), NumrustError>
+where
+    A: Into<f64> + Copy,
+    S: Data<Elem = A>,
+    D: Dimension,
+    W: Write,
+{
+    let (rows, cols) = match *a.shape() {
+        [n] => (n, 1),
+        [m, n] => (m, n),
+        _ => {
+            return Err(NumrustError::ValueError(
+                "Expected 1D or 2D array".to_string(),
+            ))
+        }
+    };
+    let (literals, specs) = parse_format(options.fmt)?;
+    let row_format = match specs.len() {
+        1 => false,
+        n if n == cols => true,
+        n => {
+            return Err(NumrustError::ValueError(format!(
+                "fmt has wrong number of % formats: {} for {} columns",
+                n, cols
+            )))
+        }
+    };
+
+    let mut out = String::new();
+    let write_comment = |out: &mut String, text: &str| {
+        if !text.is_empty() {
+            for line in text.split('\n') {
+                out.push_str(options.comments);
+                out.push_str(line);
+                out.push_str(options.newline);
+            }
+        }
+    };
+    write_comment(&mut out, options.header);
+    let values: Vec<f64> = a.iter().map(|&x| x.into()).collect();
+    for row in values.chunks(cols.max(1)).take(rows) {
+        for (j, &value) in row.iter().enumerate() {
+            if row_format {
+                out.push_str(&literals[j]);
+                out.push_str(&format_value(&specs[j], value));
+            } else {
+                if j > 0 {
+                    out.push_str(options.delimiter);
+                }
+                out.push_str(&literals[0]);
+                out.push_str(&format_value(&specs[0], value));
+                out.push_str(&literals[1]);
+            }
+        }
+        if row_format {
+            out.push_str(&literals[cols]);
+        }
+        out.push_str(options.newline);
+    }
+    write_comment(&mut out, options.footer);
+    writer.write_all(out.as_bytes()).map_err(io_error)
+}
+
+/// Saves a one- or two-dimensional array to a text file. See [`savetxt_to`].
+///
+/// # Errors
+///
+/// Returns the errors of [`savetxt_to`], and a `NumrustError::IoError` if the file cannot be
+/// created.
+pub fn savetxt<A, S, D>(
+    path: impl AsRef<Path>,
+    a: &ArrayBase<S, D>,
+    options: &SaveTxtOptions,
+) -> Result<(), NumrustError>
+where
+    A: Into<f64> + Copy,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let file = File::create(path).map_err(io_error)?;
+    let mut writer = BufWriter::new(file);
+    savetxt_to(&mut writer, a, options)?;
+    writer.flush().map_err(io_error)
+}
+
+#[cfg(test)]
+mod numrust_text_tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_loadtxt_options() {
+        let text = "header line\n% comment\n 1 2 3 \n4 5 6 % trailing\n";
+        let convert_last = |s: &str| s.parse::<f64>().ok().map(|x| x * 100.0);
+        let options = LoadTxtOptions {
+            skiprows: 1,
+            usecols: Some(&[-1, 0]),
+            comments: Some("%"),
+            converters: &[(2, &convert_last)],
+            ..Default::default()
+        };
+        let data = loadtxt_from(text.as_bytes(), &options).unwrap();
+        assert_eq!(data, array![[300.0, 1.0], [600.0, 4.0]]);
+
+        assert!(loadtxt_from("1 2\n3\n".as_bytes(), &LoadTxtOptions::default()).is_err());
+        assert!(loadtxt_from("1 x\n".as_bytes(), &LoadTxtOptions::default()).is_err());
+        let out_of_range = LoadTxtOptions {
+            usecols: Some(&[2]),
+            ..Default::default()
+        };
+        assert!(loadtxt_from("1 2\n".as_bytes(), &out_of_range).is_err());
+        assert_eq!(
+            loadtxt_from("".as_bytes(), &LoadTxtOptions::default())
+                .unwrap()
+                .shape(),
+            &[0, 0]
+        );
+    }
+
+    #[test]
+    fn test_genfromtxt_skip_footer_and_filling_value() {
+        let text = "a;b\n1;2\n;x\ntotal\n";
+        let options = GenFromTxtOptions {
+            delimiter: Some(";"),
+            skip_header: 1,
+            skip_footer: 1,
+            filling_value: -1.0,
+            ..Default::default()
+        };
+        let result = genfromtxt_from(text.as_bytes(), &options).unwrap();
+        assert_eq!(result.data, array![[1.0, 2.0], [-1.0, -1.0]]);
+        assert_eq!(result.mask, array![[false, false], [true, true]]);
+    }
+
+    #[test]
+    fn test_format_specifiers_match_printf() {
+        let spec = |fmt: &str| parse_format(fmt).unwrap().1[0];
+        assert_eq!(
+            format_value(&spec("%.18e"), 0.1),
+            "1.000000000000000056e-01"
+        );
+        assert_eq!(format_value(&spec("%e"), -12345.678), "-1.234568e+04");
+        assert_eq!(format_value(&spec("%10.3f"), -1.23456), "    -1.235");
+        assert_eq!(format_value(&spec("%-8.1f|"), 2.0), "2.0     ");
+        assert_eq!(format_value(&spec("%08.2f"), -1.5), "-0001.50");
+        assert_eq!(format_value(&spec("%+d"), 7.9), "+7");
+        assert_eq!(format_value(&spec("%g"), 0.0001), "0.0001");
+        assert_eq!(format_value(&spec("%g"), 0.00001), "1e-05");
+        assert_eq!(format_value(&spec("%g"), 123456789.0), "1.23457e+08");
+        assert_eq!(format_value(&spec("%.3G"), 1234.0), "1.23E+03");
+        assert_eq!(format_value(&spec("%g"), 100.0), "100");
+        assert_eq!(format_value(&spec("%5.1f"), f64::NAN), "  nan");
+        assert_eq!(format_value(&spec("%e"), f64::NEG_INFINITY), "-inf");
+        assert!(parse_format("%q").is_err());
+        assert!(parse_format("%5").is_err());
+    }
+
+    #[test]
+    fn test_savetxt_row_format_footer_and_round_trip() {
+        let a = array![[1.0, 2.0], [3.0, 4.0]];
+        let mut out = Vec::new();
+        let options = SaveTxtOptions {
+            fmt: "%d -> %.1f%%",
+            footer: "end\ndone",
+            ..Default::default()
+        };
+        savetxt_to(&mut out, &a, &options).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "1 -> 2.0%\n3 -> 4.0%\n# end\n# done\n"
+        );
+
+        let mut out = Vec::new();
+        savetxt_to(
+            &mut out,
+            &array![0.1, -2.5e-300],
+            &SaveTxtOptions::default(),
+        )
+        .unwrap();
+        let loaded = loadtxt_from(out.as_slice(), &LoadTxtOptions::default()).unwrap();
+        assert_eq!(loaded, array![[0.1], [-2.5e-300]]);
+
+        let bad = SaveTxtOptions {
+            fmt: "%f %f %f",
+            ..Default::default()
+        };
+        assert!(savetxt_to(&mut Vec::new(), &a, &bad).is_err());
+        assert!(savetxt_to(
+            &mut Vec::new(),
+            &ndarray::Array3::<f64>::zeros((1, 1, 1)),
+            &SaveTxtOptions::default()
+        )
+        .is_err());
+    }
+}